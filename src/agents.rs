@@ -12,6 +12,40 @@ use converge_core::llm::{
 };
 use std::sync::Arc;
 
+use crate::confidence::{extract_confidence, ConfidenceLedger, ReviewItem};
+
+/// A fact parsed from an LLM response together with its reported confidence.
+type ScoredFact = (Fact, Option<f64>);
+
+/// Commits scored facts that pass the ledger threshold and diverts the rest.
+///
+/// Diverted items are only held in the ledger; if every item is diverted
+/// the agent commits nothing, and its `accepts` checks
+/// [`ConfidenceLedger::has_held_items`] so it does not run again.
+fn admit_scored_facts(agent: &str, ledger: &ConfidenceLedger, scored: Vec<ScoredFact>) -> Vec<Fact> {
+    let mut facts = Vec::new();
+
+    for (fact, confidence) in scored {
+        if let Some(threshold) = ledger.failed_threshold(confidence) {
+            ledger.divert(ReviewItem {
+                id: fact.id,
+                key: format!("{:?}", fact.key),
+                agent: agent.to_string(),
+                content: fact.content,
+                confidence,
+                threshold,
+            });
+            continue;
+        }
+        if let Some(c) = confidence {
+            ledger.record(&fact.id, c);
+        }
+        facts.push(fact);
+    }
+
+    facts
+}
+
 /// LLM-powered agent that generates strategic insights from evaluations.
 ///
 /// This agent runs after the EvaluationAgent and synthesizes higher-level
@@ -31,6 +65,7 @@ use std::sync::Arc;
 pub struct StrategicInsightAgent {
     provider: Arc<dyn LlmProvider>,
     system_prompt: String,
+    ledger: Arc<ConfidenceLedger>,
 }
 
 impl StrategicInsightAgent {
@@ -49,7 +84,10 @@ Each insight should:
 3. Provide a clear recommendation
 
 Format your response as a numbered list of insights, one per line.
+Start each insight with your calibrated confidence that it holds, as
+[confidence: 0.00-1.00], e.g. "1. [confidence: 0.80] ...".
 Keep each insight concise (1-2 sentences)."#.to_string(),
+            ledger: Arc::new(ConfidenceLedger::default()),
         }
     }
 
@@ -58,9 +96,17 @@ Keep each insight concise (1-2 sentences)."#.to_string(),
         Self {
             provider,
             system_prompt: system_prompt.into(),
+            ledger: Arc::new(ConfidenceLedger::default()),
         }
     }
 
+    /// Records confidences in (and diverts low-confidence items to) `ledger`.
+    #[must_use]
+    pub fn with_ledger(mut self, ledger: Arc<ConfidenceLedger>) -> Self {
+        self.ledger = ledger;
+        self
+    }
+
     /// Builds the user prompt from context.
     fn build_prompt(&self, ctx: &Context) -> String {
         let mut prompt = String::new();
@@ -90,8 +136,8 @@ Keep each insight concise (1-2 sentences)."#.to_string(),
         prompt
     }
 
-    /// Parses LLM response into facts.
-    fn parse_response(&self, response: &str) -> Vec<Fact> {
+    /// Parses LLM response into facts with their reported confidence.
+    fn parse_response(&self, response: &str) -> Vec<ScoredFact> {
         let mut facts = Vec::new();
 
         for (i, line) in response.lines().enumerate() {
@@ -106,25 +152,20 @@ Keep each insight concise (1-2 sentences)."#.to_string(),
             let content = line
                 .trim_start_matches(|c: char| c.is_numeric() || c == '.' || c == ')' || c == ' ')
                 .trim();
+            let (confidence, content) = extract_confidence(content);
 
             if !content.is_empty() && content.len() > 10 {
-                facts.push(Fact {
-                    key: ContextKey::Hypotheses,
-                    id: format!("insight:{}", i + 1),
-                    content: content.to_string(),
-                });
+                facts.push((
+                    Fact {
+                        key: ContextKey::Hypotheses,
+                        id: format!("insight:{}", i + 1),
+                        content,
+                    },
+                    confidence,
+                ));
             }
         }
 
-        // Ensure we have at least one insight
-        if facts.is_empty() {
-            facts.push(Fact {
-                key: ContextKey::Hypotheses,
-                id: "insight:fallback".into(),
-                content: "LLM analysis completed but no structured insights extracted. Review raw evaluation data.".into(),
-            });
-        }
-
         facts
     }
 }
@@ -140,7 +181,9 @@ impl Agent for StrategicInsightAgent {
 
     fn accepts(&self, ctx: &Context) -> bool {
         // Run once when evaluations exist but no hypotheses (insights) yet
-        ctx.has(ContextKey::Evaluations) && !ctx.has(ContextKey::Hypotheses)
+        ctx.has(ContextKey::Evaluations)
+            && !ctx.has(ContextKey::Hypotheses)
+            && !self.ledger.has_held_items(self.name())
     }
 
     fn execute(&self, ctx: &Context) -> AgentEffect {
@@ -153,7 +196,18 @@ impl Agent for StrategicInsightAgent {
 
         match result {
             Ok(response) => {
-                let facts = self.parse_response(&response.content);
+                let scored = self.parse_response(&response.content);
+
+                // Ensure we have at least one insight
+                if scored.is_empty() {
+                    return AgentEffect::with_facts(vec![Fact {
+                        key: ContextKey::Hypotheses,
+                        id: "insight:fallback".into(),
+                        content: "LLM analysis completed but no structured insights extracted. Review raw evaluation data.".into(),
+                    }]);
+                }
+
+                let facts = admit_scored_facts(self.name(), &self.ledger, scored);
                 AgentEffect::with_facts(facts)
             }
             Err(e) => {
@@ -183,11 +237,11 @@ impl MockInsightProvider {
 
     /// Creates a mock provider with default insights.
     pub fn default_insights() -> Self {
-        Self::new(r#"1. Focus on the LinkedIn B2B campaign as your primary channel - it scores highest and aligns with market signals showing LinkedIn effectiveness for B2B.
+        Self::new(r#"1. [confidence: 0.86] Focus on the LinkedIn B2B campaign as your primary channel - it scores highest and aligns with market signals showing LinkedIn effectiveness for B2B.
 
-2. Invest in self-service demo capabilities as a secondary priority - while it requires development investment, it directly addresses the buyer preference for self-service identified in market signals.
+2. [confidence: 0.72] Invest in self-service demo capabilities as a secondary priority - while it requires development investment, it directly addresses the buyer preference for self-service identified in market signals.
 
-3. Consider a phased approach: launch LinkedIn campaign immediately for quick wins, then build self-service demo experience for long-term competitive advantage."#)
+3. [confidence: 0.64] Consider a phased approach: launch LinkedIn campaign immediately for quick wins, then build self-service demo experience for long-term competitive advantage."#)
     }
 }
 
@@ -238,6 +292,7 @@ impl LlmProvider for MockInsightProvider {
 pub struct RiskAssessmentAgent {
     provider: Arc<dyn LlmProvider>,
     system_prompt: String,
+    ledger: Arc<ConfidenceLedger>,
 }
 
 impl RiskAssessmentAgent {
@@ -256,7 +311,10 @@ For each risk:
 3. Suggest a mitigation approach
 
 Format your response as a numbered list, one risk per item.
+Start each risk with your calibrated confidence that it is material, as
+[confidence: 0.00-1.00], e.g. "1. [confidence: 0.70] ...".
 Keep each risk assessment concise (2-3 sentences)."#.to_string(),
+            ledger: Arc::new(ConfidenceLedger::default()),
         }
     }
 
@@ -265,9 +323,17 @@ Keep each risk assessment concise (2-3 sentences)."#.to_string(),
         Self {
             provider,
             system_prompt: system_prompt.into(),
+            ledger: Arc::new(ConfidenceLedger::default()),
         }
     }
 
+    /// Records confidences in (and diverts low-confidence items to) `ledger`.
+    #[must_use]
+    pub fn with_ledger(mut self, ledger: Arc<ConfidenceLedger>) -> Self {
+        self.ledger = ledger;
+        self
+    }
+
     /// Builds the user prompt from context.
     fn build_prompt(&self, ctx: &Context) -> String {
         let mut prompt = String::new();
@@ -302,8 +368,8 @@ Keep each risk assessment concise (2-3 sentences)."#.to_string(),
         prompt
     }
 
-    /// Parses LLM response into risk facts.
-    fn parse_response(&self, response: &str) -> Vec<Fact> {
+    /// Parses LLM response into risk facts with their reported confidence.
+    fn parse_response(&self, response: &str) -> Vec<ScoredFact> {
         let mut facts = Vec::new();
         let mut risk_count = 0;
 
@@ -319,26 +385,21 @@ Keep each risk assessment concise (2-3 sentences)."#.to_string(),
             let content = line
                 .trim_start_matches(|c: char| c.is_numeric() || c == '.' || c == ')' || c == ' ')
                 .trim();
+            let (confidence, content) = extract_confidence(content);
 
             if !content.is_empty() && content.len() > 20 {
                 risk_count += 1;
-                facts.push(Fact {
-                    key: ContextKey::Constraints,
-                    id: format!("risk:{}", risk_count),
-                    content: content.to_string(),
-                });
+                facts.push((
+                    Fact {
+                        key: ContextKey::Constraints,
+                        id: format!("risk:{}", risk_count),
+                        content,
+                    },
+                    confidence,
+                ));
             }
         }
 
-        // Ensure we have at least one risk identified
-        if facts.is_empty() {
-            facts.push(Fact {
-                key: ContextKey::Constraints,
-                id: "risk:none-identified".into(),
-                content: "No significant risks identified. Recommend manual review of assumptions.".into(),
-            });
-        }
-
         facts
     }
 }
@@ -357,6 +418,7 @@ impl Agent for RiskAssessmentAgent {
         ctx.has(ContextKey::Strategies)
             && ctx.has(ContextKey::Evaluations)
             && !ctx.has(ContextKey::Constraints)
+            && !self.ledger.has_held_items(self.name())
     }
 
     fn execute(&self, ctx: &Context) -> AgentEffect {
@@ -369,7 +431,18 @@ impl Agent for RiskAssessmentAgent {
 
         match result {
             Ok(response) => {
-                let facts = self.parse_response(&response.content);
+                let scored = self.parse_response(&response.content);
+
+                // Ensure we have at least one risk identified
+                if scored.is_empty() {
+                    return AgentEffect::with_facts(vec![Fact {
+                        key: ContextKey::Constraints,
+                        id: "risk:none-identified".into(),
+                        content: "No significant risks identified. Recommend manual review of assumptions.".into(),
+                    }]);
+                }
+
+                let facts = admit_scored_facts(self.name(), &self.ledger, scored);
                 AgentEffect::with_facts(facts)
             }
             Err(e) => {
@@ -399,11 +472,11 @@ impl MockRiskProvider {
 
     /// Creates a mock provider with default risk assessments.
     pub fn default_risks() -> Self {
-        Self::new(r#"1. **Resource Constraint Risk** - The self-service demo requires significant development investment while the team may be focused on the LinkedIn campaign. Mitigation: Phase the initiatives and allocate dedicated resources for each.
+        Self::new(r#"1. [confidence: 0.81] **Resource Constraint Risk** - The self-service demo requires significant development investment while the team may be focused on the LinkedIn campaign. Mitigation: Phase the initiatives and allocate dedicated resources for each.
 
-2. **Market Timing Risk** - The unclear competitive landscape means competitors could launch similar initiatives first. Mitigation: Conduct rapid competitor analysis within 2 weeks before committing to campaign messaging.
+2. [confidence: 0.58] **Market Timing Risk** - The unclear competitive landscape means competitors could launch similar initiatives first. Mitigation: Conduct rapid competitor analysis within 2 weeks before committing to campaign messaging.
 
-3. **Channel Saturation Risk** - LinkedIn B2B campaigns face increasing competition and rising costs. Mitigation: Test multiple audience segments with small budgets before scaling spend."#)
+3. [confidence: 0.69] **Channel Saturation Risk** - LinkedIn B2B campaigns face increasing competition and rising costs. Mitigation: Test multiple audience segments with small budgets before scaling spend."#)
     }
 }
 
@@ -488,6 +561,48 @@ mod tests {
         assert!(effect.facts.iter().all(|f| f.key == ContextKey::Constraints));
     }
 
+    #[test]
+    fn insight_agent_records_confidence_and_diverts_low_scores() {
+        let provider = Arc::new(MockInsightProvider::default_insights());
+        let ledger = Arc::new(ConfidenceLedger::new(Some(0.7)));
+        let agent = StrategicInsightAgent::new(provider).with_ledger(ledger.clone());
+
+        let mut ctx = Context::new();
+        ctx.add_fact(Fact::new(ContextKey::Evaluations, "eval:test", "Score: 80/100"))
+            .unwrap();
+
+        let effect = agent.execute(&ctx);
+
+        // 0.86 and 0.72 pass, 0.64 is held for review
+        assert_eq!(effect.facts.len(), 2);
+        assert_eq!(ledger.confidence(&effect.facts[0].id), Some(0.86));
+        assert!(!effect.facts[0].content.contains("confidence"));
+
+        let review = ledger.pending_review();
+        assert_eq!(review.len(), 1);
+        assert_eq!(review[0].confidence, Some(0.64));
+        assert_eq!(review[0].agent, "StrategicInsightAgent");
+    }
+
+    #[test]
+    fn risk_agent_holds_everything_back_and_does_not_rerun() {
+        let provider = Arc::new(MockRiskProvider::default_risks());
+        let ledger = Arc::new(ConfidenceLedger::new(Some(0.95)));
+        let agent = RiskAssessmentAgent::new(provider).with_ledger(ledger.clone());
+
+        let mut ctx = Context::new();
+        ctx.add_fact(Fact::new(ContextKey::Strategies, "strategy:test", "Test strategy"))
+            .unwrap();
+        ctx.add_fact(Fact::new(ContextKey::Evaluations, "eval:test", "Score: 75/100"))
+            .unwrap();
+
+        let effect = agent.execute(&ctx);
+
+        assert!(effect.facts.is_empty());
+        assert_eq!(ledger.pending_review().len(), 3);
+        assert!(!agent.accepts(&ctx));
+    }
+
     #[test]
     fn risk_assessment_agent_runs_once() {
         let provider = Arc::new(MockRiskProvider::default_risks());
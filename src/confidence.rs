@@ -0,0 +1,232 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! Confidence scoring for LLM-produced facts.
+//!
+//! LLM agents are asked to prefix every item they return with a calibrated
+//! confidence, e.g. `[confidence: 0.82]`. This module:
+//!
+//! - Extracts that annotation from a response line
+//! - Records the score per fact id so outputs can report it
+//! - Diverts items below `validation.min_confidence` to a review queue
+//!   instead of committing them to the context
+//!
+//! # Architecture Note
+//!
+//! `converge_core::Fact` carries no confidence, so scores live in a ledger
+//! next to the run. Held items stay out of the context entirely; an agent
+//! whose every item was held checks the ledger so it does not run again.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::{LazyLock, Mutex};
+
+/// An LLM item held back from the context because its confidence was too low.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewItem {
+    /// Fact id the item would have been committed under.
+    pub id: String,
+    /// Context key the item targets (e.g. "Hypotheses").
+    pub key: String,
+    /// Agent that produced the item.
+    pub agent: String,
+    /// Item content.
+    pub content: String,
+    /// Confidence reported by the LLM (`None` if it gave none).
+    pub confidence: Option<f64>,
+    /// Threshold the item failed.
+    pub threshold: f64,
+}
+
+/// A reviewer's decision on a held-back item, kept as an audit trail.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewRecord {
+    /// Local time of the decision.
+    pub at: String,
+    /// Who decided (the TUI user).
+    pub reviewer: String,
+    /// Id of the reviewed item.
+    pub id: String,
+    /// `approved`, `rejected` or `edited`.
+    pub decision: String,
+    /// Reviewer's reason for the decision.
+    pub reason: String,
+    /// Content committed to the context (`None` when rejected).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub committed: Option<String>,
+}
+
+/// Per-run record of fact confidences and items awaiting review.
+#[derive(Debug, Default)]
+pub struct ConfidenceLedger {
+    min_confidence: Option<f64>,
+    scores: Mutex<HashMap<String, f64>>,
+    review: Mutex<Vec<ReviewItem>>,
+    /// Agents with items held for review, in this run or before it resumed
+    reviewed_agents: Mutex<HashSet<String>>,
+}
+
+impl ConfidenceLedger {
+    /// Creates a ledger that diverts items below `min_confidence` to review.
+    pub fn new(min_confidence: Option<f64>) -> Self {
+        Self {
+            min_confidence,
+            ..Self::default()
+        }
+    }
+
+    /// Marks agents whose items were reviewed before this run resumed.
+    #[must_use]
+    pub fn with_reviewed_agents(self, agents: impl IntoIterator<Item = String>) -> Self {
+        if let Ok(mut reviewed) = self.reviewed_agents.lock() {
+            reviewed.extend(agents);
+        }
+        self
+    }
+
+    /// Returns the threshold an item fails, or `None` if it may be committed.
+    ///
+    /// Unscored items fail any configured threshold: an LLM that did not
+    /// report a confidence has not earned automatic acceptance.
+    pub fn failed_threshold(&self, confidence: Option<f64>) -> Option<f64> {
+        let threshold = self.min_confidence?;
+        match confidence {
+            Some(c) if c >= threshold => None,
+            _ => Some(threshold),
+        }
+    }
+
+    /// Records the confidence of a committed fact.
+    pub fn record(&self, fact_id: &str, confidence: f64) {
+        if let Ok(mut scores) = self.scores.lock() {
+            scores.insert(fact_id.to_string(), confidence);
+        }
+    }
+
+    /// Returns the recorded confidence of a fact.
+    pub fn confidence(&self, fact_id: &str) -> Option<f64> {
+        self.scores.lock().ok()?.get(fact_id).copied()
+    }
+
+    /// Adds an item to the review queue.
+    pub fn divert(&self, item: ReviewItem) {
+        if let Ok(mut reviewed) = self.reviewed_agents.lock() {
+            reviewed.insert(item.agent.clone());
+        }
+        if let Ok(mut review) = self.review.lock() {
+            review.push(item);
+        }
+    }
+
+    /// Returns all items currently held for review.
+    pub fn pending_review(&self) -> Vec<ReviewItem> {
+        self.review.lock().map(|r| r.clone()).unwrap_or_default()
+    }
+
+    /// Whether `agent` has had items held for review.
+    pub fn has_held_items(&self, agent: &str) -> bool {
+        self.reviewed_agents.lock().is_ok_and(|r| r.contains(agent))
+    }
+}
+
+/// Confidence annotations: `[confidence: 0.82]`, `(confidence: 82%)` or
+/// `confidence=0.82`.
+static ANNOTATION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)[\[(]\s*confidence\s*[:=]\s*(\d+(?:\.\d+)?)\s*(%?)\s*[\])]|\bconfidence=(\d+(?:\.\d+)?)(%?)")
+        .expect("valid confidence regex")
+});
+
+/// Extracts a confidence annotation from an LLM response line.
+///
+/// Accepts `[confidence: 0.82]`, `(confidence: 82%)` and `confidence=0.82`
+/// anywhere in the line; if there are several, the last one counts. Returns
+/// the score and the line with the annotation removed. Prose such as
+/// "confidence 20%" is not an annotation, and a score outside `0.0..=1.0`
+/// (or `0%..=100%`) is rejected; either way the line is returned unchanged.
+pub fn extract_confidence(line: &str) -> (Option<f64>, String) {
+    let Some(captures) = ANNOTATION.captures_iter(line).last() else {
+        return (None, line.trim().to_string());
+    };
+    // Groups 1-2 are the bracketed form, 3-4 the `confidence=` form
+    let raw = captures.get(1).or_else(|| captures.get(3)).map_or("", |m| m.as_str());
+    let percent = captures.get(2).or_else(|| captures.get(4)).is_some_and(|m| !m.is_empty());
+
+    let value = match raw.parse::<f64>() {
+        Ok(raw) if percent => raw / 100.0,
+        Ok(raw) => raw,
+        Err(_) => return (None, line.trim().to_string()),
+    };
+    if !(0.0..=1.0).contains(&value) {
+        return (None, line.trim().to_string());
+    }
+
+    let annotation = captures.get(0).expect("whole match");
+    let content = format!("{} {}", line[..annotation.start()].trim(), line[annotation.end()..].trim());
+    let content = content
+        .trim()
+        .trim_start_matches(|c: char| c == '-' || c == ':' || c.is_whitespace())
+        .trim_end_matches(|c: char| c == '-' || c == ',' || c.is_whitespace())
+        .to_string();
+
+    (Some(value), content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_bracketed_prefix() {
+        let (confidence, content) = extract_confidence("[confidence: 0.82] Focus on LinkedIn");
+        assert_eq!(confidence, Some(0.82));
+        assert_eq!(content, "Focus on LinkedIn");
+    }
+
+    #[test]
+    fn extracts_percent_suffix() {
+        let (confidence, content) = extract_confidence("Focus on LinkedIn (Confidence: 75%)");
+        assert_eq!(confidence, Some(0.75));
+        assert_eq!(content, "Focus on LinkedIn");
+    }
+
+    #[test]
+    fn leaves_unannotated_lines_alone() {
+        let (confidence, content) = extract_confidence("  Focus on LinkedIn ");
+        assert_eq!(confidence, None);
+        assert_eq!(content, "Focus on LinkedIn");
+    }
+
+    #[test]
+    fn uses_the_last_annotation_and_ignores_prose() {
+        let (confidence, content) = extract_confidence("Raise confidence 20% among buyers confidence=0.6");
+        assert_eq!(confidence, Some(0.6));
+        assert_eq!(content, "Raise confidence 20% among buyers");
+
+        let (confidence, content) = extract_confidence("Raise confidence 20% among buyers");
+        assert_eq!(confidence, None);
+        assert_eq!(content, "Raise confidence 20% among buyers");
+    }
+
+    #[test]
+    fn rejects_out_of_range_scores() {
+        for line in ["[confidence: 1.5] Focus on LinkedIn", "Focus on LinkedIn (confidence: 120%)"] {
+            let (confidence, content) = extract_confidence(line);
+            assert_eq!(confidence, None, "{}", line);
+            assert_eq!(content, line);
+        }
+    }
+
+    #[test]
+    fn ledger_diverts_below_threshold() {
+        let ledger = ConfidenceLedger::new(Some(0.75));
+        assert_eq!(ledger.failed_threshold(Some(0.9)), None);
+        assert_eq!(ledger.failed_threshold(Some(0.5)), Some(0.75));
+        assert_eq!(ledger.failed_threshold(None), Some(0.75));
+
+        let unlimited = ConfidenceLedger::default();
+        assert_eq!(unlimited.failed_threshold(None), None);
+    }
+}
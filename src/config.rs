@@ -10,13 +10,23 @@
 //! - Enabled domain packs
 //! - Provider configuration
 //! - Auth and tenancy settings
+//! - Validation thresholds (e.g. minimum fact confidence)
+//!
+//! The configuration is read from `.converge/config.json` (or the file named
+//! by `CONVERGE_CONFIG`); every field is optional.
 //!
 //! Note: This is **wiring configuration**, not business semantics.
 
+use anyhow::{Context as _, Result};
 use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Default configuration file, relative to the working directory.
+pub const DEFAULT_CONFIG_PATH: &str = ".converge/config.json";
 
 /// Application configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AppConfig {
     /// Host to bind to.
     pub host: String,
@@ -32,6 +42,40 @@ pub struct AppConfig {
 
     /// Auth configuration.
     pub auth: AuthConfig,
+
+    /// Validation configuration.
+    pub validation: ValidationConfig,
+}
+
+impl AppConfig {
+    /// Loads the configuration file named by `CONVERGE_CONFIG`, or
+    /// `.converge/config.json` if it exists, then applies environment
+    /// overrides. Without a file the defaults are used.
+    pub fn load() -> Result<Self> {
+        let mut config = match std::env::var_os("CONVERGE_CONFIG") {
+            Some(path) => Self::load_file(Path::new(&path))?,
+            None if Path::new(DEFAULT_CONFIG_PATH).is_file() => Self::load_file(Path::new(DEFAULT_CONFIG_PATH))?,
+            None => Self::default(),
+        };
+        if let Ok(value) = std::env::var("CONVERGE_MIN_CONFIDENCE") {
+            let value = value
+                .parse()
+                .with_context(|| format!("CONVERGE_MIN_CONFIDENCE '{}' is not a number", value))?;
+            config.validation.min_confidence = Some(value);
+        }
+        config.validation.check()?;
+        Ok(config)
+    }
+
+    /// Loads a configuration file.
+    pub fn load_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read '{}'", path.display()))?;
+        let config: Self = serde_json::from_str(&content)
+            .with_context(|| format!("'{}' is not a converge configuration", path.display()))?;
+        config.validation.check().with_context(|| format!("Invalid configuration '{}'", path.display()))?;
+        Ok(config)
+    }
 }
 
 impl Default for AppConfig {
@@ -42,12 +86,14 @@ impl Default for AppConfig {
             enabled_packs: vec!["growth-strategy".to_string()],
             providers: ProviderConfig::default(),
             auth: AuthConfig::default(),
+            validation: ValidationConfig::default(),
         }
     }
 }
 
 /// Provider configuration.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ProviderConfig {
     /// Preferred providers in order.
     pub prefer: Vec<String>,
@@ -72,8 +118,30 @@ pub struct ProviderOverride {
     pub timeout_ms: Option<u64>,
 }
 
+/// Validation configuration applied to agent output before it is committed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ValidationConfig {
+    /// LLM facts below this confidence (0.0 - 1.0) are diverted to review
+    /// instead of being committed to the context.
+    pub min_confidence: Option<f64>,
+}
+
+impl ValidationConfig {
+    /// Rejects thresholds outside `0.0..=1.0`.
+    pub fn check(&self) -> Result<()> {
+        match self.min_confidence {
+            Some(value) if !(0.0..=1.0).contains(&value) => {
+                anyhow::bail!("validation.min_confidence must be between 0.0 and 1.0, got {}", value)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
 /// Authentication configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AuthConfig {
     /// Whether auth is enabled.
     pub enabled: bool,
@@ -102,3 +170,25 @@ pub enum AuthProvider {
     /// JWT/OAuth.
     Jwt,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partial_files_use_defaults() {
+        let config: AppConfig = serde_json::from_str(r#"{"validation": {"min_confidence": 0.7}}"#).unwrap();
+        assert_eq!(config.port, 8080);
+        assert_eq!(config.validation.min_confidence, Some(0.7));
+        assert!(config.validation.check().is_ok());
+    }
+
+    #[test]
+    fn rejects_out_of_range_thresholds() {
+        let validation = ValidationConfig {
+            min_confidence: Some(1.5),
+        };
+        let error = validation.check().unwrap_err().to_string();
+        assert_eq!(error, "validation.min_confidence must be between 0.0 and 1.0, got 1.5");
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! Cooperative cancellation and pausing of convergence runs.
+//!
+//! The engine has no notion of cancellation, so every registered agent is
+//! wrapped in [`Controlled`], which consults a shared [`JobControl`] before
+//! delegating `accepts`:
+//!
+//! - **Paused**: `accepts` blocks until the job is resumed or cancelled, so
+//!   the engine stalls at the start of the next cycle.
+//! - **Cancelled**: `accepts` returns false, no agent is eligible and the
+//!   engine stops at the next cycle boundary with the context it has.
+//!
+//! Callers check [`JobControl::is_cancelled`] after `Engine::run` returns to
+//! tell a cancelled run from a converged one.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+
+use converge_core::{Agent, AgentEffect, Context, ContextKey};
+
+/// Shared cancel/pause switch for one convergence run.
+#[derive(Debug, Default)]
+pub struct JobControl {
+    cancelled: AtomicBool,
+    paused: Mutex<bool>,
+    resumed: Condvar,
+}
+
+impl JobControl {
+    /// Creates a control for a run that is neither paused nor cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests cancellation; takes effect at the next cycle boundary.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        // Wake a paused run so it can observe the cancellation
+        self.resume();
+    }
+
+    /// Returns true once cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Pauses the run at the next cycle boundary.
+    pub fn pause(&self) {
+        *self.paused.lock().unwrap_or_else(PoisonError::into_inner) = true;
+    }
+
+    /// Resumes a paused run.
+    pub fn resume(&self) {
+        *self.paused.lock().unwrap_or_else(PoisonError::into_inner) = false;
+        self.resumed.notify_all();
+    }
+
+    /// Returns true while the run is paused.
+    pub fn is_paused(&self) -> bool {
+        *self.paused.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Blocks while paused; returns false if the run has been cancelled.
+    pub fn checkpoint(&self) -> bool {
+        let mut paused = self.paused.lock().unwrap_or_else(PoisonError::into_inner);
+        while *paused && !self.is_cancelled() {
+            paused = self
+                .resumed
+                .wait(paused)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        !self.is_cancelled()
+    }
+}
+
+/// Agent wrapper that honours a [`JobControl`] before every eligibility check.
+pub struct Controlled<A> {
+    inner: A,
+    control: Arc<JobControl>,
+}
+
+impl<A: Agent> Controlled<A> {
+    /// Wraps `inner` so it stops accepting work once `control` is cancelled.
+    pub fn new(inner: A, control: Arc<JobControl>) -> Self {
+        Self { inner, control }
+    }
+}
+
+impl<A: Agent> Agent for Controlled<A> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn dependencies(&self) -> &[ContextKey] {
+        self.inner.dependencies()
+    }
+
+    fn accepts(&self, ctx: &Context) -> bool {
+        self.control.checkpoint() && self.inner.accepts(ctx)
+    }
+
+    fn execute(&self, ctx: &Context) -> AgentEffect {
+        self.inner.execute(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn cancel_wakes_a_paused_checkpoint() {
+        let control = Arc::new(JobControl::new());
+        control.pause();
+        assert!(control.is_paused());
+
+        let waiter = {
+            let control = control.clone();
+            std::thread::spawn(move || control.checkpoint())
+        };
+        std::thread::sleep(Duration::from_millis(20));
+        control.cancel();
+
+        assert!(!waiter.join().unwrap());
+        assert!(control.is_cancelled());
+    }
+
+    #[test]
+    fn checkpoint_passes_when_running() {
+        let control = JobControl::new();
+        assert!(control.checkpoint());
+        control.pause();
+        control.resume();
+        assert!(control.checkpoint());
+    }
+}
@@ -0,0 +1,256 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! Diffing the contexts of two runs.
+//!
+//! Facts are matched by context key and id. A fact present only in the
+//! second run is *added*, one present only in the first is *removed*, and
+//! one present in both with different content is *changed*. Cycle count and
+//! convergence are compared alongside.
+//!
+//! Used by `converge diff` (saved outputs or the run store) and by the TUI
+//! to compare two jobs.
+
+use serde::Serialize;
+
+use crate::output::{FactOutput, RunOutput};
+
+/// Headline of one side of a diff.
+#[derive(Debug, Clone, Serialize)]
+pub struct RunSide {
+    /// Run id, job id or file the side was loaded from.
+    pub label: String,
+    pub converged: bool,
+    pub cycles: u32,
+    pub total_facts: usize,
+}
+
+/// A fact whose content differs between the runs.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangedFact {
+    pub id: String,
+    pub before: String,
+    pub after: String,
+}
+
+/// Differences within one context key.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct KeyDiff {
+    pub key: String,
+    pub added: Vec<FactOutput>,
+    pub removed: Vec<FactOutput>,
+    pub changed: Vec<ChangedFact>,
+    /// Facts identical in both runs.
+    pub unchanged: usize,
+}
+
+impl KeyDiff {
+    /// True if the key's facts are identical in both runs.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Differences between two runs.
+#[derive(Debug, Clone, Serialize)]
+pub struct RunDiff {
+    pub before: RunSide,
+    pub after: RunSide,
+    /// Per-key differences, in context key order (identical keys included).
+    pub keys: Vec<KeyDiff>,
+}
+
+impl RunDiff {
+    /// Diffs two sides given their facts.
+    pub fn new(before: RunSide, after: RunSide, before_facts: &[FactOutput], after_facts: &[FactOutput]) -> Self {
+        Self {
+            before,
+            after,
+            keys: diff_facts(before_facts, after_facts),
+        }
+    }
+
+    /// Diffs two saved run outputs.
+    pub fn between(before: &RunOutput, after: &RunOutput) -> Self {
+        Self::new(side(before), side(after), &before.facts, &after.facts)
+    }
+
+    /// True if facts, cycles and convergence all match.
+    pub fn is_identical(&self) -> bool {
+        self.before.converged == self.after.converged
+            && self.before.cycles == self.after.cycles
+            && self.keys.iter().all(KeyDiff::is_empty)
+    }
+
+    /// Total (added, removed, changed) facts across all keys.
+    pub fn totals(&self) -> (usize, usize, usize) {
+        self.keys.iter().fold((0, 0, 0), |(a, r, c), key| {
+            (a + key.added.len(), r + key.removed.len(), c + key.changed.len())
+        })
+    }
+}
+
+fn side(output: &RunOutput) -> RunSide {
+    RunSide {
+        label: output.run_id.clone(),
+        converged: output.result.converged,
+        cycles: output.result.cycles,
+        total_facts: output.facts.len(),
+    }
+}
+
+/// Matches facts by key and id; keys keep the order they first appear in.
+fn diff_facts(before: &[FactOutput], after: &[FactOutput]) -> Vec<KeyDiff> {
+    let mut keys: Vec<KeyDiff> = Vec::new();
+    fn entry<'a>(keys: &'a mut Vec<KeyDiff>, key: &str) -> &'a mut KeyDiff {
+        let idx = match keys.iter().position(|k| k.key == key) {
+            Some(idx) => idx,
+            None => {
+                keys.push(KeyDiff {
+                    key: key.to_string(),
+                    ..KeyDiff::default()
+                });
+                keys.len() - 1
+            }
+        };
+        &mut keys[idx]
+    }
+
+    for old in before {
+        let diff = entry(&mut keys, &old.key);
+        match after.iter().find(|new| new.key == old.key && new.id == old.id) {
+            None => diff.removed.push(old.clone()),
+            Some(new) if new.content != old.content => diff.changed.push(ChangedFact {
+                id: old.id.clone(),
+                before: old.content.clone(),
+                after: new.content.clone(),
+            }),
+            Some(_) => diff.unchanged += 1,
+        }
+    }
+    for new in after {
+        if !before.iter().any(|old| old.key == new.key && old.id == new.id) {
+            entry(&mut keys, &new.key).added.push(new.clone());
+        }
+    }
+    keys
+}
+
+/// Width of each side in the human-readable side-by-side output.
+const COLUMN_WIDTH: usize = 48;
+
+fn column(text: &str) -> String {
+    let text = if text.chars().count() > COLUMN_WIDTH {
+        let mut cut: String = text.chars().take(COLUMN_WIDTH - 1).collect();
+        cut.push('…');
+        cut
+    } else {
+        text.to_string()
+    };
+    format!("{:<width$}", text, width = COLUMN_WIDTH)
+}
+
+/// Prints a diff side by side: first run on the left, second on the right.
+pub fn print_diff(diff: &RunDiff) {
+    println!("\n=== Run Diff ===");
+    println!("{} | {}", column(&diff.before.label), diff.after.label);
+    println!(
+        "{} | converged: {}",
+        column(&format!("converged: {}", diff.before.converged)),
+        diff.after.converged
+    );
+    println!(
+        "{} | cycles: {}",
+        column(&format!("cycles: {}", diff.before.cycles)),
+        diff.after.cycles
+    );
+    println!(
+        "{} | facts: {}",
+        column(&format!("facts: {}", diff.before.total_facts)),
+        diff.after.total_facts
+    );
+
+    for key in diff.keys.iter().filter(|k| !k.is_empty()) {
+        println!("\n[{}] ({} unchanged)", key.key, key.unchanged);
+        for fact in &key.removed {
+            println!("- {}", fact.id);
+            println!("  {} |", column(&fact.content));
+        }
+        for fact in &key.added {
+            println!("+ {}", fact.id);
+            println!("  {} | {}", column(""), fact.content);
+        }
+        for fact in &key.changed {
+            println!("~ {}", fact.id);
+            println!("  {} | {}", column(&fact.before), fact.after);
+        }
+    }
+
+    let (added, removed, changed) = diff.totals();
+    println!("\n{} added, {} removed, {} changed", added, removed, changed);
+    if diff.is_identical() {
+        println!("Runs are identical");
+    }
+    println!("================");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn side(label: &str, cycles: u32) -> RunSide {
+        RunSide {
+            label: label.to_string(),
+            converged: true,
+            cycles,
+            total_facts: 0,
+        }
+    }
+
+    #[test]
+    fn classifies_added_removed_and_changed_by_key_and_id() {
+        let before = vec![
+            FactOutput::test("Seeds", "seed:1", "Nordic B2B"),
+            FactOutput::test("Strategies", "strategy:1", "Expand"),
+            FactOutput::test("Strategies", "strategy:2", "Partner"),
+        ];
+        let after = vec![
+            FactOutput::test("Seeds", "seed:1", "Nordic B2B"),
+            FactOutput::test("Strategies", "strategy:1", "Expand to Germany"),
+            FactOutput::test("Strategies", "strategy:3", "Self-serve"),
+        ];
+
+        let diff = RunDiff::new(side("a", 3), side("b", 3), &before, &after);
+
+        assert!(diff.keys[0].is_empty());
+        assert_eq!(diff.keys[0].unchanged, 1);
+        let strategies = &diff.keys[1];
+        assert_eq!(strategies.removed[0].id, "strategy:2");
+        assert_eq!(strategies.added[0].id, "strategy:3");
+        assert_eq!(strategies.changed[0].after, "Expand to Germany");
+        assert_eq!(diff.totals(), (1, 1, 1));
+        assert!(!diff.is_identical());
+    }
+
+    #[test]
+    fn same_id_under_different_keys_is_not_a_match() {
+        let before = vec![FactOutput::test("Signals", "x", "a")];
+        let after = vec![FactOutput::test("Hypotheses", "x", "a")];
+
+        let diff = RunDiff::new(side("a", 2), side("b", 2), &before, &after);
+
+        assert_eq!(diff.totals(), (1, 1, 0));
+    }
+
+    #[test]
+    fn cycle_difference_alone_is_not_identical() {
+        let facts = vec![FactOutput::test("Seeds", "seed:1", "x")];
+        let diff = RunDiff::new(side("a", 2), side("b", 3), &facts, &facts);
+        assert!(!diff.is_identical());
+
+        let diff = RunDiff::new(side("a", 2), side("b", 2), &facts, &facts);
+        assert!(diff.is_identical());
+    }
+}
@@ -0,0 +1,375 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! Semantic assertions in eval fixtures.
+//!
+//! Beyond the shape checks of `EvalExpectation` (id prefixes, totals), a
+//! fixture can assert on what the facts say and how they relate:
+//!
+//! ```json
+//! "expected": {
+//!   "content": [
+//!     {"key": "Strategies", "matches": "(?i)smb|small business"},
+//!     {"key": "Constraints", "must_not_match": "(?i)guaranteed"}
+//!   ],
+//!   "counts": {"Strategies": {"min": 2, "max": 5}, "Competitors": 2},
+//!   "relationships": [{"each": "strategy:", "has": "eval:"}],
+//!   "ordering": [{"first": "signal:", "then": "strategy:"}]
+//! }
+//! ```
+//!
+//! - **content**: `matches` needs at least one fact under the key (every
+//!   fact with `"all": true`) to match the regex; `must_not_match` fails on
+//!   any match. `id_prefix` narrows the facts considered.
+//! - **counts**: an exact count or a `min`/`max` range per context key.
+//! - **relationships**: every fact whose id starts with `each` is referred
+//!   to by a fact whose id starts with `has`: the referring fact's id ends
+//!   in `:<id>` or its content mentions the id as a whole word (the rule
+//!   reports use to group evaluations under strategies).
+//! - **ordering**: every `first` fact was produced in an earlier cycle than
+//!   any `then` fact, from fact provenance (seeds count as cycle 0).
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use converge_core::ContextKey;
+use strum::IntoEnumIterator;
+
+use crate::evals::{EvalCheck, EvalExpectation};
+use crate::output::FactOutput;
+
+/// Regex assertion over the content of one key's facts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ContentAssertion {
+    /// Context key, e.g. "Strategies"
+    pub key: String,
+
+    /// Only consider facts whose id starts with this prefix
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id_prefix: Option<String>,
+
+    /// Regex that fact content must match
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub matches: Option<String>,
+
+    /// Every considered fact must match (default: at least one)
+    #[serde(default)]
+    pub all: bool,
+
+    /// Regex that no fact content may match
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub must_not_match: Option<String>,
+}
+
+/// Expected number of facts under a key: exact (`3`) or a range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged, deny_unknown_fields)]
+pub enum CountExpectation {
+    Exactly(usize),
+    Range {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min: Option<usize>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max: Option<usize>,
+    },
+}
+
+impl CountExpectation {
+    fn accepts(self, count: usize) -> bool {
+        match self {
+            CountExpectation::Exactly(n) => count == n,
+            CountExpectation::Range { min, max } => {
+                min.is_none_or(|min| count >= min) && max.is_none_or(|max| count <= max)
+            }
+        }
+    }
+
+    fn describe(self) -> String {
+        match self {
+            CountExpectation::Exactly(n) => format!("exactly {}", n),
+            CountExpectation::Range { min: Some(min), max: Some(max) } => format!("{}..={}", min, max),
+            CountExpectation::Range { min: Some(min), max: None } => format!(">= {}", min),
+            CountExpectation::Range { min: None, max: Some(max) } => format!("<= {}", max),
+            CountExpectation::Range { min: None, max: None } => "any".to_string(),
+        }
+    }
+}
+
+/// Every `each` fact must be referred to by some `has` fact.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Relationship {
+    /// Id prefix of the facts that need a counterpart
+    pub each: String,
+    /// Id prefix of the counterpart facts
+    pub has: String,
+}
+
+/// Every `first` fact must be produced before any `then` fact.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Ordering {
+    /// Id prefix of the facts expected earlier
+    pub first: String,
+    /// Id prefix of the facts expected later
+    pub then: String,
+}
+
+/// Checks a fixture's semantic assertions against a run's facts.
+pub fn check(expected: &EvalExpectation, facts: &[FactOutput]) -> Vec<EvalCheck> {
+    let mut checks = Vec::new();
+    for assertion in &expected.content {
+        check_content(assertion, facts, &mut checks);
+    }
+    for (key, count) in &expected.counts {
+        checks.push(check_count(key, *count, facts));
+    }
+    for relationship in &expected.relationships {
+        checks.push(check_relationship(relationship, facts));
+    }
+    for ordering in &expected.ordering {
+        checks.push(check_ordering(ordering, facts));
+    }
+    checks
+}
+
+/// Returns true if `key` names a context key.
+pub fn is_context_key(key: &str) -> bool {
+    ContextKey::iter().any(|k| format!("{:?}", k) == key)
+}
+
+fn unknown_key(name: String, key: &str) -> EvalCheck {
+    EvalCheck {
+        name,
+        passed: false,
+        expected: "a context key".to_string(),
+        actual: format!("unknown key '{}'", key),
+    }
+}
+
+fn check_content(assertion: &ContentAssertion, facts: &[FactOutput], checks: &mut Vec<EvalCheck>) {
+    let scope = match assertion.id_prefix {
+        Some(ref prefix) => format!("{}[{}*]", assertion.key, prefix),
+        None => assertion.key.clone(),
+    };
+    if !is_context_key(&assertion.key) {
+        checks.push(unknown_key(format!("content:{}", scope), &assertion.key));
+        return;
+    }
+    let considered: Vec<&FactOutput> = facts
+        .iter()
+        .filter(|f| f.key == assertion.key)
+        .filter(|f| assertion.id_prefix.as_ref().is_none_or(|p| f.id.starts_with(p.as_str())))
+        .collect();
+
+    if let Some(ref pattern) = assertion.matches {
+        let name = format!("matches:{}", scope);
+        checks.push(match Regex::new(pattern) {
+            Err(e) => invalid_regex(name, e),
+            Ok(regex) => {
+                let matching = considered.iter().filter(|f| regex.is_match(&f.content)).count();
+                let passed = if assertion.all {
+                    !considered.is_empty() && matching == considered.len()
+                } else {
+                    matching > 0
+                };
+                let quantifier = if assertion.all { "every" } else { "a" };
+                EvalCheck {
+                    name,
+                    passed,
+                    expected: format!("{} fact matching /{}/", quantifier, pattern),
+                    actual: format!("{} of {} match", matching, considered.len()),
+                }
+            }
+        });
+    }
+
+    if let Some(ref pattern) = assertion.must_not_match {
+        let name = format!("not_matches:{}", scope);
+        checks.push(match Regex::new(pattern) {
+            Err(e) => invalid_regex(name, e),
+            Ok(regex) => {
+                let offending: Vec<&str> = considered
+                    .iter()
+                    .filter(|f| regex.is_match(&f.content))
+                    .map(|f| f.id.as_str())
+                    .collect();
+                EvalCheck {
+                    name,
+                    passed: offending.is_empty(),
+                    expected: format!("no fact matching /{}/", pattern),
+                    actual: if offending.is_empty() {
+                        "none match".to_string()
+                    } else {
+                        format!("matched by {}", offending.join(", "))
+                    },
+                }
+            }
+        });
+    }
+}
+
+fn invalid_regex(name: String, error: regex::Error) -> EvalCheck {
+    EvalCheck {
+        name,
+        passed: false,
+        expected: "a valid regex".to_string(),
+        actual: format!("invalid regex: {}", error),
+    }
+}
+
+fn check_count(key: &str, expected: CountExpectation, facts: &[FactOutput]) -> EvalCheck {
+    let name = format!("count:{}", key);
+    if !is_context_key(key) {
+        return unknown_key(name, key);
+    }
+    let count = facts.iter().filter(|f| f.key == key).count();
+    EvalCheck {
+        name,
+        passed: expected.accepts(count),
+        expected: expected.describe(),
+        actual: count.to_string(),
+    }
+}
+
+fn check_relationship(relationship: &Relationship, facts: &[FactOutput]) -> EvalCheck {
+    let sources: Vec<&FactOutput> = facts.iter().filter(|f| f.id.starts_with(&relationship.each)).collect();
+    let targets: Vec<&FactOutput> = facts.iter().filter(|f| f.id.starts_with(&relationship.has)).collect();
+    let orphans: Vec<&str> = sources
+        .iter()
+        .filter(|source| {
+            !targets
+                .iter()
+                .any(|t| t.refers_to(&source.id))
+        })
+        .map(|source| source.id.as_str())
+        .collect();
+
+    EvalCheck {
+        name: format!("each:{}->{}", relationship.each, relationship.has),
+        passed: !sources.is_empty() && orphans.is_empty(),
+        expected: format!("every {}* referred to by a {}*", relationship.each, relationship.has),
+        actual: if sources.is_empty() {
+            format!("no {}* facts", relationship.each)
+        } else if orphans.is_empty() {
+            format!("all {} have one", sources.len())
+        } else {
+            format!("missing for {}", orphans.join(", "))
+        },
+    }
+}
+
+fn check_ordering(ordering: &Ordering, facts: &[FactOutput]) -> EvalCheck {
+    let cycle = |f: &FactOutput| f.provenance.as_ref().map_or(0, |p| p.cycle);
+    let last_first = facts
+        .iter()
+        .filter(|f| f.id.starts_with(&ordering.first))
+        .map(cycle)
+        .max();
+    let first_then = facts
+        .iter()
+        .filter(|f| f.id.starts_with(&ordering.then))
+        .map(cycle)
+        .min();
+
+    let (passed, actual) = match (last_first, first_then) {
+        (Some(a), Some(b)) => (a < b, format!("last {}* in cycle {}, first {}* in cycle {}", ordering.first, a, ordering.then, b)),
+        (None, _) => (false, format!("no {}* facts", ordering.first)),
+        (_, None) => (false, format!("no {}* facts", ordering.then)),
+    };
+    EvalCheck {
+        name: format!("order:{}<{}", ordering.first, ordering.then),
+        passed,
+        expected: format!("all {}* before any {}*", ordering.first, ordering.then),
+        actual,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provenance::FactProvenance;
+
+    fn fact(key: &str, id: &str, content: &str, cycle: u32) -> FactOutput {
+        FactOutput {
+            provenance: (cycle > 0).then(|| FactProvenance {
+                agent: "Agent".to_string(),
+                cycle,
+                derived_from: Vec::new(),
+            }),
+            ..FactOutput::test(key, id, content)
+        }
+    }
+
+    fn facts() -> Vec<FactOutput> {
+        vec![
+            fact("Signals", "signal:1", "SMB demand is rising", 1),
+            fact("Strategies", "strategy:smb", "Target small business owners", 2),
+            fact("Strategies", "strategy:ent", "Expand enterprise sales", 2),
+            fact("Evaluations", "eval:strategy:smb", "Strong fit", 3),
+        ]
+    }
+
+    #[test]
+    fn parses_exact_and_range_counts() {
+        let expected: EvalExpectation =
+            serde_json::from_str(r#"{"counts": {"Strategies": 2, "Signals": {"min": 1}}}"#).unwrap();
+        assert_eq!(expected.counts["Strategies"], CountExpectation::Exactly(2));
+
+        let checks = check(&expected, &facts());
+        assert!(checks.iter().all(|c| c.passed), "{:?}", checks);
+        assert_eq!(checks[0].expected, ">= 1");
+    }
+
+    #[test]
+    fn content_assertions_match_and_reject() {
+        let expected: EvalExpectation = serde_json::from_str(
+            r#"{"content": [
+                {"key": "Strategies", "matches": "(?i)small business"},
+                {"key": "Strategies", "matches": "(?i)small business", "all": true},
+                {"key": "Signals", "must_not_match": "SMB"},
+                {"key": "Strategies", "matches": "("},
+                {"key": "Nope", "matches": "x"}
+            ]}"#,
+        )
+        .unwrap();
+
+        let passed: Vec<bool> = check(&expected, &facts()).iter().map(|c| c.passed).collect();
+        assert_eq!(passed, [true, false, false, false, false]);
+    }
+
+    #[test]
+    fn relationships_and_ordering() {
+        let expected: EvalExpectation = serde_json::from_str(
+            r#"{
+                "relationships": [{"each": "strategy:", "has": "eval:"}],
+                "ordering": [{"first": "signal:", "then": "strategy:"}, {"first": "eval:", "then": "strategy:"}]
+            }"#,
+        )
+        .unwrap();
+
+        let checks = check(&expected, &facts());
+        assert!(!checks[0].passed);
+        assert_eq!(checks[0].actual, "missing for strategy:ent");
+        assert!(checks[1].passed);
+        assert!(!checks[2].passed);
+    }
+
+    #[test]
+    fn relationships_compare_whole_ids() {
+        let expected: EvalExpectation =
+            serde_json::from_str(r#"{"relationships": [{"each": "strategy:", "has": "eval:"}]}"#).unwrap();
+        let facts = vec![
+            fact("Strategies", "strategy:1", "Target small business owners", 2),
+            fact("Strategies", "strategy:10", "Expand enterprise sales", 2),
+            fact("Evaluations", "eval:strategy:10", "Strong fit", 3),
+        ];
+
+        let checks = check(&expected, &facts);
+        assert!(!checks[0].passed);
+        assert_eq!(checks[0].actual, "missing for strategy:1");
+    }
+}
@@ -0,0 +1,437 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! Eval baselines and regression detection.
+//!
+//! `converge eval run --save-baseline` stores each fixture's metrics as
+//! `evals/baselines/<eval_id>.json`; `--compare-baseline` compares a new
+//! run against them and fails when a metric regresses beyond the fixture's
+//! tolerances:
+//!
+//! | Metric          | Regression                   | Default tolerance |
+//! |-----------------|------------------------------|-------------------|
+//! | cycles          | more cycles                  | 0                 |
+//! | facts per key   | fewer facts under a key      | 0%                |
+//! | latency         | slower                       | 50% (and 25ms)    |
+//! | tokens          | more LLM tokens              | 20%               |
+//! | context hash    | different final context      | mock LLM only     |
+//!
+//! Fixtures override the defaults with a `baseline_tolerance` section. The
+//! context hash is only compared for mock-LLM fixtures, whose output is
+//! deterministic. With `--repeat`, latency and tokens are medians.
+//!
+//! Baselines are saved from passing runs only and record whether the mock
+//! LLM was used; a run is not compared with a baseline from the other mode.
+
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use crate::evals::{EvalFixture, EvalResult, Palette};
+
+/// Default baseline directory, relative to the working directory.
+pub const DEFAULT_BASELINES_DIR: &str = "evals/baselines";
+
+/// Latency differences below this are noise, whatever the percentage.
+const LATENCY_SLACK_MS: u64 = 25;
+
+/// Allowed regressions before a comparison fails.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Tolerances {
+    /// Extra cycles allowed
+    pub cycles: u32,
+    /// Allowed drop in facts per key, in percent
+    pub facts_pct: f64,
+    /// Allowed latency increase, in percent
+    pub latency_pct: f64,
+    /// Allowed token increase, in percent
+    pub tokens_pct: f64,
+    /// Accept a different final context (mock-LLM fixtures)
+    pub allow_context_change: bool,
+}
+
+impl Default for Tolerances {
+    fn default() -> Self {
+        Self {
+            cycles: 0,
+            facts_pct: 0.0,
+            latency_pct: 50.0,
+            tokens_pct: 20.0,
+            allow_context_change: false,
+        }
+    }
+}
+
+/// Stored metrics of one fixture.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub eval_id: String,
+    pub created_at: String,
+    pub cycles: u32,
+    pub facts_by_key: BTreeMap<String, usize>,
+    pub latency_ms: u64,
+    pub tokens: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_hash: Option<String>,
+    /// The runs used the mock LLM (`None` in baselines saved before this
+    /// was recorded)
+    #[serde(default)]
+    pub mock: Option<bool>,
+}
+
+impl Baseline {
+    /// Metrics of a fixture's runs (medians for latency and tokens).
+    pub fn of(fixture: &EvalFixture, runs: &[&EvalResult]) -> Option<Self> {
+        let first = runs.first()?;
+        let median = |mut values: Vec<u64>| {
+            values.sort_unstable();
+            values[(values.len() - 1) / 2]
+        };
+        Some(Self {
+            eval_id: fixture.eval_id.clone(),
+            created_at: chrono::Utc::now().to_rfc3339(),
+            cycles: first.cycles,
+            facts_by_key: first.facts_by_key.clone(),
+            latency_ms: median(runs.iter().map(|r| r.duration.as_millis() as u64).collect()),
+            tokens: median(runs.iter().map(|r| r.tokens).collect()),
+            context_hash: first.context_hash.as_ref().map(|h| h.overall.clone()),
+            mock: Some(fixture.use_mock_llm),
+        })
+    }
+}
+
+/// Directory of baselines, one JSON file per eval id.
+#[derive(Debug, Clone)]
+pub struct BaselineStore {
+    dir: PathBuf,
+}
+
+impl BaselineStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path_for(&self, eval_id: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", eval_id))
+    }
+
+    /// Saves a baseline, replacing the previous one.
+    pub fn save(&self, baseline: &Baseline) -> Result<PathBuf> {
+        std::fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create '{}'", self.dir.display()))?;
+        let path = self.path_for(&baseline.eval_id);
+        std::fs::write(&path, serde_json::to_string_pretty(baseline)?)
+            .with_context(|| format!("Failed to write '{}'", path.display()))?;
+        Ok(path)
+    }
+
+    /// Loads the baseline of `eval_id`, if one was saved.
+    pub fn load(&self, eval_id: &str) -> Result<Option<Baseline>> {
+        let path = self.path_for(eval_id);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read '{}'", path.display()))?;
+        let baseline = serde_json::from_str(&content)
+            .with_context(|| format!("'{}' is not an eval baseline", path.display()))?;
+        Ok(Some(baseline))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+}
+
+/// One row of the delta table.
+#[derive(Debug, Clone, Serialize)]
+pub struct MetricDelta {
+    pub metric: String,
+    pub baseline: String,
+    pub current: String,
+    pub delta: String,
+    pub regressed: bool,
+}
+
+/// Comparison of one fixture against its baseline.
+#[derive(Debug, Clone, Serialize)]
+pub struct BaselineComparison {
+    pub eval_id: String,
+    /// No baseline was saved for the fixture
+    pub missing: bool,
+    /// Why the fixture was not compared with its baseline
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skipped: Option<String>,
+    pub deltas: Vec<MetricDelta>,
+}
+
+impl BaselineComparison {
+    pub fn regressed(&self) -> bool {
+        self.deltas.iter().any(|d| d.regressed)
+    }
+}
+
+fn percent_change(baseline: u64, current: u64) -> f64 {
+    if baseline == 0 {
+        if current == 0 { 0.0 } else { 100.0 }
+    } else {
+        (current as f64 - baseline as f64) / baseline as f64 * 100.0
+    }
+}
+
+fn signed(delta: i64) -> String {
+    format!("{:+}", delta)
+}
+
+/// Compares current metrics with a baseline.
+pub fn compare(baseline: &Baseline, current: &Baseline, tolerances: &Tolerances, deterministic: bool) -> Vec<MetricDelta> {
+    let mut deltas = vec![MetricDelta {
+        metric: "cycles".to_string(),
+        baseline: baseline.cycles.to_string(),
+        current: current.cycles.to_string(),
+        delta: signed(i64::from(current.cycles) - i64::from(baseline.cycles)),
+        regressed: current.cycles > baseline.cycles + tolerances.cycles,
+    }];
+
+    let keys: BTreeSet<&String> = baseline.facts_by_key.keys().chain(current.facts_by_key.keys()).collect();
+    for key in keys {
+        let before = baseline.facts_by_key.get(key).copied().unwrap_or(0);
+        let after = current.facts_by_key.get(key).copied().unwrap_or(0);
+        let drop_pct = -percent_change(before as u64, after as u64);
+        deltas.push(MetricDelta {
+            metric: format!("facts:{}", key),
+            baseline: before.to_string(),
+            current: after.to_string(),
+            delta: signed(after as i64 - before as i64),
+            regressed: after < before && drop_pct > tolerances.facts_pct,
+        });
+    }
+
+    let latency_change = percent_change(baseline.latency_ms, current.latency_ms);
+    deltas.push(MetricDelta {
+        metric: "latency_ms".to_string(),
+        baseline: baseline.latency_ms.to_string(),
+        current: current.latency_ms.to_string(),
+        delta: format!("{:+.0}%", latency_change),
+        regressed: latency_change > tolerances.latency_pct
+            && current.latency_ms > baseline.latency_ms + LATENCY_SLACK_MS,
+    });
+
+    let token_change = percent_change(baseline.tokens, current.tokens);
+    deltas.push(MetricDelta {
+        metric: "tokens".to_string(),
+        baseline: baseline.tokens.to_string(),
+        current: current.tokens.to_string(),
+        delta: format!("{:+.0}%", token_change),
+        regressed: token_change > tolerances.tokens_pct,
+    });
+
+    if deterministic {
+        let short = |hash: &Option<String>| {
+            hash.as_deref()
+                .map_or_else(|| "-".to_string(), |h| h[..h.len().min(12)].to_string())
+        };
+        let changed = baseline.context_hash != current.context_hash;
+        deltas.push(MetricDelta {
+            metric: "context_hash".to_string(),
+            baseline: short(&baseline.context_hash),
+            current: short(&current.context_hash),
+            delta: if changed { "changed" } else { "same" }.to_string(),
+            regressed: changed && !tolerances.allow_context_change,
+        });
+    }
+    deltas
+}
+
+/// Runs of each fixture, in fixture order.
+fn runs_by_fixture<'a>(
+    fixtures: &'a [EvalFixture],
+    results: &'a [EvalResult],
+) -> impl Iterator<Item = (&'a EvalFixture, Vec<&'a EvalResult>)> {
+    fixtures.iter().map(move |fixture| {
+        let runs = results
+            .iter()
+            .filter(|r| r.eval_id == fixture.eval_id && r.error.is_none() && r.skipped.is_none())
+            .collect();
+        (fixture, runs)
+    })
+}
+
+/// Saves a baseline per fixture from its passing runs in `results`;
+/// fixtures without a passing run are skipped with a warning.
+pub fn save_all(store: &BaselineStore, fixtures: &[EvalFixture], results: &[EvalResult]) -> Result<usize> {
+    let mut saved = 0;
+    for (fixture, runs) in runs_by_fixture(fixtures, results) {
+        let passing: Vec<&EvalResult> = runs.into_iter().filter(|r| r.passed).collect();
+        match Baseline::of(fixture, &passing) {
+            Some(baseline) => {
+                store.save(&baseline)?;
+                saved += 1;
+            }
+            None => tracing::warn!(eval_id = %fixture.eval_id, "No passing run; baseline not saved"),
+        }
+    }
+    Ok(saved)
+}
+
+/// Describes the LLM mode of a baseline.
+fn llm_mode(mock: bool) -> &'static str {
+    if mock { "the mock LLM" } else { "a real LLM" }
+}
+
+/// Compares each fixture's results with its stored baseline; fixtures
+/// whose runs were all skipped are left out.
+pub fn compare_all(store: &BaselineStore, fixtures: &[EvalFixture], results: &[EvalResult]) -> Result<Vec<BaselineComparison>> {
+    let mut comparisons = Vec::new();
+    for (fixture, runs) in runs_by_fixture(fixtures, results) {
+        let all_skipped = results
+            .iter()
+            .filter(|r| r.eval_id == fixture.eval_id)
+            .all(|r| r.skipped.is_some());
+        if all_skipped {
+            continue;
+        }
+        let baseline = store.load(&fixture.eval_id)?;
+        let recorded_mock = baseline.as_ref().and_then(|b| b.mock);
+        if let Some(recorded) = recorded_mock.filter(|&mock| mock != fixture.use_mock_llm) {
+            comparisons.push(BaselineComparison {
+                eval_id: fixture.eval_id.clone(),
+                missing: false,
+                skipped: Some(format!(
+                    "baseline was saved with {}, this run used {}",
+                    llm_mode(recorded),
+                    llm_mode(fixture.use_mock_llm)
+                )),
+                deltas: Vec::new(),
+            });
+            continue;
+        }
+        let deltas = match (baseline, Baseline::of(fixture, &runs)) {
+            (Some(baseline), Some(current)) => {
+                let tolerances = fixture.baseline_tolerance.clone().unwrap_or_default();
+                compare(&baseline, &current, &tolerances, fixture.use_mock_llm)
+            }
+            (Some(_), None) => vec![MetricDelta {
+                metric: "run".to_string(),
+                baseline: "ok".to_string(),
+                current: "error".to_string(),
+                delta: "-".to_string(),
+                regressed: true,
+            }],
+            (None, _) => {
+                comparisons.push(BaselineComparison {
+                    eval_id: fixture.eval_id.clone(),
+                    missing: true,
+                    skipped: None,
+                    deltas: Vec::new(),
+                });
+                continue;
+            }
+        };
+        comparisons.push(BaselineComparison {
+            eval_id: fixture.eval_id.clone(),
+            missing: false,
+            skipped: None,
+            deltas,
+        });
+    }
+    Ok(comparisons)
+}
+
+/// Prints the delta table, with ANSI colours if `color`.
+pub fn print_comparisons(comparisons: &[BaselineComparison], color: bool) {
+    let Palette { red, reset, .. } = Palette::new(color);
+
+    println!("\n=== Baseline Comparison ===\n");
+    for comparison in comparisons {
+        if comparison.missing {
+            println!("{}: no baseline (run with --save-baseline)", comparison.eval_id);
+            continue;
+        }
+        if let Some(ref reason) = comparison.skipped {
+            println!("{}: not compared ({})", comparison.eval_id, reason);
+            continue;
+        }
+        let status = if comparison.regressed() { "REGRESSED" } else { "ok" };
+        println!("{}: {}", comparison.eval_id, status);
+        println!("  {:<24} {:>14} {:>14} {:>10}", "metric", "baseline", "current", "delta");
+        for d in &comparison.deltas {
+            let (start, end) = if d.regressed { (red, reset) } else { ("", "") };
+            println!(
+                "  {}{:<24} {:>14} {:>14} {:>10}{}",
+                start, d.metric, d.baseline, d.current, d.delta, end
+            );
+        }
+        println!();
+    }
+    println!("===========================\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn baseline(cycles: u32, strategies: usize, latency_ms: u64, tokens: u64, hash: &str) -> Baseline {
+        Baseline {
+            eval_id: "smb".to_string(),
+            created_at: String::new(),
+            cycles,
+            facts_by_key: [("Strategies".to_string(), strategies)].into(),
+            latency_ms,
+            tokens,
+            context_hash: Some(hash.to_string()),
+            mock: Some(true),
+        }
+    }
+
+    fn regressed(deltas: &[MetricDelta]) -> Vec<&str> {
+        deltas.iter().filter(|d| d.regressed).map(|d| d.metric.as_str()).collect()
+    }
+
+    #[test]
+    fn within_tolerances_is_not_a_regression() {
+        let before = baseline(4, 3, 100, 1000, "abc");
+        let after = baseline(4, 4, 140, 1100, "abc");
+        assert!(regressed(&compare(&before, &after, &Tolerances::default(), true)).is_empty());
+    }
+
+    #[test]
+    fn flags_each_regressed_metric() {
+        let before = baseline(4, 3, 100, 1000, "abc");
+        let after = baseline(5, 2, 400, 1500, "def");
+
+        let deltas = compare(&before, &after, &Tolerances::default(), true);
+        assert_eq!(regressed(&deltas), ["cycles", "facts:Strategies", "latency_ms", "tokens", "context_hash"]);
+        assert_eq!(deltas[0].delta, "+1");
+
+        // Real-LLM fixtures never compare the context
+        let deltas = compare(&before, &after, &Tolerances::default(), false);
+        assert!(deltas.iter().all(|d| d.metric != "context_hash"));
+    }
+
+    #[test]
+    fn saves_passing_runs_and_skips_mismatched_llm_modes() {
+        let dir = std::env::temp_dir().join(format!("converge-baselines-{}", uuid::Uuid::new_v4()));
+        let store = BaselineStore::new(&dir);
+        let mut fixture = EvalFixture::test("smb");
+        let run = |passed: bool| EvalResult::test("smb", passed);
+
+        assert_eq!(save_all(&store, std::slice::from_ref(&fixture), &[run(false)]).unwrap(), 0);
+        assert_eq!(save_all(&store, std::slice::from_ref(&fixture), &[run(true), run(false)]).unwrap(), 1);
+        assert_eq!(store.load("smb").unwrap().unwrap().mock, Some(true));
+
+        fixture.use_mock_llm = false;
+        let comparisons = compare_all(&store, std::slice::from_ref(&fixture), &[run(true)]).unwrap();
+        assert_eq!(
+            comparisons[0].skipped.as_deref(),
+            Some("baseline was saved with the mock LLM, this run used a real LLM")
+        );
+        assert!(!comparisons[0].regressed());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
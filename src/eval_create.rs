@@ -0,0 +1,170 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! Eval fixtures generated from recorded runs.
+//!
+//! `converge eval create --from-run <run.json|run_id>` turns a saved
+//! [`RunOutput`] into an [`EvalFixture`]: the run's seeds and pack, and
+//! expectations inferred from what it produced:
+//!
+//! - `converged` and `max_cycles` as observed
+//! - `must_contain_facts`: the id prefix (up to the first `:`) of every
+//!   produced fact, e.g. `strategy:`
+//! - `required_context_keys`: every key the run produced facts for
+//!
+//! With `--with-cassette` the LLM responses recorded with the run are
+//! copied next to the fixture, and the eval replays them instead of calling
+//! the provider. The fixture is a starting point; tighten the expectations
+//! before committing it.
+
+use anyhow::{Context as _, Result};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use crate::evals::{EvalExpectation, EvalFixture, SeedFact};
+use crate::output::RunOutput;
+use crate::store::RunStore;
+
+/// Directory of fixture cassettes, relative to the fixtures directory.
+pub const CASSETTES_DIR: &str = "cassettes";
+
+/// Id prefix of a fact: up to and including the first `:`, or the whole id.
+fn id_prefix(id: &str) -> &str {
+    id.find(':').map_or(id, |i| &id[..=i])
+}
+
+/// Default eval id of a fixture created from `output`.
+pub fn default_eval_id(output: &RunOutput) -> String {
+    let template = if output.template.is_empty() { "run" } else { &output.template };
+    let short_id: String = output.run_id.chars().filter(char::is_ascii_alphanumeric).take(8).collect();
+    format!("{}_{}", template, short_id).replace('-', "_")
+}
+
+/// A fixture reproducing `output`'s seeds and pack, expecting what it
+/// produced.
+pub fn fixture_from_run(output: &RunOutput, eval_id: &str, use_mock_llm: bool) -> EvalFixture {
+    let (seeds, produced): (Vec<_>, Vec<_>) = output.facts.iter().partition(|f| f.key == "Seeds");
+
+    let prefixes: BTreeSet<&str> = produced.iter().map(|f| id_prefix(&f.id)).collect();
+    let keys: BTreeSet<&str> = produced.iter().map(|f| f.key.as_str()).collect();
+    let mut expected: EvalExpectation = serde_json::from_str("{}").expect("all expectations are optional");
+    expected.converged = Some(output.result.converged);
+    expected.max_cycles = Some(output.result.cycles);
+    expected.must_contain_facts = prefixes.into_iter().map(str::to_string).collect();
+    expected.required_context_keys = keys.into_iter().map(str::to_string).collect();
+
+    EvalFixture {
+        schema: None,
+        eval_id: eval_id.to_string(),
+        description: format!("Generated from run {} ({})", output.run_id, output.timestamp),
+        pack: output.template.clone(),
+        tags: Vec::new(),
+        seeds: seeds
+            .into_iter()
+            .map(|f| SeedFact {
+                id: f.id.clone(),
+                content: f.content.clone(),
+            })
+            .collect(),
+        expected,
+        use_mock_llm,
+        min_pass_rate: None,
+        baseline_tolerance: None,
+        judge: None,
+        snapshot: None,
+        cassette: None,
+        min_confidence: None,
+        source: None,
+    }
+}
+
+/// Whether a recorded run used the mock LLM: from its manifest if there is
+/// one, otherwise from the recorded provider.
+fn used_mock_llm(store: &RunStore, output: &RunOutput) -> bool {
+    match store.resolve_manifest(&output.run_id) {
+        Ok((manifest, _)) => manifest.mock,
+        Err(_) => output.provider.as_ref().is_none_or(|p| p.name.starts_with("mock")),
+    }
+}
+
+/// Creates a fixture from the run `reference` and writes it to
+/// `<dir>/<eval_id>.json`, copying the run's cassette alongside if
+/// `with_cassette`. Returns the fixture path.
+pub fn create(
+    store: &RunStore,
+    reference: &str,
+    dir: &Path,
+    eval_id: Option<&str>,
+    with_cassette: bool,
+    force: bool,
+) -> Result<PathBuf> {
+    let output = store.resolve(reference)?;
+    let eval_id = eval_id.map_or_else(|| default_eval_id(&output), str::to_string);
+    let path = dir.join(format!("{}.json", eval_id));
+    if path.exists() && !force {
+        anyhow::bail!("'{}' already exists (use --force to overwrite)", path.display());
+    }
+
+    let mock = used_mock_llm(store, &output);
+    let mut fixture = fixture_from_run(&output, &eval_id, mock);
+
+    if with_cassette && mock {
+        tracing::warn!(run_id = %output.run_id, "Run used the mock LLM; not attaching a cassette");
+    } else if with_cassette {
+        let (manifest, manifest_path) = store
+            .resolve_manifest(&output.run_id)
+            .with_context(|| format!("Run {} has no recorded cassette", output.run_id))?;
+        let source = manifest.cassette_path(&manifest_path);
+        let relative = format!("{}/{}.cassette.json", CASSETTES_DIR, eval_id);
+        let target = dir.join(&relative);
+        std::fs::create_dir_all(dir.join(CASSETTES_DIR))
+            .with_context(|| format!("Failed to create '{}'", dir.join(CASSETTES_DIR).display()))?;
+        std::fs::copy(&source, &target)
+            .with_context(|| format!("Failed to copy '{}' to '{}'", source.display(), target.display()))?;
+        fixture.cassette = Some(relative);
+        fixture.min_confidence = manifest.min_confidence;
+    }
+
+    std::fs::create_dir_all(dir).with_context(|| format!("Failed to create '{}'", dir.display()))?;
+    std::fs::write(&path, serde_json::to_string_pretty(&fixture)? + "\n")
+        .with_context(|| format!("Failed to write '{}'", path.display()))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::FactOutput;
+
+    fn output() -> RunOutput {
+        let mut output = RunOutput::test(vec![
+            FactOutput::test("Seeds", "market:nordic", "Nordic SMB market"),
+            FactOutput::test("Strategies", "strategy:smb", "Self-serve SMB plan"),
+            FactOutput::test("Strategies", "strategy:partners", "Partner channel"),
+            FactOutput::test("Evaluations", "eval:strategy:smb", "Score: 80/100"),
+        ]);
+        output.run_id = "3f2a9c1e-0000-4000-8000-000000000000".to_string();
+        output.result.cycles = 4;
+        output
+    }
+
+    #[test]
+    fn infers_expectations_from_the_run() {
+        let fixture = fixture_from_run(&output(), "smb", true);
+
+        assert_eq!(fixture.pack, "growth-strategy");
+        assert_eq!(fixture.seeds.len(), 1);
+        assert_eq!(fixture.seeds[0].id, "market:nordic");
+        assert_eq!(fixture.expected.converged, Some(true));
+        assert_eq!(fixture.expected.max_cycles, Some(4));
+        assert_eq!(fixture.expected.must_contain_facts, ["eval:", "strategy:"]);
+        assert_eq!(fixture.expected.required_context_keys, ["Evaluations", "Strategies"]);
+    }
+
+    #[test]
+    fn default_eval_id_uses_template_and_run_id() {
+        assert_eq!(default_eval_id(&output()), "growth_strategy_3f2a9c1e");
+    }
+}
@@ -0,0 +1,188 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! Selecting which eval fixtures to run.
+//!
+//! Fixtures carry free-form `tags` (`smoke`, `real-llm`, `latency`, ...).
+//! `converge eval run` selects fixtures by eval id or glob (`growth_*`),
+//! by `--tag` (any of) and `--exclude-tag`, or by a named suite from
+//! `suites.json` in the fixtures directory:
+//!
+//! ```json
+//! {
+//!   "suites": {
+//!     "ci": {"description": "Every pull request", "exclude_tags": ["real-llm"]},
+//!     "nightly": {"include": ["growth_strategy_*"]}
+//!   }
+//! }
+//! ```
+//!
+//! A suite is a filter like the command-line one; when both are given a
+//! fixture must match both.
+
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::evals::EvalFixture;
+
+/// Suite manifest file name, in the fixtures directory.
+pub const SUITES_FILE: &str = "suites.json";
+
+/// Matches `text` against a glob pattern where `*` matches any run of
+/// characters and `?` any single character.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` and the text position it is matched up to
+    let mut star: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    star = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Which fixtures to run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EvalFilter {
+    /// Eval ids or globs; empty selects every fixture
+    pub include: Vec<String>,
+    /// The fixture must have at least one of these tags (if any)
+    pub tags: Vec<String>,
+    /// The fixture must have none of these tags
+    pub exclude_tags: Vec<String>,
+}
+
+impl EvalFilter {
+    pub fn matches(&self, fixture: &EvalFixture) -> bool {
+        let has_tag = |tags: &[String]| fixture.tags.iter().any(|t| tags.contains(t));
+        (self.include.is_empty() || self.include.iter().any(|p| glob_match(p, &fixture.eval_id)))
+            && (self.tags.is_empty() || has_tag(&self.tags))
+            && !has_tag(&self.exclude_tags)
+    }
+}
+
+/// A named selection of fixtures.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Suite {
+    pub description: String,
+    pub include: Vec<String>,
+    pub tags: Vec<String>,
+    pub exclude_tags: Vec<String>,
+}
+
+impl Suite {
+    pub fn filter(&self) -> EvalFilter {
+        EvalFilter {
+            include: self.include.clone(),
+            tags: self.tags.clone(),
+            exclude_tags: self.exclude_tags.clone(),
+        }
+    }
+}
+
+/// The suites defined for a fixtures directory.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SuiteManifest {
+    #[serde(default)]
+    pub suites: BTreeMap<String, Suite>,
+}
+
+impl SuiteManifest {
+    /// Loads `dir/suites.json`; a directory without one has no suites.
+    pub fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(SUITES_FILE);
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read '{}'", path.display()))?;
+        serde_json::from_str(&content).with_context(|| format!("'{}' is not a suite manifest", path.display()))
+    }
+
+    /// The suite called `name`.
+    pub fn suite(&self, name: &str) -> Result<&Suite> {
+        self.suites.get(name).ok_or_else(|| {
+            let known: Vec<&str> = self.suites.keys().map(String::as_str).collect();
+            if known.is_empty() {
+                anyhow::anyhow!("Unknown suite '{}' (no suites defined in {})", name, SUITES_FILE)
+            } else {
+                anyhow::anyhow!("Unknown suite '{}' (available: {})", name, known.join(", "))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(eval_id: &str, tags: &[&str]) -> EvalFixture {
+        EvalFixture {
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            ..EvalFixture::test(eval_id)
+        }
+    }
+
+    #[test]
+    fn globs_match_whole_ids() {
+        assert!(glob_match("growth_*", "growth_strategy_smb_001"));
+        assert!(glob_match("*_smb_00?", "growth_strategy_smb_001"));
+        assert!(glob_match("growth_strategy_smb_001", "growth_strategy_smb_001"));
+        assert!(glob_match("*a*b*", "xxaxxbxx"));
+        assert!(!glob_match("growth_*", "sdr_growth_001"));
+        assert!(!glob_match("*_002", "growth_strategy_smb_001"));
+    }
+
+    #[test]
+    fn filters_by_ids_and_tags() {
+        let smoke = fixture("growth_smb_001", &["smoke"]);
+        let real = fixture("growth_real_llm_001", &["smoke", "real-llm"]);
+        let filter = EvalFilter {
+            tags: vec!["smoke".to_string()],
+            exclude_tags: vec!["real-llm".to_string()],
+            ..Default::default()
+        };
+        assert!(filter.matches(&smoke));
+        assert!(!filter.matches(&real));
+
+        let by_id = EvalFilter {
+            include: vec!["*_real_*".to_string()],
+            ..Default::default()
+        };
+        assert!(!by_id.matches(&smoke));
+        assert!(by_id.matches(&real));
+    }
+
+    #[test]
+    fn unknown_suites_list_the_available_ones() {
+        let manifest: SuiteManifest =
+            serde_json::from_str(r#"{"suites": {"ci": {"exclude_tags": ["real-llm"]}}}"#).unwrap();
+        assert_eq!(manifest.suite("ci").unwrap().filter().exclude_tags, ["real-llm"]);
+        let error = manifest.suite("nightly").unwrap_err().to_string();
+        assert_eq!(error, "Unknown suite 'nightly' (available: ci)");
+    }
+}
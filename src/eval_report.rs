@@ -0,0 +1,347 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! Machine-readable eval reports for CI.
+//!
+//! `converge eval run --report junit:out.xml --report json:out.json` writes
+//! every [`EvalResult`] in addition to the console summary:
+//!
+//! - **JUnit XML**: one `<testsuite>` per eval, one `<testcase>` per check,
+//!   so CI systems list failing checks individually. A run that failed
+//!   before its checks is a `run` test case with an `<error>`, a skipped
+//!   one a `run` test case marked `<skipped>`.
+//! - **JSON**: totals plus every result with its checks, durations in
+//!   milliseconds, and per-fixture statistics when runs were repeated.
+
+use anyhow::{Context as _, Result};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::eval_stats::EvalStats;
+use crate::evals::{EvalCheck, EvalResult};
+use crate::hashing::ContextHash;
+
+/// Eval report format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Junit,
+    Json,
+}
+
+/// A `--report FORMAT:PATH` argument.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportSpec {
+    pub format: ReportFormat,
+    pub path: PathBuf,
+}
+
+impl FromStr for ReportSpec {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (format, path) = s
+            .split_once(':')
+            .ok_or_else(|| anyhow::anyhow!("Expected FORMAT:PATH (e.g. junit:out.xml), got '{}'", s))?;
+        let format = match format.to_ascii_lowercase().as_str() {
+            "junit" | "xml" => ReportFormat::Junit,
+            "json" => ReportFormat::Json,
+            other => anyhow::bail!("Unknown report format '{}' (expected junit or json)", other),
+        };
+        if path.is_empty() {
+            anyhow::bail!("Report '{}' has no path", s);
+        }
+        Ok(Self {
+            format,
+            path: PathBuf::from(path),
+        })
+    }
+}
+
+impl ReportSpec {
+    /// Renders `results` in this spec's format and writes them to its path.
+    pub fn write(&self, results: &[EvalResult], stats: &[EvalStats]) -> Result<()> {
+        let content = match self.format {
+            ReportFormat::Junit => render_junit(results),
+            ReportFormat::Json => serde_json::to_string_pretty(&JsonReport::of(results, stats))?,
+        };
+        write_file(&self.path, &content)
+    }
+}
+
+fn write_file(path: &Path, content: &str) -> Result<()> {
+    if let Some(dir) = path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir).with_context(|| format!("Failed to create '{}'", dir.display()))?;
+    }
+    std::fs::write(path, content).with_context(|| format!("Failed to write '{}'", path.display()))
+}
+
+/// JSON eval report.
+#[derive(Debug, Serialize)]
+pub struct JsonReport<'a> {
+    pub total: usize,
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    pub duration_ms: u128,
+    pub results: Vec<JsonResult<'a>>,
+    /// Per-fixture statistics of repeated runs
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub stats: &'a [EvalStats],
+}
+
+/// One eval in the JSON report.
+#[derive(Debug, Serialize)]
+pub struct JsonResult<'a> {
+    pub eval_id: &'a str,
+    pub run_id: String,
+    pub passed: bool,
+    pub converged: bool,
+    pub cycles: u32,
+    pub fact_count: usize,
+    pub facts_by_key: &'a BTreeMap<String, usize>,
+    pub tokens: u64,
+    pub duration_ms: u128,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context_hash: Option<&'a ContextHash>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<&'a str>,
+    /// Why the eval was not run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skipped: Option<&'a str>,
+    pub checks: Vec<JsonCheck<'a>>,
+}
+
+/// One check in the JSON report.
+#[derive(Debug, Serialize)]
+pub struct JsonCheck<'a> {
+    pub name: &'a str,
+    pub passed: bool,
+    pub expected: &'a str,
+    pub actual: &'a str,
+}
+
+impl<'a> JsonReport<'a> {
+    pub fn of(results: &'a [EvalResult], stats: &'a [EvalStats]) -> Self {
+        let passed = results.iter().filter(|r| r.passed).count();
+        let skipped = results.iter().filter(|r| r.skipped.is_some()).count();
+        Self {
+            total: results.len(),
+            passed,
+            failed: results.len() - passed - skipped,
+            skipped,
+            duration_ms: results.iter().map(|r| r.duration.as_millis()).sum(),
+            results: results
+                .iter()
+                .map(|r| JsonResult {
+                    eval_id: &r.eval_id,
+                    run_id: r.run_id.to_string(),
+                    passed: r.passed,
+                    converged: r.converged,
+                    cycles: r.cycles,
+                    fact_count: r.fact_count,
+                    facts_by_key: &r.facts_by_key,
+                    tokens: r.tokens,
+                    duration_ms: r.duration.as_millis(),
+                    context_hash: r.context_hash.as_ref(),
+                    error: r.error.as_deref(),
+                    skipped: r.skipped.as_deref(),
+                    checks: r
+                        .checks
+                        .iter()
+                        .map(|c| JsonCheck {
+                            name: &c.name,
+                            passed: c.passed,
+                            expected: &c.expected,
+                            actual: &c.actual,
+                        })
+                        .collect(),
+                })
+                .collect(),
+            stats,
+        }
+    }
+}
+
+/// Renders results as JUnit XML.
+pub fn render_junit(results: &[EvalResult]) -> String {
+    let failures = |r: &EvalResult| r.checks.iter().filter(|c| !c.passed).count();
+    let tests = |r: &EvalResult| r.checks.len() + usize::from(r.error.is_some() || r.skipped.is_some());
+    let skipped = |r: &EvalResult| usize::from(r.skipped.is_some());
+    let seconds = |r: &EvalResult| r.duration.as_secs_f64();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"converge-evals\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{:.3}\">",
+        results.iter().map(tests).sum::<usize>(),
+        results.iter().map(failures).sum::<usize>(),
+        results.iter().filter(|r| r.error.is_some()).count(),
+        results.iter().map(skipped).sum::<usize>(),
+        results.iter().map(seconds).sum::<f64>()
+    );
+    for result in results {
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{:.3}\">",
+            escape_xml(&result.eval_id),
+            tests(result),
+            failures(result),
+            usize::from(result.error.is_some()),
+            skipped(result),
+            seconds(result)
+        );
+        xml.push_str("    <properties>\n");
+        let mut properties = vec![
+            ("run_id", result.run_id.to_string()),
+            ("converged", result.converged.to_string()),
+            ("cycles", result.cycles.to_string()),
+            ("facts", result.fact_count.to_string()),
+        ];
+        if let Some(ref hash) = result.context_hash {
+            properties.push(("context_hash", hash.overall.clone()));
+        }
+        for (name, value) in properties {
+            let _ = writeln!(xml, "      <property name=\"{}\" value=\"{}\"/>", name, escape_xml(&value));
+        }
+        xml.push_str("    </properties>\n");
+
+        if let Some(ref error) = result.error {
+            let _ = writeln!(
+                xml,
+                "    <testcase classname=\"{}\" name=\"run\" time=\"{:.3}\">\n      <error message=\"{}\" type=\"EvalError\"/>\n    </testcase>",
+                escape_xml(&result.eval_id),
+                seconds(result),
+                escape_xml(error)
+            );
+        }
+        if let Some(ref reason) = result.skipped {
+            let _ = writeln!(
+                xml,
+                "    <testcase classname=\"{}\" name=\"run\" time=\"0\">\n      <skipped message=\"{}\"/>\n    </testcase>",
+                escape_xml(&result.eval_id),
+                escape_xml(reason)
+            );
+        }
+        for check in &result.checks {
+            push_check(&mut xml, &result.eval_id, check);
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+fn push_check(xml: &mut String, eval_id: &str, check: &EvalCheck) {
+    let _ = write!(
+        xml,
+        "    <testcase classname=\"{}\" name=\"{}\" time=\"0\"",
+        escape_xml(eval_id),
+        escape_xml(&check.name)
+    );
+    if check.passed {
+        xml.push_str("/>\n");
+        return;
+    }
+    let message = format!("expected {}, got {}", check.expected, check.actual);
+    let _ = writeln!(
+        xml,
+        ">\n      <failure message=\"{}\" type=\"EvalCheckFailed\">{}</failure>\n    </testcase>",
+        escape_xml(&message),
+        escape_xml(&message)
+    );
+}
+
+/// Escapes text for XML content and attributes, dropping characters XML 1.0
+/// cannot represent.
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            '\t' | '\r' => escaped.push(c),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    fn result() -> EvalResult {
+        EvalResult {
+            checks: vec![
+                EvalCheck {
+                    name: "converged".to_string(),
+                    passed: true,
+                    expected: "true".to_string(),
+                    actual: "true".to_string(),
+                },
+                EvalCheck {
+                    name: "min_facts".to_string(),
+                    passed: false,
+                    expected: ">= 5".to_string(),
+                    actual: "3".to_string(),
+                },
+            ],
+            fact_count: 3,
+            duration: Duration::from_millis(1500),
+            ..EvalResult::test("growth_<smb>", false)
+        }
+    }
+
+    #[test]
+    fn parses_report_specs() {
+        let spec: ReportSpec = "junit:reports/evals.xml".parse().unwrap();
+        assert_eq!(spec.format, ReportFormat::Junit);
+        assert_eq!(spec.path, PathBuf::from("reports/evals.xml"));
+        assert_eq!("json:out.json".parse::<ReportSpec>().unwrap().format, ReportFormat::Json);
+        assert!("out.xml".parse::<ReportSpec>().is_err());
+        assert!("tap:out.tap".parse::<ReportSpec>().is_err());
+    }
+
+    #[test]
+    fn junit_lists_each_check_with_failures_escaped() {
+        let xml = render_junit(&[result()]);
+        assert!(xml.contains("tests=\"2\" failures=\"1\" errors=\"0\" skipped=\"0\" time=\"1.500\""));
+        assert!(xml.contains("<testsuite name=\"growth_&lt;smb&gt;\""));
+        assert!(xml.contains("name=\"converged\" time=\"0\"/>"));
+        assert!(xml.contains("<failure message=\"expected &gt;= 5, got 3\" type=\"EvalCheckFailed\">"));
+        assert!(xml.contains("<property name=\"run_id\" value=\"00000000-0000-0000-0000-000000000000\"/>"));
+    }
+
+    #[test]
+    fn json_report_counts_results() {
+        let results = [result()];
+        let report = serde_json::to_value(JsonReport::of(&results, &[])).unwrap();
+        assert_eq!(report["failed"], 1);
+        assert_eq!(report["results"][0]["duration_ms"], 1500);
+        assert_eq!(report["results"][0]["checks"][1]["actual"], "3");
+        assert!(report["results"][0].get("error").is_none());
+    }
+
+    #[test]
+    fn skipped_runs_are_reported_as_skipped() {
+        let results = [result(), EvalResult::skipped("real_llm", Uuid::nil(), "no LLM credentials".to_string())];
+        let xml = render_junit(&results);
+        assert!(xml.contains("<testsuite name=\"real_llm\" tests=\"1\" failures=\"0\" errors=\"0\" skipped=\"1\""));
+        assert!(xml.contains("<skipped message=\"no LLM credentials\"/>"));
+
+        let report = serde_json::to_value(JsonReport::of(&results, &[])).unwrap();
+        assert_eq!((report["failed"].as_u64(), report["skipped"].as_u64()), (Some(1), Some(1)));
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! Golden snapshots of full eval contexts.
+//!
+//! A mock-LLM fixture can reference a snapshot of the complete context it
+//! is expected to produce, relative to the fixture file:
+//!
+//! ```json
+//! "snapshot": "snapshots/growth_strategy_smb_001.json"
+//! ```
+//!
+//! `converge eval run` diffs the actual context against the snapshot fact by
+//! fact (matched by key and id, as `converge diff` does); every added,
+//! removed or changed fact is a failed check. `--update-snapshots` skips
+//! the comparison and, once every run has finished, rewrites each snapshot
+//! once from the fixture's first completed run. Real-LLM runs are not
+//! deterministic and skip the comparison, unless they replay a cassette.
+
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::diff::{RunDiff, RunSide};
+use crate::evals::{EvalCheck, EvalFixture, EvalResult};
+use crate::output::FactOutput;
+
+/// A fact as stored in a snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotFact {
+    pub key: String,
+    pub id: String,
+    pub content: String,
+}
+
+/// The expected context of a fixture.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub eval_id: String,
+    pub facts: Vec<SnapshotFact>,
+}
+
+impl Snapshot {
+    fn of(eval_id: &str, facts: &[FactOutput]) -> Self {
+        Self {
+            eval_id: eval_id.to_string(),
+            facts: facts
+                .iter()
+                .map(|f| SnapshotFact {
+                    key: f.key.clone(),
+                    id: f.id.clone(),
+                    content: f.content.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    fn fact_outputs(&self) -> Vec<FactOutput> {
+        self.facts
+            .iter()
+            .enumerate()
+            .map(|(i, f)| FactOutput {
+                sequence: i + 1,
+                key: f.key.clone(),
+                id: f.id.clone(),
+                content: f.content.clone(),
+                confidence: None,
+                provenance: None,
+            })
+            .collect()
+    }
+
+    fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read snapshot '{}'", path.display()))?;
+        serde_json::from_str(&content).with_context(|| format!("'{}' is not an eval snapshot", path.display()))
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).with_context(|| format!("Failed to create '{}'", dir.display()))?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .with_context(|| format!("Failed to write '{}'", path.display()))
+    }
+}
+
+/// Path of a fixture's snapshot, resolved against the fixture file.
+pub fn snapshot_path(fixture: &EvalFixture) -> Option<PathBuf> {
+    fixture.snapshot.as_deref().map(|snapshot| fixture.resolve_path(snapshot))
+}
+
+fn check(name: String, passed: bool, expected: String, actual: String) -> EvalCheck {
+    EvalCheck {
+        name,
+        passed,
+        expected,
+        actual,
+    }
+}
+
+/// Path of the snapshot `fixture`'s runs are compared with: `None` for
+/// fixtures without one or calling a real LLM.
+fn compared_path(fixture: &EvalFixture) -> Option<PathBuf> {
+    let path = snapshot_path(fixture)?;
+    if !fixture.use_mock_llm && fixture.cassette.is_none() {
+        tracing::debug!(eval_id = %fixture.eval_id, "Skipping snapshot for a real-LLM run");
+        return None;
+    }
+    Some(path)
+}
+
+/// Rewrites each fixture's snapshot from its first completed run in
+/// `results`. Returns the number of snapshots written; fixtures without a
+/// completed run are skipped with a warning.
+pub fn update_all(fixtures: &[EvalFixture], results: &[EvalResult]) -> Result<usize> {
+    let mut written = 0;
+    for fixture in fixtures {
+        let Some(path) = compared_path(fixture) else {
+            continue;
+        };
+        let completed = results
+            .iter()
+            .find(|r| r.eval_id == fixture.eval_id && r.error.is_none() && r.skipped.is_none());
+        match completed {
+            Some(result) => {
+                Snapshot::of(&fixture.eval_id, &result.facts).save(&path)?;
+                written += 1;
+            }
+            None => tracing::warn!(eval_id = %fixture.eval_id, "No completed run; snapshot not updated"),
+        }
+    }
+    Ok(written)
+}
+
+/// Compares `facts` with the fixture's snapshot. Returns no checks for
+/// fixtures without a snapshot or runs calling a real LLM.
+pub fn checks(fixture: &EvalFixture, facts: &[FactOutput]) -> Vec<EvalCheck> {
+    let Some(path) = compared_path(fixture) else {
+        return Vec::new();
+    };
+
+    if !path.is_file() {
+        return vec![check(
+            "snapshot".to_string(),
+            false,
+            format!("golden context in {}", path.display()),
+            "missing (run with --update-snapshots)".to_string(),
+        )];
+    }
+    let golden = match Snapshot::load(&path) {
+        Ok(golden) => golden.fact_outputs(),
+        Err(e) => return vec![check("snapshot".to_string(), false, "a readable snapshot".to_string(), e.to_string())],
+    };
+
+    let side = |label: &str, facts: &[FactOutput]| RunSide {
+        label: label.to_string(),
+        converged: true,
+        cycles: 0,
+        total_facts: facts.len(),
+    };
+    let diff = RunDiff::new(side("golden", &golden), side("actual", facts), &golden, facts);
+    if diff.keys.iter().all(|k| k.is_empty()) {
+        return vec![check(
+            "snapshot".to_string(),
+            true,
+            "context matches snapshot".to_string(),
+            format!("{} facts match", golden.len()),
+        )];
+    }
+
+    let absent = || "(absent)".to_string();
+    let mut checks = Vec::new();
+    for key in &diff.keys {
+        for fact in &key.removed {
+            checks.push(check(format!("snapshot:{}:{}", key.key, fact.id), false, fact.content.clone(), absent()));
+        }
+        for fact in &key.added {
+            checks.push(check(format!("snapshot:{}:{}", key.key, fact.id), false, absent(), fact.content.clone()));
+        }
+        for fact in &key.changed {
+            checks.push(check(
+                format!("snapshot:{}:{}", key.key, fact.id),
+                false,
+                fact.before.clone(),
+                fact.after.clone(),
+            ));
+        }
+    }
+    checks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(dir: &Path) -> EvalFixture {
+        EvalFixture {
+            snapshot: Some("snapshots/snap.json".to_string()),
+            source: Some(dir.join("snap.json")),
+            ..EvalFixture::test("snap")
+        }
+    }
+
+    #[test]
+    fn update_then_compare_fact_by_fact() {
+        let dir = std::env::temp_dir().join(format!("converge-snapshot-{}", uuid::Uuid::new_v4()));
+        let fixture = fixture(&dir);
+        let facts = vec![FactOutput::test("Seeds", "seed:a", "a"), FactOutput::test("Strategies", "strategy:smb", "SMB")];
+
+        assert_eq!(snapshot_path(&fixture), Some(dir.join("snapshots/snap.json")));
+        assert!(!checks(&fixture, &facts)[0].passed);
+
+        let run = EvalResult {
+            facts: facts.clone(),
+            ..EvalResult::test("snap", true)
+        };
+        assert_eq!(update_all(std::slice::from_ref(&fixture), &[run]).unwrap(), 1);
+        assert!(checks(&fixture, &facts)[0].passed);
+
+        let changed = vec![FactOutput::test("Seeds", "seed:a", "a"), FactOutput::test("Strategies", "strategy:smb", "Enterprise")];
+        let result = checks(&fixture, &changed);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "snapshot:Strategies:strategy:smb");
+        assert_eq!((result[0].expected.as_str(), result[0].actual.as_str()), ("SMB", "Enterprise"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
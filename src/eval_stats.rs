@@ -0,0 +1,194 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! Flakiness statistics over repeated eval runs.
+//!
+//! `converge eval run --repeat N` runs every fixture N times. The runs of
+//! each fixture are summarised as a pass rate, how often each check failed,
+//! and p50/p95 of latency, cycles and fact count. A fixture passes when its
+//! pass rate reaches its `min_pass_rate` (default 1.0, every run passes),
+//! so non-deterministic evals can be gated statistically. Skipped runs are
+//! left out; a fixture whose runs were all skipped does not fail.
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::evals::{EvalFixture, EvalResult, Palette};
+
+/// p50 and p95 of a metric (nearest rank).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Percentiles {
+    pub p50: u64,
+    pub p95: u64,
+}
+
+impl Percentiles {
+    fn of(mut values: Vec<u64>) -> Self {
+        values.sort_unstable();
+        let rank = |p: f64| {
+            if values.is_empty() {
+                return 0;
+            }
+            let index = ((p * values.len() as f64).ceil() as usize).clamp(1, values.len()) - 1;
+            values[index]
+        };
+        Self {
+            p50: rank(0.50),
+            p95: rank(0.95),
+        }
+    }
+}
+
+/// Summary of one fixture's runs.
+#[derive(Debug, Clone, Serialize)]
+pub struct EvalStats {
+    pub eval_id: String,
+    /// Runs that executed (skipped runs excluded)
+    pub runs: usize,
+    pub skipped: usize,
+    pub passes: usize,
+    pub pass_rate: f64,
+    pub min_pass_rate: f64,
+    /// The pass rate reached `min_pass_rate`
+    pub passed: bool,
+    /// Failures per check name (run errors count as "error")
+    pub check_failures: BTreeMap<String, usize>,
+    pub latency_ms: Percentiles,
+    pub cycles: Percentiles,
+    pub facts: Percentiles,
+}
+
+/// Summarises `results` per fixture, in fixture order.
+pub fn summarize(fixtures: &[EvalFixture], results: &[EvalResult]) -> Vec<EvalStats> {
+    fixtures
+        .iter()
+        .map(|fixture| {
+            let (skipped, runs): (Vec<&EvalResult>, Vec<&EvalResult>) = results
+                .iter()
+                .filter(|r| r.eval_id == fixture.eval_id)
+                .partition(|r| r.skipped.is_some());
+            let passes = runs.iter().filter(|r| r.passed).count();
+            let pass_rate = if runs.is_empty() {
+                0.0
+            } else {
+                passes as f64 / runs.len() as f64
+            };
+            let min_pass_rate = fixture.min_pass_rate.unwrap_or(1.0);
+
+            let mut check_failures = BTreeMap::new();
+            for run in &runs {
+                if run.error.is_some() {
+                    *check_failures.entry("error".to_string()).or_insert(0) += 1;
+                }
+                for check in run.checks.iter().filter(|c| !c.passed) {
+                    *check_failures.entry(check.name.clone()).or_insert(0) += 1;
+                }
+            }
+
+            EvalStats {
+                eval_id: fixture.eval_id.clone(),
+                runs: runs.len(),
+                skipped: skipped.len(),
+                passes,
+                pass_rate,
+                min_pass_rate,
+                passed: if runs.is_empty() { !skipped.is_empty() } else { pass_rate >= min_pass_rate },
+                check_failures,
+                latency_ms: Percentiles::of(runs.iter().map(|r| r.duration.as_millis() as u64).collect()),
+                cycles: Percentiles::of(runs.iter().map(|r| u64::from(r.cycles)).collect()),
+                facts: Percentiles::of(runs.iter().map(|r| r.fact_count as u64).collect()),
+            }
+        })
+        .collect()
+}
+
+/// Prints the per-fixture statistics, with ANSI colours if `color`.
+pub fn print_stats(stats: &[EvalStats], color: bool) {
+    let Palette { green, red, yellow, reset } = Palette::new(color);
+
+    println!("\n=== Eval Statistics ===\n");
+    for stat in stats {
+        if stat.runs == 0 && stat.skipped > 0 {
+            println!("[{}SKIP{}] {}: {} runs skipped", yellow, reset, stat.eval_id, stat.skipped);
+            continue;
+        }
+        let (status_color, status) = if stat.passed { (green, "PASS") } else { (red, "FAIL") };
+        println!(
+            "[{}{}{}] {}: {}/{} passed ({:.0}%, required {:.0}%)",
+            status_color,
+            status,
+            reset,
+            stat.eval_id,
+            stat.passes,
+            stat.runs,
+            stat.pass_rate * 100.0,
+            stat.min_pass_rate * 100.0
+        );
+        println!(
+            "      latency p50 {}ms p95 {}ms | cycles p50 {} p95 {} | facts p50 {} p95 {}",
+            stat.latency_ms.p50, stat.latency_ms.p95, stat.cycles.p50, stat.cycles.p95, stat.facts.p50, stat.facts.p95
+        );
+        for (check, failures) in &stat.check_failures {
+            println!("      {} failed {}/{}", check, failures, stat.runs);
+        }
+    }
+    println!("\n=======================\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evals::EvalCheck;
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    fn run(passed: bool, millis: u64) -> EvalResult {
+        EvalResult {
+            checks: vec![EvalCheck {
+                name: "min_facts".to_string(),
+                passed,
+                expected: ">= 5".to_string(),
+                actual: String::new(),
+            }],
+            fact_count: if passed { 6 } else { 3 },
+            duration: Duration::from_millis(millis),
+            ..EvalResult::test("flaky", passed)
+        }
+    }
+
+    fn fixture(min_pass_rate: Option<f64>) -> EvalFixture {
+        EvalFixture {
+            min_pass_rate,
+            ..EvalFixture::test("flaky")
+        }
+    }
+
+    #[test]
+    fn pass_rate_gates_on_min_pass_rate() {
+        let results: Vec<EvalResult> = (1..=10).map(|i| run(i <= 8, i * 100)).collect();
+
+        let strict = &summarize(&[fixture(None)], &results)[0];
+        assert_eq!(strict.passes, 8);
+        assert!(!strict.passed);
+        assert_eq!(strict.check_failures["min_facts"], 2);
+
+        let lenient = &summarize(&[fixture(Some(0.8))], &results)[0];
+        assert!(lenient.passed);
+        assert_eq!(lenient.latency_ms, Percentiles { p50: 500, p95: 1000 });
+        assert_eq!(lenient.facts, Percentiles { p50: 6, p95: 6 });
+    }
+
+    #[test]
+    fn skipped_runs_neither_pass_nor_fail() {
+        let skipped = vec![EvalResult::skipped("flaky", Uuid::nil(), "no LLM credentials".to_string()); 3];
+        let stat = &summarize(&[fixture(None)], &skipped)[0];
+        assert_eq!((stat.runs, stat.skipped), (0, 3));
+        assert!(stat.passed);
+
+        let mut mixed = skipped;
+        mixed.push(run(false, 100));
+        assert!(!summarize(&[fixture(None)], &mixed)[0].passed);
+    }
+}
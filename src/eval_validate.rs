@@ -0,0 +1,217 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! Strict validation of eval fixture files.
+//!
+//! `converge eval validate` checks every fixture in a directory, and
+//! `converge eval run` refuses to start while any check fails:
+//!
+//! - the file parses as an [`EvalFixture`] with no unknown fields
+//!   (`$schema` is allowed)
+//! - every context key it names exists
+//! - its pack is compiled into this binary
+//! - its regexes compile and its thresholds are within range
+//! - a referenced cassette exists
+//! - no two fixtures share an `eval_id`
+//!
+//! The same shape is published as a JSON Schema in
+//! `docs/schemas/eval-fixture.schema.json` for editors and CI linters.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::eval_assertions::is_context_key;
+use crate::eval_filter::SuiteManifest;
+use crate::evals::{self, EvalFixture};
+use crate::packs;
+
+/// Location of the fixture schema, relative to the repository root.
+pub const SCHEMA_PATH: &str = "docs/schemas/eval-fixture.schema.json";
+
+/// A problem with one fixture file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+impl std::fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+/// Validates the fixtures and suite manifest in `dir`. Returns the
+/// fixtures that parsed and every issue found.
+pub fn validate_dir(dir: &Path) -> (Vec<EvalFixture>, Vec<ValidationIssue>) {
+    let mut fixtures = Vec::new();
+    let mut issues = Vec::new();
+
+    let paths = match evals::fixture_paths(dir) {
+        Ok(paths) => paths,
+        Err(e) => {
+            issues.push(ValidationIssue {
+                path: dir.to_path_buf(),
+                message: format!("{:#}", e),
+            });
+            return (fixtures, issues);
+        }
+    };
+    for path in paths {
+        match evals::load_fixture(&path) {
+            Ok(fixture) => fixtures.push(fixture),
+            Err(e) => issues.push(ValidationIssue {
+                path,
+                // The root cause carries serde's line and column
+                message: e.root_cause().to_string(),
+            }),
+        }
+    }
+
+    issues.extend(check_fixtures(&fixtures));
+    if let Err(e) = SuiteManifest::load(dir) {
+        issues.push(ValidationIssue {
+            path: dir.join(crate::eval_filter::SUITES_FILE),
+            message: e.root_cause().to_string(),
+        });
+    }
+    (fixtures, issues)
+}
+
+/// Checks parsed fixtures for problems serde cannot catch.
+pub fn check_fixtures(fixtures: &[EvalFixture]) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let mut seen: BTreeMap<&str, &EvalFixture> = BTreeMap::new();
+
+    for fixture in fixtures {
+        let path = fixture.source.clone().unwrap_or_else(|| PathBuf::from(&fixture.eval_id));
+        for message in fixture_problems(fixture) {
+            issues.push(ValidationIssue {
+                path: path.clone(),
+                message,
+            });
+        }
+        if let Some(first) = seen.insert(&fixture.eval_id, fixture) {
+            let other = first
+                .source
+                .as_ref()
+                .map_or_else(|| "another fixture".to_string(), |p| p.display().to_string());
+            issues.push(ValidationIssue {
+                path,
+                message: format!("duplicate eval_id '{}' (also in {})", fixture.eval_id, other),
+            });
+        }
+    }
+    issues
+}
+
+/// Problems with a single fixture.
+fn fixture_problems(fixture: &EvalFixture) -> Vec<String> {
+    let mut problems = Vec::new();
+    let expected = &fixture.expected;
+
+    if fixture.eval_id.trim().is_empty() {
+        problems.push("eval_id is empty".to_string());
+    }
+
+    let available = packs::available_packs();
+    if !available.contains(&fixture.pack) {
+        problems.push(format!("unknown pack '{}' (available: {})", fixture.pack, available.join(", ")));
+    }
+
+    let mut keys: Vec<(&str, &str)> = Vec::new();
+    keys.extend(expected.required_context_keys.iter().map(|k| ("expected.required_context_keys", k.as_str())));
+    keys.extend(expected.content.iter().map(|a| ("expected.content", a.key.as_str())));
+    keys.extend(expected.counts.keys().map(|k| ("expected.counts", k.as_str())));
+    if let Some(ref judge) = fixture.judge {
+        keys.extend(judge.keys.iter().map(|k| ("judge.keys", k.as_str())));
+    }
+    for (field, key) in keys {
+        if !is_context_key(key) {
+            problems.push(format!("unknown context key '{}' in {}", key, field));
+        }
+    }
+
+    for assertion in &expected.content {
+        for pattern in [&assertion.matches, &assertion.must_not_match].into_iter().flatten() {
+            if let Err(e) = regex::Regex::new(pattern) {
+                problems.push(format!("invalid regex in expected.content for {}: {}", assertion.key, e));
+            }
+        }
+    }
+
+    let out_of_range = |value: f64| !(0.0..=1.0).contains(&value);
+    if fixture.min_pass_rate.is_some_and(out_of_range) {
+        problems.push("min_pass_rate must be between 0.0 and 1.0".to_string());
+    }
+    if fixture.min_confidence.is_some_and(out_of_range) {
+        problems.push("min_confidence must be between 0.0 and 1.0".to_string());
+    }
+    if let Some(ref judge) = fixture.judge {
+        for (criterion, _) in judge.min_scores.iter().filter(|(_, score)| out_of_range(**score)) {
+            problems.push(format!("judge.min_scores.{} must be between 0.0 and 1.0", criterion));
+        }
+    }
+
+    if let Some(ref cassette) = fixture.cassette {
+        let path = fixture.resolve_path(cassette);
+        if !path.is_file() {
+            problems.push(format!("cassette '{}' not found", path.display()));
+        }
+    }
+
+    problems
+}
+
+/// Prints validation issues, one per line.
+pub fn print_issues(issues: &[ValidationIssue]) {
+    for issue in issues {
+        println!("  {}", issue);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_unknown_fields_but_allows_schema() {
+        let base = r#""eval_id": "a", "description": "", "pack": "growth-strategy", "seeds": []"#;
+        let ok = format!(r#"{{"$schema": "../{}", {}, "expected": {{}}}}"#, SCHEMA_PATH, base);
+        assert!(serde_json::from_str::<EvalFixture>(&ok).is_ok());
+
+        let typo = format!(r#"{{{}, "expected": {{"min_fact": 3}}}}"#, base);
+        let error = serde_json::from_str::<EvalFixture>(&typo).unwrap_err().to_string();
+        assert!(error.contains("unknown field `min_fact`"), "{}", error);
+    }
+
+    #[test]
+    fn reports_unknown_keys_packs_and_duplicates() {
+        let bad = EvalFixture {
+            pack: "no-such-pack".to_string(),
+            expected: serde_json::from_str(
+                r#"{"required_context_keys": ["Strategies", "Stratagems"], "content": [{"key": "Signals", "matches": "("}]}"#,
+            )
+            .unwrap(),
+            min_pass_rate: Some(1.5),
+            ..EvalFixture::test("a")
+        };
+        let messages: Vec<String> = check_fixtures(&[bad.clone(), bad]).into_iter().map(|i| i.message).collect();
+
+        assert!(messages.iter().any(|m| m.starts_with("unknown pack 'no-such-pack'")));
+        assert!(messages.contains(&"unknown context key 'Stratagems' in expected.required_context_keys".to_string()));
+        assert!(messages.iter().any(|m| m.starts_with("invalid regex in expected.content for Signals")));
+        assert!(messages.contains(&"min_pass_rate must be between 0.0 and 1.0".to_string()));
+        assert!(messages.contains(&"duplicate eval_id 'a' (also in another fixture)".to_string()));
+    }
+
+    #[test]
+    fn repository_fixtures_are_valid() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("evals");
+        let (fixtures, issues) = validate_dir(&dir);
+        assert!(issues.is_empty(), "{:?}", issues);
+        assert!(!fixtures.is_empty());
+    }
+}
@@ -21,31 +21,70 @@
 //! # Run specific eval
 //! converge eval run growth_strategy_smb_001
 //!
+//! # Run fixtures by glob, tag or suite (suites are defined in evals/suites.json)
+//! converge eval run 'growth_*' --tag smoke --exclude-tag real-llm
+//! converge eval run --suite ci
+//!
+//! # Run up to 4 fixtures at a time, each with a 60s timeout
+//! converge eval run --jobs 4 --timeout 60
+//!
+//! # Run each fixture 10 times and report pass rate and p50/p95 latency
+//! converge eval run --repeat 10
+//!
+//! # Record metrics, then fail later runs that regress against them
+//! converge eval run --mock --save-baseline
+//! converge eval run --mock --compare-baseline
+//!
+//! # Rewrite golden context snapshots from the current runs
+//! converge eval run --mock --update-snapshots
+//!
+//! # Generate a fixture from a saved run, replaying its LLM responses
+//! converge eval create --from-run <run_id> --with-cassette
+//!
+//! # Check fixtures strictly (also done before every run)
+//! converge eval validate
+//!
 //! # List available evals
 //! converge eval list
 //! ```
+//!
+//! Each fixture runs on its own engine on a blocking worker thread. Its
+//! duration (and so the `max_latency_ms` check) is its own wall time from
+//! engine setup to convergence, not including time spent waiting for a
+//! worker. A fixture that exceeds its timeout is cancelled at the next cycle
+//! boundary and reported as failed. Results are returned in fixture order
+//! whatever order the fixtures finish in.
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
 use uuid::Uuid;
 
 use converge_core::{Context as ConvergeContext, ContextKey, Engine, Fact};
-use converge_core::llm::LlmProvider;
-use converge_provider::{AnthropicProvider, OpenAiProvider};
 use strum::IntoEnumIterator;
 
-use crate::agents::{MockInsightProvider, RiskAssessmentAgent, StrategicInsightAgent};
-use converge_domain::growth_strategy::{
-    BrandSafetyInvariant, CompetitorAgent, EvaluationAgent, MarketSignalAgent,
-    RequireEvaluationRationale, RequireMultipleStrategies, RequireStrategyEvaluations,
-    StrategyAgent,
-};
+use crate::confidence::ConfidenceLedger;
+use crate::control::JobControl;
+use crate::eval_baseline::Tolerances;
+use crate::eval_assertions::{self, ContentAssertion, CountExpectation, Ordering, Relationship};
+use crate::eval_filter::SUITES_FILE;
+use crate::eval_snapshot;
+use crate::hashing::ContextHash;
+use crate::judge::{self, JudgeSpec};
+use crate::output::{fact_outputs, FactOutput};
+use crate::replay::{Cassette, CassetteMode, CassettePlayer};
+use crate::wiring::{has_llm_credentials, register_pack_agents, AgentWiring, LLM_API_KEY_VARS};
+
+/// Default per-fixture timeout of `converge eval run`, in seconds.
+pub const DEFAULT_EVAL_TIMEOUT_SECS: u64 = 120;
 
 /// A seed fact for the eval fixture
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct SeedFact {
     pub id: String,
     pub content: String,
@@ -53,6 +92,7 @@ pub struct SeedFact {
 
 /// Expected outcomes for an eval
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct EvalExpectation {
     /// Must converge (reach fixed point)
     #[serde(default)]
@@ -89,11 +129,35 @@ pub struct EvalExpectation {
     /// Context keys that must have facts
     #[serde(default)]
     pub required_context_keys: Vec<String>,
+
+    /// Regex assertions on fact content per context key
+    #[serde(default)]
+    pub content: Vec<ContentAssertion>,
+
+    /// Exact or ranged fact counts per context key
+    #[serde(default)]
+    pub counts: BTreeMap<String, CountExpectation>,
+
+    /// Facts that must be referred to by other facts
+    #[serde(default)]
+    pub relationships: Vec<Relationship>,
+
+    /// Facts that must be produced before others
+    #[serde(default)]
+    pub ordering: Vec<Ordering>,
 }
 
 /// An eval fixture defining a test scenario
+///
+/// Unknown fields are rejected so typos fail loudly; `$schema` may point
+/// editors at `docs/schemas/eval-fixture.schema.json`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct EvalFixture {
+    /// JSON Schema the file declares (ignored)
+    #[serde(rename = "$schema", default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+
     /// Unique identifier for this eval
     pub eval_id: String,
 
@@ -103,6 +167,10 @@ pub struct EvalFixture {
     /// Pack to use for this eval
     pub pack: String,
 
+    /// Labels for selecting fixtures (e.g. smoke, real-llm, latency)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
     /// Input seeds
     pub seeds: Vec<SeedFact>,
 
@@ -112,6 +180,69 @@ pub struct EvalFixture {
     /// Whether to use mock LLM (faster, deterministic)
     #[serde(default)]
     pub use_mock_llm: bool,
+
+    /// Share of repeated runs that must pass (0.0-1.0, default 1.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_pass_rate: Option<f64>,
+
+    /// Allowed regressions when compared with a saved baseline
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline_tolerance: Option<Tolerances>,
+
+    /// Rubric scoring of the produced insights by a judge
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub judge: Option<JudgeSpec>,
+
+    /// Golden snapshot of the full expected context, relative to this file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<String>,
+
+    /// Recorded LLM cassette to replay instead of calling the provider,
+    /// relative to this file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cassette: Option<String>,
+
+    /// Review threshold for LLM facts (0.0-1.0), as recorded with the
+    /// cassette's run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_confidence: Option<f64>,
+
+    /// File the fixture was loaded from
+    #[serde(skip)]
+    pub source: Option<PathBuf>,
+}
+
+impl EvalFixture {
+    /// Resolves a path given relative to the fixture file.
+    pub fn resolve_path(&self, relative: &str) -> PathBuf {
+        let base = self.source.as_deref().and_then(Path::parent).unwrap_or(Path::new("."));
+        base.join(relative)
+    }
+}
+
+#[cfg(test)]
+impl EvalFixture {
+    /// A mock-LLM `growth-strategy` fixture without seeds or expectations,
+    /// for tests.
+    pub fn test(eval_id: &str) -> Self {
+        Self {
+            schema: None,
+            eval_id: eval_id.to_string(),
+            description: String::new(),
+            pack: "growth-strategy".to_string(),
+            tags: Vec::new(),
+            seeds: Vec::new(),
+            expected: serde_json::from_str("{}").expect("all expectations are optional"),
+            use_mock_llm: true,
+            min_pass_rate: None,
+            baseline_tolerance: None,
+            judge: None,
+            snapshot: None,
+            cassette: None,
+            min_confidence: None,
+            source: None,
+        }
+    }
 }
 
 /// Result of running an eval
@@ -135,14 +266,29 @@ pub struct EvalResult {
     /// Actual fact count
     pub fact_count: usize,
 
+    /// Actual fact count per context key
+    pub facts_by_key: BTreeMap<String, usize>,
+
+    /// LLM tokens used by the run's agents
+    pub tokens: u64,
+
     /// Whether convergence was reached
     pub converged: bool,
 
+    /// Hash of the final context (absent if the run failed)
+    pub context_hash: Option<ContextHash>,
+
     /// Total run duration
     pub duration: Duration,
 
     /// Error message if run failed
     pub error: Option<String>,
+
+    /// Why the eval was not run (reported as SKIP, neither pass nor fail)
+    pub skipped: Option<String>,
+
+    /// Final context (empty if the run failed), for updating snapshots
+    pub facts: Vec<FactOutput>,
 }
 
 /// Individual check within an eval
@@ -164,46 +310,97 @@ impl EvalResult {
             checks: vec![],
             cycles: 0,
             fact_count: 0,
+            facts_by_key: BTreeMap::new(),
+            tokens: 0,
             converged: false,
+            context_hash: None,
             duration,
             error: Some(error),
+            skipped: None,
+            facts: Vec::new(),
+        }
+    }
+
+    /// Create a result for an eval that was not run
+    pub fn skipped(eval_id: &str, run_id: Uuid, reason: String) -> Self {
+        Self {
+            error: None,
+            skipped: Some(reason),
+            ..Self::error(eval_id, run_id, String::new(), Duration::ZERO)
+        }
+    }
+}
+
+#[cfg(test)]
+impl EvalResult {
+    /// A converged four-cycle result without checks or facts, for tests.
+    pub fn test(eval_id: &str, passed: bool) -> Self {
+        Self {
+            passed,
+            cycles: 4,
+            converged: true,
+            error: None,
+            ..Self::error(eval_id, Uuid::nil(), String::new(), Duration::ZERO)
         }
     }
 }
 
+/// Why `fixture` cannot run here: it calls a real LLM (no mock, no
+/// cassette, or an LLM judge) and no provider credentials are set.
+pub fn skip_reason(fixture: &EvalFixture) -> Option<String> {
+    let needs_llm = (!fixture.use_mock_llm && fixture.cassette.is_none()) || judge::needs_llm(fixture);
+    (needs_llm && !has_llm_credentials()).then(|| format!("no LLM credentials ({} unset)", LLM_API_KEY_VARS.join(", ")))
+}
+
 /// Load an eval fixture from a JSON file
 pub fn load_fixture(path: &Path) -> Result<EvalFixture> {
     let content = std::fs::read_to_string(path)
         .with_context(|| format!("Failed to read fixture file: {}", path.display()))?;
 
-    let fixture: EvalFixture = serde_json::from_str(&content)
+    let mut fixture: EvalFixture = serde_json::from_str(&content)
         .with_context(|| format!("Failed to parse fixture JSON: {}", path.display()))?;
+    fixture.source = Some(path.to_path_buf());
 
     Ok(fixture)
 }
 
-/// Load all fixtures from a directory
-pub fn load_fixtures_from_dir(dir: &Path) -> Result<Vec<EvalFixture>> {
-    let mut fixtures = Vec::new();
+/// Fixture files in a directory: every `.json` file except the suite
+/// manifest, sorted by path
+pub fn fixture_paths(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
 
     if !dir.exists() {
-        return Ok(fixtures);
+        return Ok(paths);
     }
 
     for entry in std::fs::read_dir(dir)? {
-        let entry = entry?;
-        let path = entry.path();
+        let path = entry?.path();
+
+        // The suite manifest lives alongside the fixtures
+        if path.file_name().is_some_and(|name| name == SUITES_FILE) {
+            continue;
+        }
 
         if path.extension().map(|e| e == "json").unwrap_or(false) {
-            match load_fixture(&path) {
-                Ok(fixture) => fixtures.push(fixture),
-                Err(e) => {
-                    tracing::warn!(path = %path.display(), error = %e, "Failed to load fixture");
-                }
-            }
+            paths.push(path);
         }
     }
 
+    paths.sort();
+    Ok(paths)
+}
+
+/// Load all fixtures from a directory
+///
+/// A fixture that cannot be read or parsed (including one with unknown
+/// fields) is an error; `converge eval validate` reports every problem at
+/// once.
+pub fn load_fixtures_from_dir(dir: &Path) -> Result<Vec<EvalFixture>> {
+    let mut fixtures = fixture_paths(dir)?
+        .iter()
+        .map(|path| load_fixture(path))
+        .collect::<Result<Vec<_>>>()?;
+
     // Sort by eval_id for consistent ordering
     fixtures.sort_by(|a, b| a.eval_id.cmp(&b.eval_id));
 
@@ -212,7 +409,23 @@ pub fn load_fixtures_from_dir(dir: &Path) -> Result<Vec<EvalFixture>> {
 
 /// Run a single eval fixture
 pub fn run_eval(fixture: &EvalFixture) -> EvalResult {
-    let run_id = Uuid::new_v4();
+    run_eval_controlled(fixture, Uuid::new_v4(), Arc::new(JobControl::new()), false)
+}
+
+/// Run a single eval fixture that can be cancelled through `control`,
+/// leaving out the snapshot comparison if `update_snapshots` (the caller
+/// rewrites the snapshots once every run has finished)
+fn run_eval_controlled(
+    fixture: &EvalFixture,
+    run_id: Uuid,
+    control: Arc<JobControl>,
+    update_snapshots: bool,
+) -> EvalResult {
+    if let Some(reason) = skip_reason(fixture) {
+        tracing::info!(eval_id = %fixture.eval_id, reason = %reason, "Skipping eval");
+        return EvalResult::skipped(&fixture.eval_id, run_id, reason);
+    }
+
     let start = Instant::now();
 
     tracing::info!(
@@ -238,7 +451,18 @@ pub fn run_eval(fixture: &EvalFixture) -> EvalResult {
 
     // Create engine and register agents
     let mut engine = Engine::new();
-    if let Err(e) = register_pack_agents(&mut engine, &fixture.pack, fixture.use_mock_llm) {
+    let mut wiring = AgentWiring::new(fixture.use_mock_llm)
+        .with_ledger(Arc::new(ConfidenceLedger::new(fixture.min_confidence)))
+        .with_control(control.clone());
+    if let Some(ref cassette) = fixture.cassette {
+        match Cassette::load(&fixture.resolve_path(cassette)) {
+            Ok(cassette) => {
+                wiring = wiring.with_cassette(CassetteMode::Replay(Arc::new(CassettePlayer::new(cassette))));
+            }
+            Err(e) => return EvalResult::error(&fixture.eval_id, run_id, format!("{:#}", e), start.elapsed()),
+        }
+    }
+    if let Err(e) = register_pack_agents(&mut engine, &fixture.pack, &wiring) {
         return EvalResult::error(
             &fixture.eval_id,
             run_id,
@@ -261,6 +485,9 @@ pub fn run_eval(fixture: &EvalFixture) -> EvalResult {
     };
 
     let duration = start.elapsed();
+    if control.is_cancelled() {
+        return EvalResult::error(&fixture.eval_id, run_id, "Cancelled".to_string(), duration);
+    }
 
     // Collect facts
     let all_facts: Vec<_> = ContextKey::iter()
@@ -268,6 +495,10 @@ pub fn run_eval(fixture: &EvalFixture) -> EvalResult {
         .collect();
 
     let fact_count = all_facts.len();
+    let facts_by_key: BTreeMap<String, usize> = ContextKey::iter()
+        .map(|key| (format!("{:?}", key), result.context.get(key).len()))
+        .filter(|(_, count)| *count > 0)
+        .collect();
     let strategy_count = result.context.get(ContextKey::Strategies).len();
     let evaluation_count = result.context.get(ContextKey::Evaluations).len();
 
@@ -360,28 +591,47 @@ pub fn run_eval(fixture: &EvalFixture) -> EvalResult {
 
     // Check: required_context_keys
     for key_name in &expected.required_context_keys {
-        let key = match key_name.as_str() {
-            "Seeds" => Some(ContextKey::Seeds),
-            "Signals" => Some(ContextKey::Signals),
-            "Competitors" => Some(ContextKey::Competitors),
-            "Strategies" => Some(ContextKey::Strategies),
-            "Evaluations" => Some(ContextKey::Evaluations),
-            "Hypotheses" => Some(ContextKey::Hypotheses),
-            "Constraints" => Some(ContextKey::Constraints),
-            _ => None,
-        };
-
-        if let Some(context_key) = key {
-            let has_facts = !result.context.get(context_key).is_empty();
+        let Some(context_key) = ContextKey::iter().find(|k| format!("{:?}", k) == *key_name) else {
             checks.push(EvalCheck {
                 name: format!("has_key:{}", key_name),
-                passed: has_facts,
-                expected: format!("{} has facts", key_name),
-                actual: if has_facts { "has facts".to_string() } else { "empty".to_string() },
+                passed: false,
+                expected: "a context key".to_string(),
+                actual: format!("unknown key '{}'", key_name),
             });
+            continue;
+        };
+
+        let has_facts = !result.context.get(context_key).is_empty();
+        checks.push(EvalCheck {
+            name: format!("has_key:{}", key_name),
+            passed: has_facts,
+            expected: format!("{} has facts", key_name),
+            actual: if has_facts { "has facts".to_string() } else { "empty".to_string() },
+        });
+    }
+
+    // Check: content, counts, relationships and ordering
+    let outputs = fact_outputs(&result.context, &wiring.ledger, &wiring.provenance);
+    checks.extend(eval_assertions::check(expected, &outputs));
+
+    // Check: judge rubric scores
+    if let Some(ref spec) = fixture.judge {
+        match judge::judge_for(fixture) {
+            Ok(judge) => checks.extend(judge::checks(spec, judge.as_ref(), &fixture.seeds, &outputs)),
+            Err(e) => checks.push(EvalCheck {
+                name: "judge".to_string(),
+                passed: false,
+                expected: "an LLM judge".to_string(),
+                actual: format!("{:#}", e),
+            }),
         }
     }
 
+    // Check: golden context snapshot
+    if !update_snapshots {
+        checks.extend(eval_snapshot::checks(fixture, &outputs));
+    }
+
     // Determine overall pass/fail
     let passed = checks.iter().all(|c| c.passed);
 
@@ -402,81 +652,130 @@ pub fn run_eval(fixture: &EvalFixture) -> EvalResult {
         checks,
         cycles: result.cycles,
         fact_count,
+        facts_by_key,
+        tokens: wiring.stats.total_tokens(),
         converged: result.converged,
+        context_hash: Some(ContextHash::of_context(&result.context)),
         duration,
         error: None,
+        skipped: None,
+        facts: outputs,
     }
 }
 
-/// Run multiple eval fixtures
-pub fn run_evals(fixtures: &[EvalFixture]) -> Vec<EvalResult> {
-    fixtures.iter().map(run_eval).collect()
+/// Run multiple eval fixtures, at most `jobs` at a time
+///
+/// Results are in fixture order. A fixture still running after `timeout`
+/// is cancelled and reported as failed; its worker slot frees up once the
+/// engine reaches the next cycle boundary. With `update_snapshots`, runs
+/// are not compared with their golden snapshots; rewrite them afterwards
+/// with [`eval_snapshot::update_all`].
+pub async fn run_evals(
+    fixtures: &[EvalFixture],
+    jobs: usize,
+    timeout: Duration,
+    update_snapshots: bool,
+) -> Vec<EvalResult> {
+    run_evals_with(fixtures, jobs, timeout, move |fixture, run_id, control| {
+        run_eval_controlled(fixture, run_id, control, update_snapshots)
+    })
+    .await
 }
 
-/// Creates an LLM provider (real or mock based on flag)
-fn create_llm_provider(use_mock: bool) -> Arc<dyn LlmProvider> {
-    if use_mock {
-        return Arc::new(MockInsightProvider::default_insights()) as Arc<dyn LlmProvider>;
-    }
-
-    tokio::task::block_in_place(|| {
-        // Try Anthropic first
-        if let Ok(provider) = AnthropicProvider::from_env("claude-sonnet-4-20250514") {
-            return Arc::new(provider) as Arc<dyn LlmProvider>;
-        }
+/// [`run_evals`] with `run` executing each fixture on a blocking worker.
+async fn run_evals_with<F>(fixtures: &[EvalFixture], jobs: usize, timeout: Duration, run: F) -> Vec<EvalResult>
+where
+    F: Fn(&EvalFixture, Uuid, Arc<JobControl>) -> EvalResult + Send + Sync + 'static,
+{
+    let workers = Arc::new(Semaphore::new(jobs.max(1)));
+    let run = Arc::new(run);
+
+    let tasks: Vec<_> = fixtures
+        .iter()
+        .cloned()
+        .map(|fixture| {
+            let workers = workers.clone();
+            let run = run.clone();
+            tokio::spawn(async move {
+                let permit = workers
+                    .acquire_owned()
+                    .await
+                    .expect("eval worker semaphore is never closed");
+                let eval_id = fixture.eval_id.clone();
+                let run_id = Uuid::new_v4();
+                let control = Arc::new(JobControl::new());
+                let worker_control = control.clone();
+                let worker = tokio::task::spawn_blocking(move || {
+                    let result = run(&fixture, run_id, worker_control);
+                    drop(permit);
+                    result
+                });
+
+                match tokio::time::timeout(timeout, worker).await {
+                    Ok(Ok(result)) => result,
+                    Ok(Err(e)) => EvalResult::error(&eval_id, run_id, format!("Eval worker failed: {}", e), Duration::ZERO),
+                    Err(_) => {
+                        control.cancel();
+                        EvalResult::error(
+                            &eval_id,
+                            run_id,
+                            format!("Timed out after {}s", timeout.as_secs_f64()),
+                            timeout,
+                        )
+                    }
+                }
+            })
+        })
+        .collect();
 
-        // Try OpenAI second
-        if let Ok(provider) = OpenAiProvider::from_env("gpt-4o") {
-            return Arc::new(provider) as Arc<dyn LlmProvider>;
-        }
+    let mut results = Vec::with_capacity(tasks.len());
+    for (task, fixture) in tasks.into_iter().zip(fixtures) {
+        results.push(task.await.unwrap_or_else(|e| {
+            EvalResult::error(&fixture.eval_id, Uuid::new_v4(), format!("Eval task failed: {}", e), Duration::ZERO)
+        }));
+    }
+    results
+}
 
-        // Fall back to mock provider
-        Arc::new(MockInsightProvider::default_insights()) as Arc<dyn LlmProvider>
-    })
+/// ANSI colour codes shared by the eval reports; all empty without colour.
+#[derive(Debug, Clone, Copy)]
+pub struct Palette {
+    pub green: &'static str,
+    pub red: &'static str,
+    pub yellow: &'static str,
+    pub reset: &'static str,
 }
 
-/// Register agents for a pack
-fn register_pack_agents(engine: &mut Engine, pack_name: &str, use_mock_llm: bool) -> Result<()> {
-    match pack_name {
-        "growth-strategy" => {
-            // Register deterministic agents
-            engine.register(MarketSignalAgent);
-            engine.register(CompetitorAgent);
-            engine.register(StrategyAgent);
-            engine.register(EvaluationAgent);
-
-            // Create LLM provider
-            let llm_provider = create_llm_provider(use_mock_llm);
-
-            // Register LLM-powered agents
-            engine.register(StrategicInsightAgent::new(llm_provider.clone()));
-            engine.register(RiskAssessmentAgent::new(llm_provider));
-
-            // Register Invariants
-            engine.register_invariant(BrandSafetyInvariant::default());
-            engine.register_invariant(RequireMultipleStrategies);
-            engine.register_invariant(RequireStrategyEvaluations);
-            engine.register_invariant(RequireEvaluationRationale);
-        }
-        _ => {
-            return Err(anyhow::anyhow!("Unknown pack: {}", pack_name));
+impl Palette {
+    pub fn new(color: bool) -> Self {
+        let paint = |code: &'static str| if color { code } else { "" };
+        Self {
+            green: paint("\x1b[32m"),
+            red: paint("\x1b[31m"),
+            yellow: paint("\x1b[33m"),
+            reset: paint("\x1b[0m"),
         }
     }
-    Ok(())
 }
 
-/// Print eval results in a formatted way
-pub fn print_results(results: &[EvalResult]) {
+/// Print eval results in a formatted way, with ANSI colours if `color`
+pub fn print_results(results: &[EvalResult], color: bool) {
     let total = results.len();
+    let skipped = results.iter().filter(|r| r.skipped.is_some()).count();
     let passed = results.iter().filter(|r| r.passed).count();
-    let failed = total - passed;
+    let failed = total - passed - skipped;
+    let Palette { green, red, yellow, reset } = Palette::new(color);
 
     println!("\n=== Eval Results ===\n");
 
     for result in results {
+        if let Some(ref reason) = result.skipped {
+            println!("[{}SKIP{}] {} ({})", yellow, reset, result.eval_id, reason);
+            continue;
+        }
+
         let status = if result.passed { "PASS" } else { "FAIL" };
-        let status_color = if result.passed { "\x1b[32m" } else { "\x1b[31m" };
-        let reset = "\x1b[0m";
+        let status_color = if result.passed { green } else { red };
 
         println!(
             "[{}{}{}] {} ({}ms, {} cycles, {} facts)",
@@ -487,6 +786,9 @@ pub fn print_results(results: &[EvalResult]) {
             result.fact_count,
         );
 
+        if let Some(ref hash) = result.context_hash {
+            println!("      Context: {}", hash.overall);
+        }
         if let Some(ref error) = result.error {
             println!("      Error: {}", error);
         }
@@ -496,7 +798,7 @@ pub fn print_results(results: &[EvalResult]) {
             if !check.passed {
                 println!(
                     "      {}FAIL{}: {} - expected {}, got {}",
-                    "\x1b[31m", reset,
+                    red, reset,
                     check.name,
                     check.expected,
                     check.actual
@@ -507,10 +809,11 @@ pub fn print_results(results: &[EvalResult]) {
 
     println!("\n===================");
     println!(
-        "Total: {} | {}Passed: {}{} | {}Failed: {}{}",
+        "Total: {} | {}Passed: {}{} | {}Failed: {}{} | Skipped: {}",
         total,
-        "\x1b[32m", passed, "\x1b[0m",
-        if failed > 0 { "\x1b[31m" } else { "\x1b[0m" }, failed, "\x1b[0m"
+        green, passed, reset,
+        if failed > 0 { red } else { reset }, failed, reset,
+        skipped
     );
     println!("===================\n");
 }
@@ -518,6 +821,7 @@ pub fn print_results(results: &[EvalResult]) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::BTreeSet;
 
     #[test]
     fn test_fixture_parsing() {
@@ -542,6 +846,77 @@ mod tests {
         assert!(fixture.use_mock_llm);
     }
 
+    #[test]
+    fn schema_lists_every_fixture_field() {
+        let schema: serde_json::Value =
+            serde_json::from_str(include_str!("../docs/schemas/eval-fixture.schema.json")).unwrap();
+        let keys = |value: &serde_json::Value| -> BTreeSet<String> { value.as_object().unwrap().keys().cloned().collect() };
+
+        let mut fixture = EvalFixture::test("a");
+        fixture.schema = Some(String::new());
+        fixture.tags = vec!["smoke".to_string()];
+        fixture.min_pass_rate = Some(1.0);
+        fixture.baseline_tolerance = Some(serde_json::from_str("{}").unwrap());
+        fixture.judge = Some(serde_json::from_str("{}").unwrap());
+        fixture.snapshot = Some(String::new());
+        fixture.cassette = Some(String::new());
+        fixture.min_confidence = Some(0.5);
+        fixture.source = Some(PathBuf::from("a.json"));
+
+        // `source` is `#[serde(skip)]`, so it is neither serialized nor a schema property
+        assert_eq!(keys(&schema["properties"]), keys(&serde_json::to_value(&fixture).unwrap()));
+        assert_eq!(
+            keys(&schema["$defs"]["expectation"]["properties"]),
+            keys(&serde_json::to_value(&fixture.expected).unwrap())
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn run_evals_keeps_fixture_order_with_parallel_workers() {
+        let fixture = |id: &str| EvalFixture {
+            pack: "no-such-pack".to_string(),
+            ..EvalFixture::test(id)
+        };
+        let fixtures = vec![fixture("c"), fixture("a"), fixture("b")];
+
+        let results = run_evals(&fixtures, 2, Duration::from_secs(10), false).await;
+        let ids: Vec<_> = results.iter().map(|r| r.eval_id.as_str()).collect();
+        assert_eq!(ids, ["c", "a", "b"]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn run_evals_times_out_hung_fixtures_and_reports_in_fixture_order() {
+        let fixtures = vec![EvalFixture::test("slow"), EvalFixture::test("fast"), EvalFixture::test("hung")];
+        let completed = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let finished = completed.clone();
+        let results = run_evals_with(&fixtures, 3, Duration::from_millis(500), move |fixture, run_id, control| {
+            match fixture.eval_id.as_str() {
+                "slow" => std::thread::sleep(Duration::from_millis(100)),
+                // Runs until the timeout cancels it
+                "hung" => {
+                    while !control.is_cancelled() {
+                        std::thread::sleep(Duration::from_millis(10));
+                    }
+                }
+                _ => {}
+            }
+            finished.lock().unwrap().push(fixture.eval_id.clone());
+            EvalResult {
+                run_id,
+                ..EvalResult::test(&fixture.eval_id, true)
+            }
+        })
+        .await;
+
+        let ids: Vec<_> = results.iter().map(|r| r.eval_id.as_str()).collect();
+        assert_eq!(ids, ["slow", "fast", "hung"]);
+        assert!(results[0].passed && results[1].passed);
+        assert!(!results[2].passed);
+        assert_eq!(results[2].error.as_deref(), Some("Timed out after 0.5s"));
+        assert_eq!(completed.lock().unwrap()[..2], ["fast", "slow"]);
+    }
+
     #[test]
     fn test_eval_check_logic() {
         let check = EvalCheck {
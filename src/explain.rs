@@ -0,0 +1,316 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! Explaining a completed run.
+//!
+//! `converge explain` answers "why" from a saved run output:
+//!
+//! - **Facts**: the agent and cycle that produced each fact and the facts it
+//!   was derived from (seeds were supplied, not produced)
+//! - **Invariants**: the invariants registered for the run. Individual
+//!   checks are not recorded; the engine stops with an error on the first
+//!   violation and such runs are not saved, so a saved run completed with
+//!   them in place
+//! - **Outcome**: whether the run reached a fixed point or ran out of cycles
+//! - **Silent agents**: agents that never executed, with what their
+//!   declined `accepts` checks saw
+//!
+//! Runs saved before provenance or agent statistics were recorded explain
+//! what they can and say what is missing.
+
+use serde::Serialize;
+
+use crate::output::RunOutput;
+use crate::telemetry::AgentStat;
+
+/// Why one fact is in the context.
+#[derive(Debug, Clone, Serialize)]
+pub struct FactExplanation {
+    pub key: String,
+    pub id: String,
+    /// Producing agent (`None` for seeds and unrecorded facts)
+    pub agent: Option<String>,
+    pub cycle: Option<u32>,
+    pub derived_from: Vec<String>,
+    pub reason: String,
+}
+
+/// An invariant registered for the run.
+#[derive(Debug, Clone, Serialize)]
+pub struct InvariantExplanation {
+    pub name: String,
+    /// What is known about it; check outcomes are not recorded
+    pub status: String,
+}
+
+/// An agent that never executed, and why.
+#[derive(Debug, Clone, Serialize)]
+pub struct SilentAgent {
+    pub name: String,
+    pub reason: String,
+}
+
+/// Everything `converge explain` reports about a run.
+#[derive(Debug, Clone, Serialize)]
+pub struct Explanation {
+    pub run_id: String,
+    pub template: String,
+    pub converged: bool,
+    pub cycles: u32,
+    /// Why the run converged or halted
+    pub outcome: String,
+    pub facts: Vec<FactExplanation>,
+    pub invariants: Vec<InvariantExplanation>,
+    pub silent_agents: Vec<SilentAgent>,
+    /// Agents that executed but never emitted a fact
+    pub unproductive_agents: Vec<SilentAgent>,
+    /// LLM items held back for review instead of entering the context
+    pub held_for_review: usize,
+    /// Parts of the trace the saved run does not have
+    pub missing: Vec<String>,
+}
+
+impl Explanation {
+    /// Explains a saved run.
+    pub fn of(output: &RunOutput) -> Self {
+        let mut missing = Vec::new();
+        if output.facts.iter().all(|f| f.provenance.is_none()) && output.facts.iter().any(|f| f.key != "Seeds") {
+            missing.push("fact provenance".to_string());
+        }
+        if output.agents.is_empty() {
+            missing.push("agent statistics".to_string());
+        }
+        if output.invariants.is_empty() {
+            missing.push("registered invariants".to_string());
+        }
+
+        let facts = output
+            .facts
+            .iter()
+            .map(|fact| {
+                let provenance = fact.provenance.as_ref();
+                let reason = match provenance {
+                    Some(p) if p.derived_from.is_empty() => {
+                        format!("emitted by {} in cycle {}", p.agent, p.cycle)
+                    }
+                    Some(p) => format!(
+                        "emitted by {} in cycle {} from {} fact(s)",
+                        p.agent,
+                        p.cycle,
+                        p.derived_from.len()
+                    ),
+                    None if fact.key == "Seeds" => "supplied as a seed".to_string(),
+                    None => "producer not recorded".to_string(),
+                };
+                FactExplanation {
+                    key: fact.key.clone(),
+                    id: fact.id.clone(),
+                    agent: provenance.map(|p| p.agent.clone()),
+                    cycle: provenance.map(|p| p.cycle),
+                    derived_from: provenance.map(|p| p.derived_from.clone()).unwrap_or_default(),
+                    reason,
+                }
+            })
+            .collect();
+
+        // A violation fails the run before it is saved
+        let invariants = output
+            .invariants
+            .iter()
+            .map(|name| InvariantExplanation {
+                name: name.clone(),
+                status: "registered (run completed)".to_string(),
+            })
+            .collect();
+
+        let silent_agents = output
+            .agents
+            .iter()
+            .filter(|a| a.runs == 0)
+            .map(|a| SilentAgent {
+                name: a.name.clone(),
+                reason: silent_reason(a),
+            })
+            .collect();
+        let unproductive_agents = output
+            .agents
+            .iter()
+            .filter(|a| a.runs > 0 && a.facts_produced() == 0)
+            .map(|a| SilentAgent {
+                name: a.name.clone(),
+                reason: format!("executed {} time(s) without emitting a fact", a.runs),
+            })
+            .collect();
+
+        Self {
+            run_id: output.run_id.clone(),
+            template: output.template.clone(),
+            converged: output.result.converged,
+            cycles: output.result.cycles,
+            outcome: outcome(output),
+            facts,
+            invariants,
+            silent_agents,
+            unproductive_agents,
+            held_for_review: output.review.len(),
+            missing,
+        }
+    }
+}
+
+fn outcome(output: &RunOutput) -> String {
+    let cycles = output.result.cycles;
+    if !output.result.converged {
+        return format!(
+            "Halted after {} cycles without reaching a fixed point: agents were still adding facts when the cycle budget ran out",
+            cycles
+        );
+    }
+    let last_productive = output
+        .facts
+        .iter()
+        .filter_map(|f| f.provenance.as_ref().map(|p| p.cycle))
+        .max();
+    match last_productive {
+        Some(cycle) => format!(
+            "Converged after {} cycles: the last new facts were added in cycle {}, after which no agent changed the context (fixed point)",
+            cycles, cycle
+        ),
+        None => format!(
+            "Converged after {} cycles: a cycle passed in which no agent changed the context (fixed point)",
+            cycles
+        ),
+    }
+}
+
+fn silent_reason(agent: &AgentStat) -> String {
+    if agent.declined == 0 {
+        return if agent.dependencies.is_empty() {
+            "never considered by the engine".to_string()
+        } else {
+            format!(
+                "never considered: its dependencies ({}) never changed",
+                agent.dependencies.join(", ")
+            )
+        };
+    }
+    if agent.missing_dependencies.is_empty() {
+        format!(
+            "accepts returned false {} time(s) although all its dependencies had facts (its own precondition did not hold)",
+            agent.declined
+        )
+    } else {
+        format!(
+            "accepts returned false {} time(s); at the last check there were no facts for {}",
+            agent.declined,
+            agent.missing_dependencies.join(", ")
+        )
+    }
+}
+
+/// Prints an explanation for humans.
+pub fn print_explanation(explanation: &Explanation) {
+    println!("\n=== Explain {} ({}) ===", explanation.run_id, explanation.template);
+    println!("{}", explanation.outcome);
+
+    println!("\nFacts:");
+    for fact in &explanation.facts {
+        println!("  [{}] {}: {}", fact.key, fact.id, fact.reason);
+        if !fact.derived_from.is_empty() {
+            println!("      from {}", fact.derived_from.join(", "));
+        }
+    }
+
+    println!("\nInvariants:");
+    if explanation.invariants.is_empty() {
+        println!("  (not recorded)");
+    }
+    for invariant in &explanation.invariants {
+        println!("  {}: {}", invariant.name, invariant.status);
+    }
+
+    println!("\nAgents that never executed:");
+    if explanation.silent_agents.is_empty() {
+        println!("  (none)");
+    }
+    for agent in &explanation.silent_agents {
+        println!("  {}: {}", agent.name, agent.reason);
+    }
+    for agent in &explanation.unproductive_agents {
+        println!("  {} (ran, no facts): {}", agent.name, agent.reason);
+    }
+
+    if explanation.held_for_review > 0 {
+        println!(
+            "\n{} LLM item(s) were held back for review and are not in the context",
+            explanation.held_for_review
+        );
+    }
+    if !explanation.missing.is_empty() {
+        println!("\nNot recorded in this run: {}", explanation.missing.join(", "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::FactOutput;
+    use crate::provenance::FactProvenance;
+
+    fn run(facts: Vec<FactOutput>, agents: Vec<AgentStat>) -> RunOutput {
+        let mut run = RunOutput::test(facts);
+        run.result.cycles = 4;
+        run.agents = agents;
+        run.invariants = vec!["RequireMultipleStrategies".to_string()];
+        run
+    }
+
+    fn fact(key: &str, id: &str, provenance: Option<FactProvenance>) -> FactOutput {
+        FactOutput {
+            provenance,
+            ..FactOutput::test(key, id, "")
+        }
+    }
+
+    #[test]
+    fn explains_facts_outcome_and_silent_agents() {
+        let facts = vec![
+            fact("Seeds", "seed:a", None),
+            fact(
+                "Signals",
+                "signal:1",
+                Some(FactProvenance {
+                    agent: "MarketSignalAgent".to_string(),
+                    cycle: 3,
+                    derived_from: vec!["seed:a".to_string()],
+                }),
+            ),
+        ];
+        let agents = vec![
+            AgentStat {
+                name: "MarketSignalAgent".to_string(),
+                runs: 1,
+                facts_by_key: [("Signals".to_string(), 1)].into(),
+                ..AgentStat::default()
+            },
+            AgentStat {
+                name: "RiskAssessmentAgent".to_string(),
+                declined: 2,
+                dependencies: vec!["Strategies".to_string(), "Evaluations".to_string()],
+                missing_dependencies: vec!["Evaluations".to_string()],
+                ..AgentStat::default()
+            },
+        ];
+
+        let explanation = Explanation::of(&run(facts, agents));
+        assert_eq!(explanation.facts[0].reason, "supplied as a seed");
+        assert_eq!(explanation.facts[1].reason, "emitted by MarketSignalAgent in cycle 3 from 1 fact(s)");
+        assert!(explanation.outcome.contains("cycle 3"));
+        assert_eq!(explanation.invariants[0].status, "registered (run completed)");
+        assert_eq!(explanation.silent_agents.len(), 1);
+        assert!(explanation.silent_agents[0].reason.ends_with("no facts for Evaluations"));
+        assert!(explanation.missing.is_empty());
+    }
+}
@@ -0,0 +1,423 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! Run reports for stakeholders.
+//!
+//! Renders a run's output as a Markdown, HTML or CSV report: run metadata
+//! followed by the context in reading order (seeds, signals, competitors,
+//! strategies with their evaluations, insights and risks). Keys without a
+//! section of their own are listed at the end, followed by the reviewer
+//! decisions of TUI jobs.
+//!
+//! Used by `converge export` and the TUI job detail view.
+
+use anyhow::{Context as _, Result};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::output::{FactOutput, RunOutput};
+
+/// Directory the TUI writes job reports to.
+pub const DEFAULT_EXPORTS_DIR: &str = ".converge/exports";
+
+/// Report sections: (context key, heading). Evaluations are listed under
+/// the strategy they evaluate rather than in a section of their own.
+const SECTIONS: &[(&str, &str)] = &[
+    ("Seeds", "Seeds"),
+    ("Signals", "Signals"),
+    ("Competitors", "Competitors"),
+    ("Strategies", "Strategies"),
+    ("Hypotheses", "Insights"),
+    ("Constraints", "Risks"),
+];
+
+/// Report format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Markdown,
+    Html,
+    Csv,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 3] = [ExportFormat::Markdown, ExportFormat::Html, ExportFormat::Csv];
+
+    /// File extension for the format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "md",
+            ExportFormat::Html => "html",
+            ExportFormat::Csv => "csv",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "md" | "markdown" => Ok(ExportFormat::Markdown),
+            "html" => Ok(ExportFormat::Html),
+            "csv" => Ok(ExportFormat::Csv),
+            other => anyhow::bail!("Unknown export format '{}' (expected md, html or csv)", other),
+        }
+    }
+}
+
+/// A fact in the report, with the evaluations that refer to it.
+#[derive(Debug)]
+struct Item<'a> {
+    fact: &'a FactOutput,
+    evaluations: Vec<&'a FactOutput>,
+}
+
+#[derive(Debug)]
+struct Section<'a> {
+    title: String,
+    items: Vec<Item<'a>>,
+}
+
+/// Groups a run's facts into report sections.
+///
+/// An evaluation belongs to the first strategy it refers to (see
+/// [`FactOutput::refers_to`]); evaluations matching no strategy get a
+/// section of their own.
+fn sections(output: &RunOutput) -> Vec<Section<'_>> {
+    let strategies: Vec<&FactOutput> = facts_for(output, "Strategies").collect();
+    let mut unmatched = Vec::new();
+    let mut evaluations_by_strategy: Vec<Vec<&FactOutput>> = vec![Vec::new(); strategies.len()];
+    for evaluation in facts_for(output, "Evaluations") {
+        match strategies.iter().position(|s| evaluation.refers_to(&s.id)) {
+            Some(i) => evaluations_by_strategy[i].push(evaluation),
+            None => unmatched.push(evaluation),
+        }
+    }
+    let mut evaluations_by_strategy = evaluations_by_strategy.into_iter();
+
+    let mut sections: Vec<Section> = SECTIONS
+        .iter()
+        .map(|&(key, title)| Section {
+            title: title.to_string(),
+            items: facts_for(output, key)
+                .map(|fact| Item {
+                    fact,
+                    evaluations: if key == "Strategies" {
+                        evaluations_by_strategy.next().unwrap_or_default()
+                    } else {
+                        Vec::new()
+                    },
+                })
+                .collect(),
+        })
+        .collect();
+
+    if !unmatched.is_empty() {
+        sections.push(Section {
+            title: "Evaluations".to_string(),
+            items: unmatched.into_iter().map(|fact| Item { fact, evaluations: Vec::new() }).collect(),
+        });
+    }
+
+    // Keys without a section, in output order
+    let mut other_keys: Vec<&str> = Vec::new();
+    for fact in &output.facts {
+        let key = fact.key.as_str();
+        if key != "Evaluations" && !SECTIONS.iter().any(|&(k, _)| k == key) && !other_keys.contains(&key) {
+            other_keys.push(key);
+        }
+    }
+    for key in other_keys {
+        sections.push(Section {
+            title: key.to_string(),
+            items: facts_for(output, key).map(|fact| Item { fact, evaluations: Vec::new() }).collect(),
+        });
+    }
+
+    sections
+}
+
+fn facts_for<'a>(output: &'a RunOutput, key: &'a str) -> impl Iterator<Item = &'a FactOutput> {
+    output.facts.iter().filter(move |f| f.key == key)
+}
+
+/// Run metadata as (label, value) pairs.
+fn metadata(output: &RunOutput) -> Vec<(&'static str, String)> {
+    let result = &output.result;
+    let outcome = if result.converged {
+        format!("Converged in {} cycles", result.cycles)
+    } else {
+        format!("Halted after {} cycles", result.cycles)
+    };
+    let provider = output
+        .provider
+        .as_ref()
+        .map_or_else(|| "not recorded".to_string(), |p| format!("{} ({})", p.name, p.model));
+    let mut rows = vec![
+        ("Run ID", output.run_id.clone()),
+        ("Correlation ID", output.correlation_id.clone()),
+        ("Template", output.template.clone()),
+        ("Timestamp", output.timestamp.clone()),
+        (
+            "Actor",
+            format!("{} {} (converge {})", output.actor.actor_type, output.actor.device_id, output.actor.cli_version),
+        ),
+        ("Provider", provider),
+        ("Result", format!("{}, {} facts", outcome, result.total_facts)),
+    ];
+    if !output.review.is_empty() {
+        rows.push(("Pending review", output.review.len().to_string()));
+    }
+    rows
+}
+
+/// One line per review decision: what was decided on which item, by whom and why.
+fn decision_lines(output: &RunOutput) -> Vec<String> {
+    output
+        .decisions
+        .iter()
+        .map(|d| format!("{} {} by {} at {}: {}", d.id, d.decision, d.reviewer, d.at, one_line(&d.reason)))
+        .collect()
+}
+
+fn confidence_note(fact: &FactOutput) -> String {
+    fact.confidence
+        .map(|c| format!(" (confidence {:.0}%)", c * 100.0))
+        .unwrap_or_default()
+}
+
+/// Renders a run report in `format`.
+pub fn render(output: &RunOutput, format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Markdown => render_markdown(output),
+        ExportFormat::Html => render_html(output),
+        ExportFormat::Csv => render_csv(output),
+    }
+}
+
+fn render_markdown(output: &RunOutput) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "# Run report: {}\n", output.template);
+    out.push_str("| | |\n|---|---|\n");
+    for (label, value) in metadata(output) {
+        let _ = writeln!(out, "| {} | {} |", label, value.replace('|', "\\|"));
+    }
+
+    for section in sections(output) {
+        let _ = writeln!(out, "\n## {}\n", section.title);
+        if section.items.is_empty() {
+            out.push_str("_None_\n");
+        }
+        for item in &section.items {
+            let fact = item.fact;
+            let _ = writeln!(out, "- **{}**: {}{}", fact.id, one_line(&fact.content), confidence_note(fact));
+            for evaluation in &item.evaluations {
+                let _ = writeln!(out, "  - _Evaluation_ {}: {}", evaluation.id, one_line(&evaluation.content));
+            }
+        }
+    }
+
+    let decisions = decision_lines(output);
+    if !decisions.is_empty() {
+        out.push_str("\n## Review decisions\n\n");
+        for line in decisions {
+            let _ = writeln!(out, "- {}", line);
+        }
+    }
+    out
+}
+
+fn render_html(output: &RunOutput) -> String {
+    let mut out = String::new();
+    let title = format!("Run report: {}", output.template);
+    let _ = writeln!(
+        out,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>",
+        escape_html(&title)
+    );
+    out.push_str(
+        "<style>\nbody { font-family: sans-serif; max-width: 60em; margin: 2em auto; }\n\
+         table.meta th { text-align: left; padding-right: 1em; }\n\
+         .id { color: #555; font-family: monospace; }\n\
+         .evaluation { color: #333; font-size: 0.95em; }\n</style>\n</head>\n<body>\n",
+    );
+    let _ = writeln!(out, "<h1>{}</h1>\n<table class=\"meta\">", escape_html(&title));
+    for (label, value) in metadata(output) {
+        let _ = writeln!(out, "<tr><th>{}</th><td>{}</td></tr>", label, escape_html(&value));
+    }
+    out.push_str("</table>\n");
+
+    for section in sections(output) {
+        let _ = writeln!(out, "<h2>{}</h2>", escape_html(&section.title));
+        if section.items.is_empty() {
+            out.push_str("<p><em>None</em></p>\n");
+            continue;
+        }
+        out.push_str("<ul>\n");
+        for item in &section.items {
+            let fact = item.fact;
+            let _ = write!(
+                out,
+                "<li><span class=\"id\">{}</span> {}{}",
+                escape_html(&fact.id),
+                escape_html(&fact.content),
+                escape_html(&confidence_note(fact))
+            );
+            if !item.evaluations.is_empty() {
+                out.push_str("\n<ul>\n");
+                for evaluation in &item.evaluations {
+                    let _ = writeln!(
+                        out,
+                        "<li class=\"evaluation\"><span class=\"id\">{}</span> {}</li>",
+                        escape_html(&evaluation.id),
+                        escape_html(&evaluation.content)
+                    );
+                }
+                out.push_str("</ul>\n");
+            }
+            out.push_str("</li>\n");
+        }
+        out.push_str("</ul>\n");
+    }
+
+    let decisions = decision_lines(output);
+    if !decisions.is_empty() {
+        out.push_str("<h2>Review decisions</h2>\n<ul>\n");
+        for line in decisions {
+            let _ = writeln!(out, "<li>{}</li>", escape_html(&line));
+        }
+        out.push_str("</ul>\n");
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+/// One row per fact; evaluations follow the strategy they belong to.
+fn render_csv(output: &RunOutput) -> String {
+    let mut out = String::from("run_id,section,sequence,key,id,content,confidence\n");
+    let mut row = |section: &str, fact: &FactOutput| {
+        let sequence = fact.sequence.to_string();
+        let confidence = fact.confidence.map(|c| format!("{:.2}", c)).unwrap_or_default();
+        let fields = [
+            output.run_id.as_str(),
+            section,
+            &sequence,
+            &fact.key,
+            &fact.id,
+            &fact.content,
+            &confidence,
+        ];
+        let line: Vec<String> = fields.iter().map(|f| escape_csv(f)).collect();
+        out.push_str(&line.join(","));
+        out.push('\n');
+    };
+    for section in sections(output) {
+        for item in &section.items {
+            row(&section.title, item.fact);
+            for evaluation in &item.evaluations {
+                row(&section.title, evaluation);
+            }
+        }
+    }
+    out
+}
+
+/// Writes the report in every format to `dir/<run_id>.<ext>`.
+pub fn write_all(output: &RunOutput, dir: &Path) -> Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    ExportFormat::ALL
+        .iter()
+        .map(|&format| {
+            let path = dir.join(format!("{}.{}", output.run_id, format.extension()));
+            std::fs::write(&path, render(output, format))
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            Ok(path)
+        })
+        .collect()
+}
+
+fn one_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::confidence::ReviewRecord;
+
+    #[test]
+    fn evaluations_are_grouped_under_their_strategy() {
+        let output = RunOutput::test(vec![
+            FactOutput::test("Strategies", "strategy:1", "Expand to Nordics"),
+            FactOutput::test("Strategies", "strategy:10", "Partner channel"),
+            FactOutput::test("Evaluations", "eval:strategy:10", "Score: 80/100"),
+            FactOutput::test("Evaluations", "eval:other", "Unrelated"),
+            FactOutput::test("Diagnostic", "diag:1", "note"),
+        ]);
+        let sections = sections(&output);
+        let strategies = sections.iter().find(|s| s.title == "Strategies").unwrap();
+        assert!(strategies.items[0].evaluations.is_empty());
+        assert_eq!(strategies.items[1].evaluations[0].id, "eval:strategy:10");
+
+        let titles: Vec<&str> = sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(&titles[SECTIONS.len()..], ["Evaluations", "Diagnostic"]);
+    }
+
+    #[test]
+    fn formats_escape_their_content() {
+        let output = RunOutput::test(vec![FactOutput::test("Hypotheses", "insight:1", "Grow <fast>, \"safely\"")]);
+        let html = render(&output, ExportFormat::Html);
+        assert!(html.contains("Grow &lt;fast&gt;, &quot;safely&quot;"));
+
+        let csv = render(&output, ExportFormat::Csv);
+        assert!(csv.contains("run_1,Insights,1,Hypotheses,insight:1,\"Grow <fast>, \"\"safely\"\"\","));
+
+        let markdown = render(&output, ExportFormat::Markdown);
+        assert!(markdown.contains("## Insights\n\n- **insight:1**: Grow <fast>, \"safely\""));
+        assert!(markdown.contains("| Provider | not recorded |"));
+    }
+
+    #[test]
+    fn review_decisions_close_the_report() {
+        let mut output = RunOutput::test(vec![FactOutput::test("Hypotheses", "insight:1", "Grow")]);
+        output.decisions.push(ReviewRecord {
+            at: "2025-01-01 10:00:00".to_string(),
+            reviewer: "ana".to_string(),
+            id: "insight:2".to_string(),
+            decision: "rejected".to_string(),
+            reason: "Not <grounded>".to_string(),
+            committed: None,
+        });
+
+        let markdown = render(&output, ExportFormat::Markdown);
+        assert!(markdown.ends_with("## Review decisions\n\n- insight:2 rejected by ana at 2025-01-01 10:00:00: Not <grounded>\n"));
+        let html = render(&output, ExportFormat::Html);
+        assert!(html.contains("<li>insight:2 rejected by ana at 2025-01-01 10:00:00: Not &lt;grounded&gt;</li>"));
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! Content hashes for reproducibility checks.
+//!
+//! The context hash identifies a run's final context independently of fact
+//! order: facts are sorted by key and id, and each contributes its key, id
+//! and content, each prefixed with its byte length (u64, little-endian) so
+//! no text can shift one field into the next. Confidence and provenance are
+//! not hashed; two runs with the same facts have the same context.
+//!
+//! [`ContextHash`] adds one hash per `ContextKey` with the same encoding,
+//! so drift can be traced to the keys that changed. It is included in run
+//! outputs, the streaming final status event and eval results.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+
+use converge_core::{Context, ContextKey};
+use strum::IntoEnumIterator;
+
+use crate::output::FactOutput;
+
+/// Canonical hashes of a final context.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContextHash {
+    /// Hash over every fact (as [`context_hash`])
+    pub overall: String,
+    /// Hash over the facts of each non-empty key
+    pub by_key: BTreeMap<String, String>,
+}
+
+impl ContextHash {
+    /// Hashes a run output's facts.
+    pub fn of(facts: &[FactOutput]) -> Self {
+        Self::from_triples(facts.iter().map(|f| (f.key.as_str(), f.id.as_str(), f.content.as_str())))
+    }
+
+    /// Hashes the facts of an engine context.
+    pub fn of_context(context: &Context) -> Self {
+        let keys: Vec<(ContextKey, String)> = ContextKey::iter().map(|key| (key, format!("{:?}", key))).collect();
+        Self::from_triples(keys.iter().flat_map(|(key, name)| {
+            context
+                .get(*key)
+                .iter()
+                .map(move |f| (name.as_str(), f.id.as_str(), f.content.as_str()))
+        }))
+    }
+
+    fn from_triples<'a>(facts: impl Iterator<Item = (&'a str, &'a str, &'a str)>) -> Self {
+        let mut facts: Vec<_> = facts.collect();
+        facts.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+
+        let mut by_key = BTreeMap::new();
+        for chunk in facts.chunk_by(|a, b| a.0 == b.0) {
+            by_key.insert(chunk[0].0.to_string(), hash_sorted(chunk));
+        }
+        Self {
+            overall: hash_sorted(&facts),
+            by_key,
+        }
+    }
+
+    /// First 12 hex digits of the overall hash, for display.
+    pub fn short(&self) -> &str {
+        &self.overall[..self.overall.len().min(12)]
+    }
+}
+
+/// Lowercase hex SHA-256 of `data`.
+pub fn sha256_hex(data: impl AsRef<[u8]>) -> String {
+    format!("{:x}", Sha256::digest(data.as_ref()))
+}
+
+/// Hash of a context's facts, independent of their order.
+pub fn context_hash(facts: &[FactOutput]) -> String {
+    ContextHash::of(facts).overall
+}
+
+/// SHA-256 of `fields`, each length-prefixed so that moving text from one
+/// field to the next changes the hash.
+pub fn hash_fields<'a>(fields: impl IntoIterator<Item = &'a str>) -> String {
+    let mut hasher = Sha256::new();
+    for field in fields {
+        hasher.update((field.len() as u64).to_le_bytes());
+        hasher.update(field.as_bytes());
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// Hashes (key, id, content) triples already sorted by key and id.
+fn hash_sorted(facts: &[(&str, &str, &str)]) -> String {
+    hash_fields(facts.iter().flat_map(|&(key, id, content)| [key, id, content]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn context_hash_ignores_order_but_not_content() {
+        let a = FactOutput::test("Seeds", "seed:a", "a");
+        let b = FactOutput::test("Signals", "signal:1", "b");
+        let hash = context_hash(&[a.clone(), b.clone()]);
+        assert_eq!(hash, context_hash(&[b.clone(), a.clone()]));
+        assert_eq!(hash.len(), 64);
+
+        let changed = FactOutput::test("Signals", "signal:1", "c");
+        assert_ne!(hash, context_hash(&[a, changed]));
+        assert_eq!(sha256_hex(""), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+    }
+
+    #[test]
+    fn field_boundaries_are_part_of_the_hash() {
+        let split = context_hash(&[FactOutput::test("Seeds", "seed:a\u{1f}b", "c")]);
+        let shifted = context_hash(&[FactOutput::test("Seeds", "seed:a", "b\u{1f}c")]);
+        assert_ne!(split, shifted);
+    }
+
+    #[test]
+    fn per_key_hashes_change_only_for_the_changed_key() {
+        let before = ContextHash::of(&[FactOutput::test("Seeds", "seed:a", "a"), FactOutput::test("Signals", "signal:1", "b")]);
+        let after = ContextHash::of(&[FactOutput::test("Seeds", "seed:a", "a"), FactOutput::test("Signals", "signal:1", "c")]);
+
+        assert_eq!(before.by_key.len(), 2);
+        assert_eq!(before.by_key["Seeds"], after.by_key["Seeds"]);
+        assert_ne!(before.by_key["Signals"], after.by_key["Signals"]);
+        assert_ne!(before.overall, after.overall);
+    }
+
+    #[test]
+    fn engine_context_and_run_output_hash_alike() {
+        let mut context = Context::new();
+        context.add_fact(converge_core::Fact::new(ContextKey::Seeds, "seed:a", "a")).unwrap();
+        context.add_fact(converge_core::Fact::new(ContextKey::Signals, "signal:1", "b")).unwrap();
+
+        let from_output = ContextHash::of(&[FactOutput::test("Signals", "signal:1", "b"), FactOutput::test("Seeds", "seed:a", "a")]);
+        assert_eq!(ContextHash::of_context(&context), from_output);
+    }
+}
@@ -0,0 +1,364 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! LLM-as-judge rubric checks for eval fixtures.
+//!
+//! Shape checks pass even when LLM insights are nonsense. A fixture with a
+//! `judge` section has the produced facts (Hypotheses and Constraints by
+//! default) scored against its seeds and a rubric, and each score threshold
+//! becomes an `EvalCheck`:
+//!
+//! ```json
+//! "judge": {
+//!   "rubric": "Insights must be specific to Nordic B2B SaaS.",
+//!   "min_scores": {"relevance": 0.7, "specificity": 0.5, "grounding": 0.6}
+//! }
+//! ```
+//!
+//! Scores run from 0.0 to 1.0; without `min_scores` the three criteria
+//! above must reach 0.5. Fixtures using the mock LLM, or any fixture when
+//! `CONVERGE_JUDGE=stub`, use the deterministic [`StubJudge`]. Otherwise the
+//! judge is an LLM:
+//!
+//! - `CONVERGE_JUDGE=anthropic|openai` picks the provider (default: the
+//!   first with credentials, Anthropic before OpenAI)
+//! - `CONVERGE_JUDGE_MODEL` overrides the provider's default model
+//!
+//! Without credentials the judge check fails; it never falls back to a mock.
+
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::Arc;
+
+use converge_core::llm::{LlmProvider, LlmRequest};
+use converge_provider::{AnthropicProvider, OpenAiProvider};
+
+use crate::evals::{EvalCheck, EvalFixture, SeedFact};
+use crate::output::FactOutput;
+use crate::wiring::{ANTHROPIC_MODEL, OPENAI_MODEL};
+
+/// Criteria scored when a fixture sets no `min_scores`.
+pub const DEFAULT_CRITERIA: [&str; 3] = ["relevance", "specificity", "grounding"];
+
+/// Threshold of each default criterion.
+pub const DEFAULT_MIN_SCORE: f64 = 0.5;
+
+const JUDGE_SYSTEM_PROMPT: &str = "You are a strict evaluator of strategic analysis. \
+Score the findings against the business context and rubric. Respond with JSON only.";
+
+/// The `judge` section of a fixture.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JudgeSpec {
+    /// Context keys whose facts are judged
+    #[serde(default = "default_judged_keys")]
+    pub keys: Vec<String>,
+
+    /// What good findings look like for this fixture
+    #[serde(default)]
+    pub rubric: String,
+
+    /// Minimum score (0.0-1.0) per criterion
+    #[serde(default)]
+    pub min_scores: BTreeMap<String, f64>,
+}
+
+fn default_judged_keys() -> Vec<String> {
+    vec!["Hypotheses".to_string(), "Constraints".to_string()]
+}
+
+/// What a judge is asked to score.
+#[derive(Debug, Clone)]
+pub struct JudgeRequest<'a> {
+    pub seeds: &'a [SeedFact],
+    pub facts: Vec<&'a FactOutput>,
+    pub rubric: &'a str,
+    pub criteria: Vec<&'a str>,
+}
+
+/// A judge's verdict.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JudgeScores {
+    /// Score per criterion, 0.0-1.0
+    pub scores: BTreeMap<String, f64>,
+    #[serde(default)]
+    pub rationale: String,
+}
+
+/// Scores produced facts against a rubric.
+pub trait Judge: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn score(&self, request: &JudgeRequest<'_>) -> Result<JudgeScores>;
+}
+
+/// Judge backed by an LLM provider.
+pub struct LlmJudge {
+    provider: Arc<dyn LlmProvider>,
+}
+
+impl LlmJudge {
+    pub fn new(provider: Arc<dyn LlmProvider>) -> Self {
+        Self { provider }
+    }
+
+    fn build_prompt(request: &JudgeRequest<'_>) -> String {
+        let mut prompt = String::from("## Business context (seeds)\n");
+        for seed in request.seeds {
+            let _ = writeln!(prompt, "- {}: {}", seed.id, seed.content);
+        }
+        prompt.push_str("\n## Findings to evaluate\n");
+        for fact in &request.facts {
+            let _ = writeln!(prompt, "- [{}] {}: {}", fact.key, fact.id, fact.content);
+        }
+        if !request.rubric.is_empty() {
+            let _ = write!(prompt, "\n## Rubric\n{}\n", request.rubric);
+        }
+        prompt.push_str("\n## Task\nScore the findings from 0.0 to 1.0 on each criterion:\n");
+        for criterion in &request.criteria {
+            let _ = writeln!(prompt, "- {}: {}", criterion, criterion_description(criterion));
+        }
+        prompt.push_str(
+            "\nRespond with a single JSON object, e.g. \
+             {\"scores\": {\"relevance\": 0.8}, \"rationale\": \"one or two sentences\"}",
+        );
+        prompt
+    }
+}
+
+fn criterion_description(criterion: &str) -> &'static str {
+    match criterion {
+        "relevance" => "do the findings address the business context and goal?",
+        "specificity" => "are they concrete and actionable rather than generic advice?",
+        "grounding" => "are they supported by the seeds rather than invented?",
+        _ => "as described in the rubric",
+    }
+}
+
+impl Judge for LlmJudge {
+    fn name(&self) -> &str {
+        self.provider.name()
+    }
+
+    fn score(&self, request: &JudgeRequest<'_>) -> Result<JudgeScores> {
+        let llm_request = LlmRequest::new(Self::build_prompt(request)).with_system(JUDGE_SYSTEM_PROMPT.to_string());
+        let response = tokio::task::block_in_place(|| self.provider.complete(&llm_request))
+            .map_err(|e| anyhow::anyhow!("Judge call failed: {}", e))?;
+        parse_scores(&response.content)
+    }
+}
+
+/// Extracts the JSON verdict from a judge response, tolerating prose or a
+/// code fence around it.
+fn parse_scores(response: &str) -> Result<JudgeScores> {
+    let start = response.find('{').context("Judge response contains no JSON object")?;
+    let end = response
+        .rfind('}')
+        .filter(|&end| end > start)
+        .context("Judge response contains no JSON object")?;
+    let scores: JudgeScores = serde_json::from_str(&response[start..=end])
+        .with_context(|| format!("Judge response is not a verdict: {}", &response[start..=end]))?;
+    Ok(scores)
+}
+
+/// Deterministic judge for offline runs: gives every criterion the same
+/// score unless overridden.
+#[derive(Debug, Clone)]
+pub struct StubJudge {
+    default_score: f64,
+    scores: BTreeMap<String, f64>,
+}
+
+impl Default for StubJudge {
+    fn default() -> Self {
+        Self::uniform(1.0)
+    }
+}
+
+impl StubJudge {
+    /// Scores every criterion `score`.
+    pub fn uniform(score: f64) -> Self {
+        Self {
+            default_score: score,
+            scores: BTreeMap::new(),
+        }
+    }
+
+    /// Scores `criterion` as `score`.
+    #[must_use]
+    pub fn with_score(mut self, criterion: &str, score: f64) -> Self {
+        self.scores.insert(criterion.to_string(), score);
+        self
+    }
+}
+
+impl Judge for StubJudge {
+    fn name(&self) -> &str {
+        "stub"
+    }
+
+    fn score(&self, request: &JudgeRequest<'_>) -> Result<JudgeScores> {
+        Ok(JudgeScores {
+            scores: request
+                .criteria
+                .iter()
+                .map(|c| (c.to_string(), self.scores.get(*c).copied().unwrap_or(self.default_score)))
+                .collect(),
+            rationale: "stub judge".to_string(),
+        })
+    }
+}
+
+/// The judge for a fixture: the stub for mock-LLM fixtures or when
+/// `CONVERGE_JUDGE=stub`, otherwise an LLM judge as configured by
+/// `CONVERGE_JUDGE` and `CONVERGE_JUDGE_MODEL`.
+pub fn judge_for(fixture: &EvalFixture) -> Result<Arc<dyn Judge>> {
+    if !needs_llm(fixture) {
+        return Ok(Arc::new(StubJudge::default()));
+    }
+    let choice = judge_choice();
+    let provider = match choice.as_str() {
+        "" if has_key("ANTHROPIC_API_KEY") => "anthropic",
+        "" if has_key("OPENAI_API_KEY") => "openai",
+        "" => anyhow::bail!(
+            "No LLM judge available: set ANTHROPIC_API_KEY or OPENAI_API_KEY, or CONVERGE_JUDGE=stub"
+        ),
+        other => other,
+    };
+    let model = std::env::var("CONVERGE_JUDGE_MODEL").ok().filter(|m| !m.trim().is_empty());
+    Ok(Arc::new(LlmJudge::new(judge_provider(provider, model.as_deref())?)))
+}
+
+/// Whether judging `fixture` calls a real LLM: it has a judge section, does
+/// not use the mock LLM and `CONVERGE_JUDGE` is not `stub`.
+pub fn needs_llm(fixture: &EvalFixture) -> bool {
+    fixture.judge.is_some() && !fixture.use_mock_llm && judge_choice() != "stub"
+}
+
+/// `CONVERGE_JUDGE`, lowercased (empty if unset).
+fn judge_choice() -> String {
+    std::env::var("CONVERGE_JUDGE").unwrap_or_default().trim().to_ascii_lowercase()
+}
+
+fn has_key(var: &str) -> bool {
+    std::env::var(var).is_ok_and(|v| !v.trim().is_empty())
+}
+
+/// Creates the judge's LLM provider.
+fn judge_provider(provider: &str, model: Option<&str>) -> Result<Arc<dyn LlmProvider>> {
+    // The providers use blocking HTTP clients
+    tokio::task::block_in_place(|| match provider {
+        "anthropic" => {
+            let model = model.unwrap_or(ANTHROPIC_MODEL);
+            AnthropicProvider::from_env(model)
+                .map(|p| Arc::new(p) as Arc<dyn LlmProvider>)
+                .map_err(|e| anyhow::anyhow!("Cannot create the Anthropic judge ({}): {}", model, e))
+        }
+        "openai" => {
+            let model = model.unwrap_or(OPENAI_MODEL);
+            OpenAiProvider::from_env(model)
+                .map(|p| Arc::new(p) as Arc<dyn LlmProvider>)
+                .map_err(|e| anyhow::anyhow!("Cannot create the OpenAI judge ({}): {}", model, e))
+        }
+        other => anyhow::bail!("Unknown CONVERGE_JUDGE '{}' (expected stub, anthropic or openai)", other),
+    })
+}
+
+/// Has `judge` score a run's facts and turns the thresholds into checks.
+pub fn checks(spec: &JudgeSpec, judge: &dyn Judge, seeds: &[SeedFact], facts: &[FactOutput]) -> Vec<EvalCheck> {
+    let judged: Vec<&FactOutput> = facts.iter().filter(|f| spec.keys.contains(&f.key)).collect();
+    let failed = |actual: String| {
+        vec![EvalCheck {
+            name: "judge".to_string(),
+            passed: false,
+            expected: format!("{} to score the findings", judge.name()),
+            actual,
+        }]
+    };
+    if judged.is_empty() {
+        return failed(format!("no {} facts to judge", spec.keys.join("/")));
+    }
+
+    let thresholds: BTreeMap<String, f64> = if spec.min_scores.is_empty() {
+        DEFAULT_CRITERIA.iter().map(|c| (c.to_string(), DEFAULT_MIN_SCORE)).collect()
+    } else {
+        spec.min_scores.clone()
+    };
+    let request = JudgeRequest {
+        seeds,
+        facts: judged,
+        rubric: &spec.rubric,
+        criteria: thresholds.keys().map(String::as_str).collect(),
+    };
+    let verdict = match judge.score(&request) {
+        Ok(verdict) => verdict,
+        Err(e) => return failed(e.to_string()),
+    };
+
+    thresholds
+        .iter()
+        .map(|(criterion, min)| {
+            let score = verdict.scores.get(criterion).copied();
+            let passed = score.is_some_and(|s| s >= *min);
+            let actual = match score {
+                Some(s) if passed => format!("{:.2}", s),
+                Some(s) => format!("{:.2} ({})", s, verdict.rationale),
+                None => "not scored".to_string(),
+            };
+            EvalCheck {
+                name: format!("judge:{}", criterion),
+                passed,
+                expected: format!(">= {:.2}", min),
+                actual,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec() -> JudgeSpec {
+        serde_json::from_str(r#"{"rubric": "Be specific", "min_scores": {"relevance": 0.7, "grounding": 0.6}}"#).unwrap()
+    }
+
+    fn insight() -> FactOutput {
+        FactOutput::test("Hypotheses", "insight:1", "Nordic SMBs adopt SaaS through partners")
+    }
+
+    #[test]
+    fn thresholds_become_checks() {
+        let judge = StubJudge::uniform(0.8).with_score("grounding", 0.4);
+        let checks = checks(&spec(), &judge, &[], &[insight()]);
+
+        assert_eq!(checks.len(), 2);
+        assert_eq!(checks[0].name, "judge:grounding");
+        assert!(!checks[0].passed);
+        assert_eq!(checks[0].actual, "0.40 (stub judge)");
+        assert!(checks[1].passed);
+    }
+
+    #[test]
+    fn nothing_to_judge_fails() {
+        let checks = checks(&spec(), &StubJudge::default(), &[], &[]);
+        assert_eq!(checks.len(), 1);
+        assert!(!checks[0].passed);
+        assert_eq!(checks[0].actual, "no Hypotheses/Constraints facts to judge");
+    }
+
+    #[test]
+    fn parses_verdicts_wrapped_in_prose() {
+        let verdict = parse_scores(
+            "Here you go:\n```json\n{\"scores\": {\"relevance\": 0.9}, \"rationale\": \"on topic\"}\n```",
+        )
+        .unwrap();
+        assert_eq!(verdict.scores["relevance"], 0.9);
+        assert!(parse_scores("no json").is_err());
+        assert!(parse_scores("} n/a {").is_err());
+    }
+}
@@ -26,44 +26,78 @@
 //!
 //! # List available domain packs
 //! converge packs list
+//!
+//! # Compare two saved runs (run ids or JSON files)
+//! converge diff <run_a> <run_b>
+//!
+//! # Explain why a saved run produced each fact and how it ended
+//! converge explain <run>
+//!
+//! # Export a saved run as a report (md, html or csv)
+//! converge export <run> --format html -o report.html
+//!
+//! # Drive a running converge server from the TUI
+//! converge tui --remote http://localhost:8080
+//!
+//! # Re-execute a saved run with its recorded LLM responses
+//! converge replay <run_id or manifest.json>
 //! ```
 
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
 mod agents;
+mod confidence;
 mod config;
+mod control;
+mod diff;
+mod eval_assertions;
+mod eval_baseline;
+mod eval_create;
+mod eval_filter;
+mod eval_report;
+mod eval_snapshot;
+mod eval_stats;
+mod eval_validate;
 mod evals;
+mod explain;
+mod export;
+mod hashing;
+mod judge;
+mod output;
 mod packs;
+mod provenance;
+mod replay;
+mod store;
 mod streaming;
+mod telemetry;
 mod ui;
+mod wiring;
 
 use anyhow::Result;
 use chrono::Utc;
 use clap::{Parser, Subcommand};
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
-use serde::Serialize;
-use std::io;
+use std::io::{self, IsTerminal};
 use std::panic;
 use std::sync::Arc;
 use tracing::{info, warn};
 use tracing_subscriber::EnvFilter;
 
-use crate::agents::{MockInsightProvider, RiskAssessmentAgent, StrategicInsightAgent};
+use crate::confidence::ConfidenceLedger;
+use crate::control::JobControl;
+use crate::hashing::ContextHash;
+use crate::output::{fact_outputs, ActorInfo, RunOutput, RunResultOutput};
+use crate::replay::{CassetteMode, CassetteRecorder, RunManifest};
+use crate::store::RunStore;
+use crate::wiring::{register_pack_agents, AgentWiring};
 
-use converge_core::llm::LlmProvider;
 use converge_core::{Context, ContextKey, Engine, Fact};
-use converge_provider::{AnthropicProvider, OpenAiProvider};
-use converge_domain::growth_strategy::{
-    BrandSafetyInvariant, CompetitorAgent, EvaluationAgent, MarketSignalAgent,
-    RequireEvaluationRationale, RequireMultipleStrategies, RequireStrategyEvaluations,
-    StrategyAgent,
-};
 use strum::IntoEnumIterator;
 
 /// Converge - Semantic convergence engine for agentic workflows
@@ -79,7 +113,12 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     /// Launch interactive TUI
-    Tui,
+    Tui {
+        /// Drive a running converge server instead of the local engine
+        /// (e.g. http://localhost:8080)
+        #[arg(long, env = "CONVERGE_REMOTE")]
+        remote: Option<String>,
+    },
 
     /// Manage domain packs
     Packs {
@@ -113,6 +152,11 @@ enum Commands {
         #[arg(long)]
         mock: bool,
 
+        /// Divert LLM facts below this confidence (0.0-1.0) to review
+        /// (default: `validation.min_confidence` in .converge/config.json)
+        #[arg(long, env = "CONVERGE_MIN_CONFIDENCE")]
+        min_confidence: Option<f64>,
+
         /// Output as JSON
         #[arg(long)]
         json: bool,
@@ -121,9 +165,62 @@ enum Commands {
         #[arg(long)]
         stream: bool,
 
-        /// Quiet mode: exit code only, no output
+        /// Quiet mode: exit code only, no output (implies --no-save)
         #[arg(long)]
         quiet: bool,
+
+        /// Do not save the output, manifest and cassette to the run store
+        #[arg(long, env = "CONVERGE_NO_SAVE")]
+        no_save: bool,
+    },
+
+    /// Compare the contexts of two runs
+    Diff {
+        /// First run: a saved JSON output or a run id in the run store
+        run_a: String,
+
+        /// Second run: a saved JSON output or a run id in the run store
+        run_b: String,
+
+        /// Output the diff as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Explain a completed run: fact lineage, invariants, outcome and
+    /// agents that never fired
+    Explain {
+        /// A saved JSON output or a run id in the run store
+        run: String,
+
+        /// Output the explanation as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Export a run as a Markdown, HTML or CSV report
+    Export {
+        /// A saved JSON output or a run id in the run store
+        run: String,
+
+        /// Report format: md, html or csv
+        #[arg(short, long, default_value = "md")]
+        format: export::ExportFormat,
+
+        /// Write the report to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+    },
+
+    /// Re-execute a saved run with its recorded LLM responses and verify
+    /// that the resulting context matches
+    Replay {
+        /// A manifest file or a run id in the run store
+        manifest: String,
+
+        /// Output the replay report as JSON
+        #[arg(long)]
+        json: bool,
     },
 
     /// Run eval fixtures for reproducible testing
@@ -137,8 +234,20 @@ enum Commands {
 enum EvalCommands {
     /// Run eval fixtures
     Run {
-        /// Specific eval ID to run (runs all if not specified)
-        eval_id: Option<String>,
+        /// Eval IDs or globs (e.g. growth_*) to run (runs all if not specified)
+        eval_ids: Vec<String>,
+
+        /// Only run fixtures with any of these tags (repeatable)
+        #[arg(long = "tag", value_name = "TAG")]
+        tags: Vec<String>,
+
+        /// Skip fixtures with any of these tags (repeatable)
+        #[arg(long = "exclude-tag", value_name = "TAG")]
+        exclude_tags: Vec<String>,
+
+        /// Run a suite defined in the directory's suites.json
+        #[arg(long)]
+        suite: Option<String>,
 
         /// Directory containing eval fixtures
         #[arg(short, long, default_value = "evals")]
@@ -147,6 +256,66 @@ enum EvalCommands {
         /// Use mock LLM for faster deterministic tests
         #[arg(long)]
         mock: bool,
+
+        /// Number of fixtures to run concurrently
+        #[arg(short, long, default_value = "1")]
+        jobs: usize,
+
+        /// Per-fixture timeout in seconds
+        #[arg(long, default_value_t = evals::DEFAULT_EVAL_TIMEOUT_SECS)]
+        timeout: u64,
+
+        /// Run each fixture this many times and report pass rates
+        #[arg(long, default_value = "1")]
+        repeat: usize,
+
+        /// Also write a report: junit:PATH or json:PATH (repeatable)
+        #[arg(long = "report", value_name = "FORMAT:PATH")]
+        reports: Vec<eval_report::ReportSpec>,
+
+        /// Save each fixture's metrics as its baseline
+        #[arg(long)]
+        save_baseline: bool,
+
+        /// Fail if a metric regresses against the saved baseline
+        #[arg(long)]
+        compare_baseline: bool,
+
+        /// Directory of eval baselines
+        #[arg(long, default_value = eval_baseline::DEFAULT_BASELINES_DIR)]
+        baseline_dir: String,
+
+        /// Rewrite golden context snapshots instead of comparing with them
+        #[arg(long)]
+        update_snapshots: bool,
+    },
+    /// Generate an eval fixture from a recorded run
+    Create {
+        /// Run to generate from: a saved JSON output or a run id
+        #[arg(long = "from-run", value_name = "RUN")]
+        from_run: String,
+
+        /// Eval ID (default: <template>_<run id prefix>)
+        #[arg(long)]
+        eval_id: Option<String>,
+
+        /// Directory to write the fixture to
+        #[arg(short, long, default_value = "evals")]
+        dir: String,
+
+        /// Replay the run's recorded LLM responses instead of calling the provider
+        #[arg(long)]
+        with_cassette: bool,
+
+        /// Overwrite an existing fixture
+        #[arg(long)]
+        force: bool,
+    },
+    /// Check eval fixtures strictly (unknown fields, keys and packs, duplicate ids)
+    Validate {
+        /// Directory containing eval fixtures
+        #[arg(short, long, default_value = "evals")]
+        dir: String,
     },
     /// List available eval fixtures
     List {
@@ -167,40 +336,6 @@ enum PacksCommands {
     },
 }
 
-/// JSON output format for run results (Cross-Platform Contract compliant)
-#[derive(Debug, Serialize)]
-struct RunOutput {
-    run_id: String,
-    correlation_id: String,
-    timestamp: String,
-    actor: ActorInfo,
-    result: RunResultOutput,
-    facts: Vec<FactOutput>,
-}
-
-#[derive(Debug, Serialize)]
-struct ActorInfo {
-    #[serde(rename = "type")]
-    actor_type: String,
-    device_id: String,
-    cli_version: String,
-}
-
-#[derive(Debug, Serialize)]
-struct RunResultOutput {
-    converged: bool,
-    cycles: u32,
-    total_facts: usize,
-}
-
-#[derive(Debug, Serialize)]
-struct FactOutput {
-    sequence: usize,
-    key: String,
-    id: String,
-    content: String,
-}
-
 #[tokio::main]
 async fn main() -> Result<()> {
     // Load .env if present
@@ -208,13 +343,15 @@ async fn main() -> Result<()> {
 
     let cli = Cli::parse();
 
-    // Check if we should suppress tracing (quiet mode for Run command)
+    // Suppress tracing in quiet mode and in the TUI, where log lines
+    // would be drawn over the screen (jobs and evals log from the shared
+    // agent wiring)
     let suppress_tracing = matches!(
         &cli.command,
-        Commands::Run { quiet: true, .. }
+        Commands::Run { quiet: true, .. } | Commands::Tui { .. }
     );
 
-    // Initialize tracing (skip for quiet mode)
+    // Initialize tracing (skip for quiet mode and the TUI)
     if !suppress_tracing {
         tracing_subscriber::fmt()
             .with_env_filter(
@@ -225,9 +362,10 @@ async fn main() -> Result<()> {
     }
 
     match cli.command {
-        Commands::Tui => {
-            // Don't initialize tracing for TUI (conflicts with terminal)
-            run_tui().await?;
+        Commands::Tui { remote } => {
+            let remote = remote.as_deref().map(ui::remote::RemoteClient::new).transpose()?;
+            let config = config::AppConfig::load()?;
+            run_tui(remote, config.validation.min_confidence).await?;
         }
 
         Commands::Packs { command } => match command {
@@ -261,10 +399,20 @@ async fn main() -> Result<()> {
             run_id,
             correlation_id,
             mock,
+            min_confidence,
             json,
             stream,
             quiet,
+            no_save,
         } => {
+            // The flag (or its environment variable) overrides the config file
+            let validation = match min_confidence {
+                Some(min_confidence) => config::ValidationConfig { min_confidence: Some(min_confidence) },
+                None => config::AppConfig::load()?.validation,
+            };
+            validation.check()?;
+            let min_confidence = validation.min_confidence;
+
             // Generate or use provided run_id
             let run_id = run_id.unwrap_or_else(|| format!("run_{}", uuid::Uuid::new_v4()));
             let correlation_id = correlation_id.unwrap_or_else(|| format!("cor_{}", uuid::Uuid::new_v4()));
@@ -330,9 +478,17 @@ async fn main() -> Result<()> {
 
             // Run convergence loop inline
             let mut engine = Engine::new();
-
-            // Register agents from template (Bridge to domain packs)
-            register_pack_agents(&mut engine, template.as_str(), mock)?;
+            let manifest_seeds = replay::manifest_seeds(&context);
+
+            // Register agents from template (Bridge to domain packs);
+            // LLM responses are recorded for `converge replay`
+            let ledger = Arc::new(ConfidenceLedger::new(min_confidence));
+            let control = Arc::new(JobControl::new());
+            let recorder = Arc::new(CassetteRecorder::new());
+            let mut wiring = AgentWiring::new(mock)
+                .with_ledger(ledger.clone())
+                .with_control(control.clone())
+                .with_cassette(CassetteMode::Record(recorder.clone()));
 
             // Set up streaming callback if requested
             let streaming_handler = if stream {
@@ -343,29 +499,53 @@ async fn main() -> Result<()> {
                     OutputFormat::Human
                 };
                 let handler = Arc::new(StreamingHandler::new(format));
-                engine.set_streaming(handler.clone());
+                wiring = wiring.with_streaming(handler.clone());
                 Some(handler)
             } else {
                 None
             };
+            register_pack_agents(&mut engine, template.as_str(), &wiring)?;
+
+            // Ctrl+C cancels cooperatively at the next cycle boundary;
+            // a second Ctrl+C aborts immediately
+            let signal_control = control.clone();
+            tokio::spawn(async move {
+                if tokio::signal::ctrl_c().await.is_ok() {
+                    signal_control.cancel();
+                    if tokio::signal::ctrl_c().await.is_ok() {
+                        std::process::exit(130);
+                    }
+                }
+            });
 
             if !stream && !quiet {
                 info!("Starting convergence loop...");
             }
 
-            // Run engine - handle errors differently in quiet mode
-            let result = if quiet {
-                match engine.run(context) {
-                    Ok(r) => r,
-                    Err(e) => {
-                        // Exit codes per CLI_CONTRACT.md:
-                        // 1 = halted (invariant violated), 3 = error (system failure)
-                        let exit_code = if e.to_string().contains("invariant") { 1 } else { 3 };
-                        std::process::exit(exit_code);
-                    }
+            // Run engine. Cancelling stops agents early, so an acceptance
+            // invariant may fail after a cancel; that is still a cancellation.
+            let run = engine.run(context);
+            if control.is_cancelled() {
+                let cycles = run.as_ref().map_or_else(|_| wiring.stats.current_cycle(), |r| r.cycles);
+                if let Some(ref handler) = streaming_handler {
+                    handler.emit_cancelled(cycles);
+                } else if !quiet && !json {
+                    warn!(cycles, "Job cancelled");
                 }
-            } else {
-                engine.run(context)?
+                // Exit code 130 = cancelled (128 + SIGINT)
+                std::process::exit(130);
+            }
+
+            // Handle errors differently in quiet mode
+            let result = match run {
+                Ok(r) => r,
+                Err(e) if quiet => {
+                    // Exit codes per CLI_CONTRACT.md:
+                    // 1 = halted (invariant violated), 3 = error (system failure)
+                    let exit_code = if e.to_string().contains("invariant") { 1 } else { 3 };
+                    std::process::exit(exit_code);
+                }
+                Err(e) => return Err(e.into()),
             };
 
             if !stream && !quiet {
@@ -376,6 +556,49 @@ async fn main() -> Result<()> {
                 }
             }
 
+            let facts = fact_outputs(&result.context, &ledger, &wiring.provenance);
+            let output = RunOutput {
+                run_id: run_id.clone(),
+                correlation_id: correlation_id.clone(),
+                timestamp: Utc::now().to_rfc3339(),
+                template: template.clone(),
+                actor: ActorInfo {
+                    actor_type: "system".to_string(),
+                    device_id: device_id.clone(),
+                    cli_version: env!("CARGO_PKG_VERSION").to_string(),
+                },
+                provider: wiring.stats.provider(),
+                result: RunResultOutput {
+                    converged: result.converged,
+                    cycles: result.cycles,
+                    total_facts: facts.len(),
+                },
+                context_hash: Some(ContextHash::of(&facts)),
+                facts,
+                review: ledger.pending_review(),
+                decisions: Vec::new(),
+                agents: wiring.stats.snapshot(),
+                invariants: wiring.stats.invariants(),
+            };
+
+            // Save for `converge diff` and `converge replay`; a failed save
+            // never fails the run
+            if !no_save && !quiet {
+                let store = RunStore::default();
+                match store.save(&output) {
+                    Ok(path) if !stream && !json => info!(path = %path.display(), "Run saved"),
+                    Ok(_) => {}
+                    Err(e) => warn!(error = %e, "Failed to save run"),
+                }
+                let cassette = recorder.cassette(output.provider.clone());
+                let manifest = RunManifest::new(&output, manifest_seeds, mock, min_confidence, &cassette);
+                match store.save_manifest(&manifest, &cassette) {
+                    Ok(path) if !stream && !json => info!(path = %path.display(), "Manifest saved"),
+                    Ok(_) => {}
+                    Err(e) => warn!(error = %e, "Failed to save run manifest"),
+                }
+            }
+
             // Handle output based on mode
             if quiet {
                 // Quiet mode: exit code only
@@ -385,44 +608,9 @@ async fn main() -> Result<()> {
                 std::process::exit(exit_code);
             } else if let Some(handler) = streaming_handler {
                 // Streaming mode: emit final status line
-                handler.emit_final_status(result.converged, result.cycles);
+                handler.emit_final_status(result.converged, result.cycles, output.context_hash.as_ref());
             } else if json {
                 // JSON output (Cross-Platform Contract compliant)
-                let final_facts: usize = ContextKey::iter()
-                    .map(|key| result.context.get(key).len())
-                    .sum();
-
-                let mut facts: Vec<FactOutput> = Vec::new();
-                let mut sequence = 0usize;
-                for key in ContextKey::iter() {
-                    for fact in result.context.get(key) {
-                        sequence += 1;
-                        facts.push(FactOutput {
-                            sequence,
-                            key: format!("{:?}", key),
-                            id: fact.id.clone(),
-                            content: fact.content.clone(),
-                        });
-                    }
-                }
-
-                let output = RunOutput {
-                    run_id: run_id.clone(),
-                    correlation_id: correlation_id.clone(),
-                    timestamp: Utc::now().to_rfc3339(),
-                    actor: ActorInfo {
-                        actor_type: "system".to_string(),
-                        device_id: device_id.clone(),
-                        cli_version: env!("CARGO_PKG_VERSION").to_string(),
-                    },
-                    result: RunResultOutput {
-                        converged: result.converged,
-                        cycles: result.cycles,
-                        total_facts: final_facts,
-                    },
-                    facts,
-                };
-
                 println!("{}", serde_json::to_string_pretty(&output)?);
             } else {
                 // Human-readable output
@@ -445,21 +633,122 @@ async fn main() -> Result<()> {
                     if !facts.is_empty() {
                         println!("[{:?}]", key);
                         for fact in facts {
-                            println!("  {} | {}", fact.id, fact.content);
+                            match ledger.confidence(&fact.id) {
+                                Some(c) => println!("  {} | {} ({:.0}%)", fact.id, fact.content, c * 100.0),
+                                None => println!("  {} | {}", fact.id, fact.content),
+                            }
                         }
                         println!();
                     }
                 }
                 println!("=======================");
+
+                let review = ledger.pending_review();
+                if !review.is_empty() {
+                    println!("\n=== Held for Review ===\n");
+                    for item in &review {
+                        let confidence = item
+                            .confidence
+                            .map_or_else(|| "unscored".to_string(), |c| format!("{:.0}%", c * 100.0));
+                        println!("  [{}] {} by {} ({} < {:.0}%)", item.key, item.id, item.agent, confidence, item.threshold * 100.0);
+                        println!("    {}", item.content);
+                    }
+                    println!("\n=======================");
+                }
+            }
+        }
+
+        Commands::Diff { run_a, run_b, json } => {
+            let store = RunStore::default();
+            let before = store.resolve(&run_a)?;
+            let after = store.resolve(&run_b)?;
+            let diff = diff::RunDiff::between(&before, &after);
+
+            if json {
+                println!("{}", serde_json::to_string_pretty(&diff)?);
+            } else {
+                diff::print_diff(&diff);
+            }
+
+            // Like diff(1): 0 = identical, 1 = differences
+            if !diff.is_identical() {
+                std::process::exit(1);
+            }
+        }
+
+        Commands::Explain { run, json } => {
+            let run = RunStore::default().resolve(&run)?;
+            let explanation = explain::Explanation::of(&run);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&explanation)?);
+            } else {
+                explain::print_explanation(&explanation);
+            }
+        }
+
+        Commands::Export { run, format, output } => {
+            let run = RunStore::default().resolve(&run)?;
+            let report = export::render(&run, format);
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, report)
+                        .map_err(|e| anyhow::anyhow!("Failed to write '{}': {}", path.display(), e))?;
+                    println!("Exported {} to {}", run.run_id, path.display());
+                }
+                None => print!("{}", report),
+            }
+        }
+
+        Commands::Replay { manifest, json } => {
+            let store = RunStore::default();
+            let (manifest, path) = store.resolve_manifest(&manifest)?;
+            let cassette = replay::Cassette::load(&manifest.cassette_path(&path))?;
+            // The recorded output locates the first divergent fact
+            let recorded = store.load_for_manifest(&manifest, &path).ok();
+            let report = replay::replay(&manifest, cassette, recorded.as_ref())?;
+
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                replay::print_report(&report);
+            }
+
+            // 0 = reproducible, 1 = the replayed context differs
+            if !report.reproducible {
+                std::process::exit(1);
             }
         }
 
         Commands::Eval { command } => match command {
-            EvalCommands::Run { eval_id, dir, mock } => {
+            EvalCommands::Run {
+                eval_ids,
+                tags,
+                exclude_tags,
+                suite,
+                dir,
+                mock,
+                jobs,
+                timeout,
+                repeat,
+                reports,
+                save_baseline,
+                compare_baseline,
+                baseline_dir,
+                update_snapshots,
+            } => {
                 let dir_path = std::path::Path::new(&dir);
 
-                // Load fixtures
-                let mut fixtures = evals::load_fixtures_from_dir(dir_path)?;
+                // Load fixtures; any invalid fixture stops the whole run
+                let (mut fixtures, issues) = eval_validate::validate_dir(dir_path);
+                if !issues.is_empty() {
+                    eprintln!("Invalid eval fixtures in '{}':", dir);
+                    for issue in &issues {
+                        eprintln!("  {}", issue);
+                    }
+                    eprintln!("Run `converge eval validate --dir {}` after fixing them.", dir);
+                    std::process::exit(1);
+                }
+                fixtures.sort_by(|a, b| a.eval_id.cmp(&b.eval_id));
 
                 if fixtures.is_empty() {
                     println!("No eval fixtures found in '{}'", dir);
@@ -467,13 +756,20 @@ async fn main() -> Result<()> {
                     return Ok(());
                 }
 
-                // Filter to specific eval if provided
-                if let Some(ref id) = eval_id {
-                    fixtures.retain(|f| f.eval_id == *id);
-                    if fixtures.is_empty() {
-                        println!("Eval '{}' not found in '{}'", id, dir);
-                        return Ok(());
-                    }
+                // Select fixtures by id/glob, tags and suite
+                let filter = eval_filter::EvalFilter {
+                    include: eval_ids,
+                    tags,
+                    exclude_tags,
+                };
+                let suite_filter = match suite {
+                    Some(ref name) => Some(eval_filter::SuiteManifest::load(dir_path)?.suite(name)?.filter()),
+                    None => None,
+                };
+                fixtures.retain(|f| filter.matches(f) && suite_filter.as_ref().is_none_or(|s| s.matches(f)));
+                if fixtures.is_empty() {
+                    println!("No eval fixtures in '{}' match the selection", dir);
+                    return Ok(());
                 }
 
                 // Override mock setting if flag provided
@@ -483,17 +779,78 @@ async fn main() -> Result<()> {
                     }
                 }
 
-                info!(count = fixtures.len(), "Running eval fixtures");
+                info!(count = fixtures.len(), jobs, repeat, "Running eval fixtures");
+
+                // Run evals, each fixture `repeat` times
+                let runs: Vec<evals::EvalFixture> = fixtures
+                    .iter()
+                    .flat_map(|f| std::iter::repeat_n(f.clone(), repeat.max(1)))
+                    .collect();
+                let timeout = std::time::Duration::from_secs(timeout);
+                let results = evals::run_evals(&runs, jobs, timeout, update_snapshots).await;
+                if update_snapshots {
+                    let written = eval_snapshot::update_all(&fixtures, &results)?;
+                    info!(count = written, "Eval snapshots updated");
+                }
+                let stats = eval_stats::summarize(&fixtures, &results);
 
-                // Run evals
-                let results = evals::run_evals(&fixtures);
+                // Print results; colour only on a terminal
+                let color = io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+                evals::print_results(&results, color);
+                if repeat > 1 {
+                    eval_stats::print_stats(&stats, color);
+                }
+                let report_stats = if repeat > 1 { stats.as_slice() } else { &[] };
+                for report in &reports {
+                    report.write(&results, report_stats)?;
+                    info!(path = %report.path.display(), "Eval report written");
+                }
 
-                // Print results
-                evals::print_results(&results);
+                // Compare before saving so a run can check and then re-baseline
+                let baselines = eval_baseline::BaselineStore::new(&baseline_dir);
+                let mut regressed = false;
+                if compare_baseline {
+                    let comparisons = eval_baseline::compare_all(&baselines, &fixtures, &results)?;
+                    eval_baseline::print_comparisons(&comparisons, color);
+                    regressed = comparisons.iter().any(|c| c.regressed());
+                }
+                if save_baseline {
+                    let saved = eval_baseline::save_all(&baselines, &fixtures, &results)?;
+                    info!(count = saved, dir = %baselines.dir().display(), "Eval baselines saved");
+                }
 
-                // Exit with error code if any failed
-                let all_passed = results.iter().all(|r| r.passed);
-                if !all_passed {
+                // Exit with error code if any fixture missed its pass rate
+                // (every run must pass unless the fixture sets min_pass_rate)
+                // or regressed against its baseline
+                if regressed || !stats.iter().all(|s| s.passed) {
+                    std::process::exit(1);
+                }
+            }
+            EvalCommands::Create {
+                from_run,
+                eval_id,
+                dir,
+                with_cassette,
+                force,
+            } => {
+                let path = eval_create::create(
+                    &RunStore::default(),
+                    &from_run,
+                    std::path::Path::new(&dir),
+                    eval_id.as_deref(),
+                    with_cassette,
+                    force,
+                )?;
+                println!("Created eval fixture {}", path.display());
+                println!("Review its expectations before committing it.");
+            }
+            EvalCommands::Validate { dir } => {
+                let (fixtures, issues) = eval_validate::validate_dir(std::path::Path::new(&dir));
+                if issues.is_empty() {
+                    println!("{} eval fixtures in '{}' are valid", fixtures.len(), dir);
+                } else {
+                    println!("{} problems in eval fixtures in '{}':", issues.len(), dir);
+                    eval_validate::print_issues(&issues);
                     std::process::exit(1);
                 }
             }
@@ -512,6 +869,18 @@ async fn main() -> Result<()> {
                     println!("    Pack: {}", fixture.pack);
                     println!("    Seeds: {}", fixture.seeds.len());
                     println!("    Mock LLM: {}", fixture.use_mock_llm);
+                    if !fixture.tags.is_empty() {
+                        println!("    Tags: {}", fixture.tags.join(", "));
+                    }
+                    println!();
+                }
+
+                let manifest = eval_filter::SuiteManifest::load(dir_path)?;
+                if !manifest.suites.is_empty() {
+                    println!("Suites:\n");
+                    for (name, suite) in &manifest.suites {
+                        println!("  {} - {}", name, suite.description);
+                    }
                     println!();
                 }
             }
@@ -524,11 +893,11 @@ async fn main() -> Result<()> {
 /// Cleanup terminal on exit or panic
 fn cleanup_terminal() {
     let _ = disable_raw_mode();
-    let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture);
+    let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture, DisableBracketedPaste);
 }
 
 /// Run the TUI application with proper terminal lifecycle management
-async fn run_tui() -> Result<()> {
+async fn run_tui(remote: Option<ui::remote::RemoteClient>, min_confidence: Option<f64>) -> Result<()> {
     // Set up panic hook to restore terminal
     let original_hook = panic::take_hook();
     panic::set_hook(Box::new(move |panic_info| {
@@ -539,12 +908,16 @@ async fn run_tui() -> Result<()> {
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture, EnableBracketedPaste)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Create app and run
-    let app = ui::App::new();
+    let mut app = ui::App::new();
+    app.min_confidence = min_confidence;
+    if let Some(client) = remote {
+        app.connect_remote(client);
+    }
     let res = ui::run_app(&mut terminal, app).await;
 
     // Restore terminal
@@ -557,82 +930,3 @@ async fn run_tui() -> Result<()> {
 
     Ok(())
 }
-
-/// Creates an LLM provider from environment variables.
-///
-/// Tries providers in order of preference:
-/// 1. Anthropic (ANTHROPIC_API_KEY) - Claude models
-/// 2. OpenAI (OPENAI_API_KEY) - GPT models
-/// 3. Falls back to MockInsightProvider if no API keys are set
-///
-/// Note: This function uses `block_in_place` because the underlying providers
-/// use blocking HTTP clients that can't be created directly in async context.
-fn create_llm_provider() -> Arc<dyn LlmProvider> {
-    // Use block_in_place to safely create blocking providers from async context
-    tokio::task::block_in_place(|| {
-        // Try Anthropic first (Claude is excellent for strategic analysis)
-        if let Ok(provider) = AnthropicProvider::from_env("claude-sonnet-4-20250514") {
-            info!(provider = "anthropic", model = "claude-sonnet-4-20250514", "Using Anthropic Claude for LLM insights");
-            return Arc::new(provider) as Arc<dyn LlmProvider>;
-        }
-
-        // Try OpenAI second
-        if let Ok(provider) = OpenAiProvider::from_env("gpt-4o") {
-            info!(provider = "openai", model = "gpt-4o", "Using OpenAI GPT for LLM insights");
-            return Arc::new(provider) as Arc<dyn LlmProvider>;
-        }
-
-        // Fall back to mock provider
-        warn!("No LLM API keys found (ANTHROPIC_API_KEY or OPENAI_API_KEY). Using mock provider.");
-        info!("Set ANTHROPIC_API_KEY or OPENAI_API_KEY in .env for real LLM insights");
-        Arc::new(MockInsightProvider::default_insights()) as Arc<dyn LlmProvider>
-    })
-}
-
-/// Register agents and invariants for a specific domain pack.
-///
-/// This acts as the bridge between the distribution layer and the domain packs.
-///
-/// # Arguments
-/// * `engine` - The convergence engine to register agents with
-/// * `pack_name` - Name of the domain pack (e.g., "growth-strategy")
-/// * `use_mock` - If true, use mock LLM provider for deterministic output
-fn register_pack_agents(engine: &mut Engine, pack_name: &str, use_mock: bool) -> Result<()> {
-    match pack_name {
-        "growth-strategy" => {
-            info!(pack = %pack_name, mock = use_mock, "Registering growth-strategy agents and invariants");
-
-            // Register deterministic agents
-            engine.register(MarketSignalAgent);
-            engine.register(CompetitorAgent);
-            engine.register(StrategyAgent);
-            engine.register(EvaluationAgent);
-
-            // Create LLM provider based on mock flag
-            let llm_provider: Arc<dyn LlmProvider> = if use_mock {
-                info!("Using mock LLM provider for deterministic output");
-                Arc::new(MockInsightProvider::default_insights())
-            } else {
-                create_llm_provider()
-            };
-
-            // Register LLM-powered agents
-            engine.register(StrategicInsightAgent::new(llm_provider.clone()));
-            info!("Registered LLM-powered StrategicInsightAgent");
-
-            engine.register(RiskAssessmentAgent::new(llm_provider));
-            info!("Registered LLM-powered RiskAssessmentAgent");
-
-            // Register Invariants
-            engine.register_invariant(BrandSafetyInvariant::default());
-            engine.register_invariant(RequireMultipleStrategies);
-            engine.register_invariant(RequireStrategyEvaluations);
-            engine.register_invariant(RequireEvaluationRationale);
-        }
-        _ => {
-            warn!(pack = %pack_name, "No specific agent registration for pack");
-        }
-    }
-    Ok(())
-}
-
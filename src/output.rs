@@ -0,0 +1,199 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! JSON output of `converge run` (Cross-Platform Contract).
+//!
+//! The same document is printed by `converge run --json`, saved to the run
+//! store and read back by `converge diff`, so it is both `Serialize` and
+//! `Deserialize`. Fields added after the first contract version default when
+//! missing so older saved outputs still load.
+
+use serde::{Deserialize, Serialize};
+
+use converge_core::{Context, ContextKey};
+use strum::IntoEnumIterator;
+
+use crate::confidence::{ConfidenceLedger, ReviewItem, ReviewRecord};
+use crate::hashing::ContextHash;
+use crate::provenance::{FactProvenance, ProvenanceLog};
+use crate::telemetry::AgentStat;
+
+/// JSON output format for run results (Cross-Platform Contract compliant)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunOutput {
+    pub run_id: String,
+    pub correlation_id: String,
+    pub timestamp: String,
+    /// Template (domain pack) the run used
+    #[serde(default)]
+    pub template: String,
+    pub actor: ActorInfo,
+    /// LLM provider the run's agents used (absent if none was created)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<ProviderInfo>,
+    pub result: RunResultOutput,
+    pub facts: Vec<FactOutput>,
+    /// Canonical hash of the final context, overall and per key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_hash: Option<ContextHash>,
+    /// LLM items held back for review (below `validation.min_confidence`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub review: Vec<ReviewItem>,
+    /// Reviewer decisions on held-back items (TUI jobs)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub decisions: Vec<ReviewRecord>,
+    /// Per-agent statistics, including declined `accepts` checks
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub agents: Vec<AgentStat>,
+    /// Invariants registered for the run
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub invariants: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActorInfo {
+    #[serde(rename = "type")]
+    pub actor_type: String,
+    pub device_id: String,
+    pub cli_version: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProviderInfo {
+    pub name: String,
+    pub model: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunResultOutput {
+    pub converged: bool,
+    pub cycles: u32,
+    pub total_facts: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactOutput {
+    pub sequence: usize,
+    pub key: String,
+    pub id: String,
+    pub content: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+    /// Producing agent, cycle and source facts (absent for seeds)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<FactProvenance>,
+}
+
+impl FactOutput {
+    /// Whether this fact refers to the fact `id`: its own id ends in
+    /// `:<id>` (as in `eval:strategy:1`), or its content mentions `id` as a
+    /// whole word. `strategy:10` does not refer to `strategy:1`.
+    pub fn refers_to(&self, id: &str) -> bool {
+        if id.is_empty() || self.id == id {
+            return false;
+        }
+        self.id.strip_suffix(id).is_some_and(|rest| rest.ends_with(':')) || mentions(&self.content, id)
+    }
+}
+
+/// Whether `text` contains `id` not directly preceded or followed by
+/// another id character.
+fn mentions(text: &str, id: &str) -> bool {
+    let is_id_char = |c: char| c.is_alphanumeric() || matches!(c, '_' | '-' | ':');
+    text.match_indices(id).any(|(start, _)| {
+        let before = text[..start].chars().next_back();
+        let after = text[start + id.len()..].chars().next();
+        !before.is_some_and(is_id_char) && !after.is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | '-'))
+    })
+}
+
+#[cfg(test)]
+impl FactOutput {
+    /// A fact without confidence or provenance, for tests.
+    pub fn test(key: &str, id: &str, content: &str) -> Self {
+        Self {
+            sequence: 0,
+            key: key.to_string(),
+            id: id.to_string(),
+            content: content.to_string(),
+            confidence: None,
+            provenance: None,
+        }
+    }
+}
+
+#[cfg(test)]
+impl RunOutput {
+    /// A converged three-cycle `growth-strategy` run of `facts`, numbered
+    /// from 1, for tests.
+    pub fn test(facts: Vec<FactOutput>) -> Self {
+        let facts: Vec<FactOutput> = facts
+            .into_iter()
+            .enumerate()
+            .map(|(i, fact)| FactOutput { sequence: i + 1, ..fact })
+            .collect();
+        Self {
+            run_id: "run_1".to_string(),
+            correlation_id: "cor_1".to_string(),
+            timestamp: "2025-01-01T00:00:00Z".to_string(),
+            template: "growth-strategy".to_string(),
+            actor: ActorInfo {
+                actor_type: "system".to_string(),
+                device_id: "cli:host:user".to_string(),
+                cli_version: "0.1.0".to_string(),
+            },
+            provider: None,
+            result: RunResultOutput {
+                converged: true,
+                cycles: 3,
+                total_facts: facts.len(),
+            },
+            facts,
+            context_hash: None,
+            review: Vec::new(),
+            decisions: Vec::new(),
+            agents: Vec::new(),
+            invariants: Vec::new(),
+        }
+    }
+}
+
+/// Flattens a context into `FactOutput`s, in `ContextKey` order.
+pub fn fact_outputs(context: &Context, ledger: &ConfidenceLedger, provenance: &ProvenanceLog) -> Vec<FactOutput> {
+    let mut facts = Vec::new();
+    let mut sequence = 0usize;
+    for key in ContextKey::iter() {
+        for fact in context.get(key) {
+            sequence += 1;
+            facts.push(FactOutput {
+                sequence,
+                key: format!("{:?}", key),
+                id: fact.id.clone(),
+                content: fact.content.clone(),
+                confidence: ledger.confidence(&fact.id),
+                provenance: provenance.get(&fact.id),
+            });
+        }
+    }
+    facts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fact(id: &str, content: &str) -> FactOutput {
+        FactOutput::test("Evaluations", id, content)
+    }
+
+    #[test]
+    fn refers_to_matches_whole_ids_only() {
+        assert!(fact("eval:strategy:1", "").refers_to("strategy:1"));
+        assert!(!fact("eval:strategy:10", "").refers_to("strategy:1"));
+        assert!(fact("eval:a", "Scores strategy:1: strong fit").refers_to("strategy:1"));
+        assert!(!fact("eval:a", "Scores strategy:10 and xstrategy:1").refers_to("strategy:1"));
+        assert!(!fact("strategy:1", "").refers_to("strategy:1"));
+    }
+}
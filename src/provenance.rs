@@ -0,0 +1,168 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! Fact provenance: which agent produced a fact, in which cycle, and from
+//! which facts.
+//!
+//! Every registered agent is wrapped in [`Traced`], which records each fact
+//! the agent emits into a shared [`ProvenanceLog`]. A fact is derived from
+//! the facts under the agent's declared dependency keys at the time it
+//! executed. Seeds have no provenance; they are the roots of every lineage.
+//!
+//! The log tracks the current cycle through its `StreamingCallback`
+//! implementation, like `AgentStats`.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use converge_core::{Agent, AgentEffect, Context, ContextKey, Fact, StreamingCallback};
+
+/// Lineage of one fact.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FactProvenance {
+    /// Agent that emitted the fact.
+    pub agent: String,
+    /// Cycle the agent executed in.
+    pub cycle: u32,
+    /// Ids of the facts the agent could see under its dependency keys.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub derived_from: Vec<String>,
+}
+
+/// Provenance of every fact emitted during one run, by fact id.
+#[derive(Debug, Default)]
+pub struct ProvenanceLog {
+    current_cycle: AtomicU32,
+    facts: Mutex<HashMap<String, FactProvenance>>,
+}
+
+impl ProvenanceLog {
+    /// Creates an empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Provenance of the fact with `id`, if an agent emitted it.
+    pub fn get(&self, id: &str) -> Option<FactProvenance> {
+        self.facts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(id)
+            .cloned()
+    }
+
+    /// Records `facts` as emitted by `agent` from what it saw in `ctx`.
+    fn record(&self, agent: &str, dependencies: &[ContextKey], ctx: &Context, facts: &[Fact]) {
+        if facts.is_empty() {
+            return;
+        }
+        let derived_from: Vec<String> = dependencies
+            .iter()
+            .flat_map(|key| ctx.get(*key))
+            .map(|fact| fact.id.clone())
+            .collect();
+        let cycle = self.current_cycle.load(Ordering::SeqCst);
+        let mut log = self.facts.lock().unwrap_or_else(PoisonError::into_inner);
+        for fact in facts {
+            log.insert(
+                fact.id.clone(),
+                FactProvenance {
+                    agent: agent.to_string(),
+                    cycle,
+                    derived_from: derived_from.clone(),
+                },
+            );
+        }
+    }
+}
+
+impl StreamingCallback for ProvenanceLog {
+    fn on_cycle_start(&self, cycle: u32) {
+        self.current_cycle.store(cycle, Ordering::SeqCst);
+    }
+
+    fn on_fact(&self, _cycle: u32, _fact: &Fact) {}
+
+    fn on_cycle_end(&self, _cycle: u32, _facts_added: usize) {}
+}
+
+/// Agent wrapper that records the provenance of emitted facts.
+pub struct Traced<A> {
+    inner: A,
+    log: Arc<ProvenanceLog>,
+}
+
+impl<A: Agent> Traced<A> {
+    /// Wraps `inner`, recording into `log`.
+    pub fn new(inner: A, log: Arc<ProvenanceLog>) -> Self {
+        Self { inner, log }
+    }
+}
+
+impl<A: Agent> Agent for Traced<A> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn dependencies(&self) -> &[ContextKey] {
+        self.inner.dependencies()
+    }
+
+    fn accepts(&self, ctx: &Context) -> bool {
+        self.inner.accepts(ctx)
+    }
+
+    fn execute(&self, ctx: &Context) -> AgentEffect {
+        let effect = self.inner.execute(ctx);
+        self.log
+            .record(self.inner.name(), self.inner.dependencies(), ctx, &effect.facts);
+        effect
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Summarizer;
+
+    impl Agent for Summarizer {
+        fn name(&self) -> &str {
+            "Summarizer"
+        }
+
+        fn dependencies(&self) -> &[ContextKey] {
+            &[ContextKey::Seeds]
+        }
+
+        fn accepts(&self, _ctx: &Context) -> bool {
+            true
+        }
+
+        fn execute(&self, _ctx: &Context) -> AgentEffect {
+            AgentEffect::with_facts(vec![Fact::new(ContextKey::Signals, "signal:1", "summary")])
+        }
+    }
+
+    #[test]
+    fn traced_agent_records_agent_cycle_and_dependency_facts() {
+        let log = Arc::new(ProvenanceLog::new());
+        let agent = Traced::new(Summarizer, log.clone());
+
+        let mut ctx = Context::new();
+        ctx.add_fact(Fact::new(ContextKey::Seeds, "seed:a", "a")).unwrap();
+        ctx.add_fact(Fact::new(ContextKey::Seeds, "seed:b", "b")).unwrap();
+        log.on_cycle_start(2);
+        agent.execute(&ctx);
+
+        let provenance = log.get("signal:1").expect("provenance recorded");
+        assert_eq!(provenance.agent, "Summarizer");
+        assert_eq!(provenance.cycle, 2);
+        assert_eq!(provenance.derived_from, ["seed:a", "seed:b"]);
+        assert!(log.get("seed:a").is_none());
+    }
+}
@@ -0,0 +1,524 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! Run manifests, LLM cassettes and deterministic replay.
+//!
+//! Every `converge run` records the LLM responses its agents receive into a
+//! [`Cassette`] and saves a [`RunManifest`] next to the run output:
+//!
+//! ```text
+//! .converge/runs/<run_id>.json            run output
+//! .converge/runs/<run_id>.manifest.json   what the run depended on
+//! .converge/runs/<run_id>.cassette.json   recorded LLM responses
+//! ```
+//!
+//! `converge replay <manifest>` re-executes the run with the recorded
+//! responses instead of a live provider and checks that the resulting
+//! context hash matches the manifest. Responses are looked up by agent and
+//! prompt hash, in recording order, so a changed prompt shows up as a
+//! cassette miss rather than a silently different answer.
+
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use converge_core::llm::{FinishReason, LlmError, LlmProvider, LlmRequest, LlmResponse, TokenUsage};
+use converge_core::{Context, ContextKey, Engine, Fact};
+
+use crate::confidence::ConfidenceLedger;
+use crate::hashing::{context_hash, hash_fields, sha256_hex};
+use crate::output::{fact_outputs, FactOutput, ProviderInfo, RunOutput};
+use crate::packs;
+use crate::wiring::{register_pack_agents, AgentWiring};
+
+/// Version of the manifest format.
+pub const MANIFEST_VERSION: u32 = 1;
+
+/// A seed fact as recorded in a manifest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestSeed {
+    pub id: String,
+    pub content: String,
+}
+
+/// Everything a run depended on, enough to re-execute it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunManifest {
+    pub manifest_version: u32,
+    pub run_id: String,
+    pub created_at: String,
+    /// Version of the converge binary that ran it
+    pub binary_version: String,
+    /// Domain packs compiled into the binary
+    pub enabled_packs: Vec<String>,
+    pub template: String,
+    /// Version of the pack providing the template
+    pub template_version: String,
+    pub seeds: Vec<ManifestSeed>,
+    pub seeds_hash: String,
+    /// The run used the mock LLM provider
+    pub mock: bool,
+    /// Review threshold (`validation.min_confidence`) the run's ledger used
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_confidence: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<ProviderInfo>,
+    /// Hashes of every prompt sent to the LLM, in call order
+    pub prompt_hashes: Vec<String>,
+    /// Cassette file, relative to the manifest
+    pub cassette: String,
+    /// Hash of the final context (see `hashing::context_hash`)
+    pub context_hash: String,
+}
+
+impl RunManifest {
+    /// Describes a completed run.
+    pub fn new(
+        output: &RunOutput,
+        seeds: Vec<ManifestSeed>,
+        mock: bool,
+        min_confidence: Option<f64>,
+        cassette: &Cassette,
+    ) -> Self {
+        let template_version = packs::available_packs()
+            .into_iter()
+            .map(|name| packs::pack_info(&name))
+            .find(|info| info.name == output.template || info.templates.contains(&output.template))
+            .map_or_else(|| "unknown".to_string(), |info| info.version);
+        Self {
+            manifest_version: MANIFEST_VERSION,
+            run_id: output.run_id.clone(),
+            created_at: output.timestamp.clone(),
+            binary_version: env!("CARGO_PKG_VERSION").to_string(),
+            enabled_packs: packs::available_packs(),
+            template: output.template.clone(),
+            template_version,
+            seeds_hash: seeds_hash(&seeds),
+            seeds,
+            mock,
+            min_confidence,
+            provider: output.provider.clone(),
+            prompt_hashes: cassette.interactions.iter().map(|i| i.prompt_hash.clone()).collect(),
+            cassette: cassette_file_name(&output.run_id),
+            context_hash: context_hash(&output.facts),
+        }
+    }
+
+    /// Loads a manifest file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read '{}'", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("'{}' is not a converge run manifest", path.display()))
+    }
+
+    /// Path of the manifest's cassette, given where the manifest was loaded from.
+    pub fn cassette_path(&self, manifest_path: &Path) -> PathBuf {
+        manifest_path.parent().unwrap_or(Path::new(".")).join(&self.cassette)
+    }
+}
+
+/// Seeds of a context, as recorded in a manifest.
+pub fn manifest_seeds(context: &Context) -> Vec<ManifestSeed> {
+    context
+        .get(ContextKey::Seeds)
+        .iter()
+        .map(|fact| ManifestSeed {
+            id: fact.id.clone(),
+            content: fact.content.clone(),
+        })
+        .collect()
+}
+
+fn seeds_hash(seeds: &[ManifestSeed]) -> String {
+    sha256_hex(serde_json::to_vec(seeds).unwrap_or_default())
+}
+
+/// File name of a run's cassette.
+pub fn cassette_file_name(run_id: &str) -> String {
+    format!("{}.cassette.json", run_id)
+}
+
+/// Hash identifying a prompt (system prompt and user prompt).
+fn prompt_hash(request: &LlmRequest) -> String {
+    let system = request.system.as_deref().unwrap_or_default();
+    hash_fields([system, request.prompt.as_str()])
+}
+
+/// One recorded LLM call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    pub agent: String,
+    pub prompt_hash: String,
+    /// Response content (empty if the call failed)
+    #[serde(default)]
+    pub content: String,
+    #[serde(default)]
+    pub model: String,
+    /// Error message if the call failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Recorded LLM calls of one run.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Cassette {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<ProviderInfo>,
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Loads a cassette file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read cassette '{}'", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("'{}' is not a converge cassette", path.display()))
+    }
+}
+
+/// Records or replays LLM calls; threaded into agents by the wiring.
+#[derive(Clone)]
+pub enum CassetteMode {
+    Record(Arc<CassetteRecorder>),
+    Replay(Arc<CassettePlayer>),
+}
+
+/// Collects the LLM calls of a run.
+#[derive(Debug, Default)]
+pub struct CassetteRecorder {
+    interactions: Mutex<Vec<Interaction>>,
+}
+
+impl CassetteRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The recorded calls, attributed to `provider`.
+    pub fn cassette(&self, provider: Option<ProviderInfo>) -> Cassette {
+        Cassette {
+            provider,
+            interactions: self.interactions.lock().unwrap_or_else(PoisonError::into_inner).clone(),
+        }
+    }
+
+    /// Wraps `inner` so calls made for `agent` are recorded.
+    pub fn provider(self: &Arc<Self>, inner: Arc<dyn LlmProvider>, agent: &str) -> Recording {
+        Recording {
+            inner,
+            agent: agent.to_string(),
+            recorder: self.clone(),
+        }
+    }
+}
+
+/// LLM provider that records every call into a [`CassetteRecorder`].
+pub struct Recording {
+    inner: Arc<dyn LlmProvider>,
+    agent: String,
+    recorder: Arc<CassetteRecorder>,
+}
+
+impl LlmProvider for Recording {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn model(&self) -> &str {
+        self.inner.model()
+    }
+
+    fn complete(&self, request: &LlmRequest) -> Result<LlmResponse, LlmError> {
+        let result = self.inner.complete(request);
+        let (content, model, error) = match result {
+            Ok(ref response) => (response.content.clone(), response.model.clone(), None),
+            Err(ref e) => (String::new(), String::new(), Some(e.to_string())),
+        };
+        self.recorder
+            .interactions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(Interaction {
+                agent: self.agent.clone(),
+                prompt_hash: prompt_hash(request),
+                content,
+                model,
+                error,
+            });
+        result
+    }
+}
+
+/// Serves recorded responses in place of a live provider.
+#[derive(Debug)]
+pub struct CassettePlayer {
+    cassette: Cassette,
+    /// Next interaction to serve per (agent, prompt hash)
+    served: Mutex<HashMap<(String, String), usize>>,
+    /// Calls with no recorded response, as "agent: prompt hash"
+    misses: Mutex<Vec<String>>,
+}
+
+impl CassettePlayer {
+    pub fn new(cassette: Cassette) -> Self {
+        Self {
+            cassette,
+            served: Mutex::new(HashMap::new()),
+            misses: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// The provider the cassette was recorded with.
+    pub fn recorded_provider(&self) -> Option<&ProviderInfo> {
+        self.cassette.provider.as_ref()
+    }
+
+    /// Calls that found no recorded response.
+    pub fn misses(&self) -> Vec<String> {
+        self.misses.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// A provider replaying the calls recorded for `agent`.
+    pub fn provider(self: &Arc<Self>, agent: &str) -> Replaying {
+        Replaying {
+            agent: agent.to_string(),
+            player: self.clone(),
+        }
+    }
+
+    fn next(&self, agent: &str, prompt_hash: &str) -> Option<&Interaction> {
+        let mut served = self.served.lock().unwrap_or_else(PoisonError::into_inner);
+        let index = served.entry((agent.to_string(), prompt_hash.to_string())).or_default();
+        let interaction = self
+            .cassette
+            .interactions
+            .iter()
+            .filter(|i| i.agent == agent && i.prompt_hash == prompt_hash)
+            .nth(*index)?;
+        *index += 1;
+        Some(interaction)
+    }
+}
+
+/// LLM provider answering from a [`CassettePlayer`].
+pub struct Replaying {
+    agent: String,
+    player: Arc<CassettePlayer>,
+}
+
+impl LlmProvider for Replaying {
+    fn name(&self) -> &str {
+        self.player.recorded_provider().map_or("cassette", |p| p.name.as_str())
+    }
+
+    fn model(&self) -> &str {
+        self.player.recorded_provider().map_or("cassette", |p| p.model.as_str())
+    }
+
+    fn complete(&self, request: &LlmRequest) -> Result<LlmResponse, LlmError> {
+        let hash = prompt_hash(request);
+        let Some(interaction) = self.player.next(&self.agent, &hash) else {
+            self.player
+                .misses
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(format!("{}: {}", self.agent, hash));
+            return Err(LlmError::provider(format!(
+                "No recorded response for {} (prompt {})",
+                self.agent, hash
+            )));
+        };
+        if let Some(ref error) = interaction.error {
+            return Err(LlmError::provider(error.clone()));
+        }
+        // Token usage is not recorded; replayed responses report none
+        Ok(LlmResponse {
+            content: interaction.content.clone(),
+            model: interaction.model.clone(),
+            usage: TokenUsage {
+                prompt_tokens: 0,
+                completion_tokens: 0,
+                total_tokens: 0,
+            },
+            finish_reason: FinishReason::Stop,
+        })
+    }
+}
+
+/// First position where a replayed context differs from the recorded one.
+#[derive(Debug, Clone, Serialize)]
+pub struct Divergence {
+    /// 1-based position in `ContextKey` order
+    pub position: usize,
+    pub expected: Option<FactOutput>,
+    pub actual: Option<FactOutput>,
+}
+
+/// Finds the first fact that differs (by key, id or content).
+pub fn first_divergence(expected: &[FactOutput], actual: &[FactOutput]) -> Option<Divergence> {
+    let same = |a: &FactOutput, b: &FactOutput| a.key == b.key && a.id == b.id && a.content == b.content;
+    (0..expected.len().max(actual.len()))
+        .find(|&i| match (expected.get(i), actual.get(i)) {
+            (Some(a), Some(b)) => !same(a, b),
+            _ => true,
+        })
+        .map(|i| Divergence {
+            position: i + 1,
+            expected: expected.get(i).cloned(),
+            actual: actual.get(i).cloned(),
+        })
+}
+
+/// Outcome of replaying a manifest.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplayReport {
+    pub run_id: String,
+    pub reproducible: bool,
+    pub expected_hash: String,
+    pub actual_hash: String,
+    pub converged: bool,
+    pub cycles: u32,
+    /// The manifest was written by a different binary version
+    pub binary_version_changed: bool,
+    /// LLM calls the cassette had no response for
+    pub cassette_misses: Vec<String>,
+    /// First differing fact, if the recorded run output is available
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub divergence: Option<Divergence>,
+}
+
+/// Re-executes a manifest's run with its recorded LLM responses.
+///
+/// `recorded` is the original run output, used to locate the first
+/// divergent fact when the hashes differ.
+pub fn replay(manifest: &RunManifest, cassette: Cassette, recorded: Option<&RunOutput>) -> Result<ReplayReport> {
+    if seeds_hash(&manifest.seeds) != manifest.seeds_hash {
+        anyhow::bail!("Manifest seeds do not match their recorded hash; the manifest was modified");
+    }
+
+    let mut context = Context::new();
+    for seed in &manifest.seeds {
+        context
+            .add_fact(Fact::new(ContextKey::Seeds, &seed.id, &seed.content))
+            .map_err(|e| anyhow::anyhow!("Failed to add seed fact: {}", e))?;
+    }
+
+    let player = Arc::new(CassettePlayer::new(cassette));
+    let wiring = AgentWiring::new(manifest.mock)
+        .with_ledger(Arc::new(ConfidenceLedger::new(manifest.min_confidence)))
+        .with_cassette(CassetteMode::Replay(player.clone()));
+    let mut engine = Engine::new();
+    register_pack_agents(&mut engine, &manifest.template, &wiring)?;
+    let result = engine
+        .run(context)
+        .map_err(|e| anyhow::anyhow!("Replay failed: {}", e))?;
+
+    let facts = fact_outputs(&result.context, &wiring.ledger, &wiring.provenance);
+    let actual_hash = context_hash(&facts);
+    let reproducible = actual_hash == manifest.context_hash;
+    Ok(ReplayReport {
+        run_id: manifest.run_id.clone(),
+        reproducible,
+        expected_hash: manifest.context_hash.clone(),
+        actual_hash,
+        converged: result.converged,
+        cycles: result.cycles,
+        binary_version_changed: manifest.binary_version != env!("CARGO_PKG_VERSION"),
+        cassette_misses: player.misses(),
+        divergence: if reproducible {
+            None
+        } else {
+            recorded.and_then(|output| first_divergence(&output.facts, &facts))
+        },
+    })
+}
+
+/// Prints a replay report for humans.
+pub fn print_report(report: &ReplayReport) {
+    println!("\n=== Replay {} ===", report.run_id);
+    println!("Expected context hash: {}", report.expected_hash);
+    println!("Actual context hash:   {}", report.actual_hash);
+    println!("Converged: {} in {} cycles", report.converged, report.cycles);
+    if report.binary_version_changed {
+        println!("Note: the run was recorded by a different converge version");
+    }
+    if !report.cassette_misses.is_empty() {
+        println!("\nLLM calls with no recorded response (prompt changed?):");
+        for miss in &report.cassette_misses {
+            println!("  {}", miss);
+        }
+    }
+
+    if report.reproducible {
+        println!("\nReproducible: the replayed context matches the recorded run");
+        return;
+    }
+    println!("\nNOT reproducible: the replayed context differs");
+    match report.divergence {
+        Some(ref d) => {
+            println!("First divergent fact (position {}):", d.position);
+            let describe = |fact: &Option<FactOutput>| match fact {
+                Some(f) => format!("[{}] {} | {}", f.key, f.id, f.content),
+                None => "(none)".to_string(),
+            };
+            println!("  recorded: {}", describe(&d.expected));
+            println!("  replayed: {}", describe(&d.actual));
+        }
+        None => println!("(recorded run output not found; cannot locate the first divergent fact)"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn player_serves_recorded_responses_in_order_and_counts_misses() {
+        let request = LlmRequest::new("Find insights".to_string()).with_system("Be brief".to_string());
+        let interaction = |content: &str| Interaction {
+            agent: "StrategicInsightAgent".to_string(),
+            prompt_hash: prompt_hash(&request),
+            content: content.to_string(),
+            model: "m".to_string(),
+            error: None,
+        };
+        let player = Arc::new(CassettePlayer::new(Cassette {
+            provider: None,
+            interactions: vec![interaction("first"), interaction("second")],
+        }));
+        let insight = player.provider("StrategicInsightAgent");
+
+        assert_eq!(insight.complete(&request).unwrap().content, "first");
+        assert_eq!(insight.complete(&request).unwrap().content, "second");
+        assert!(player.misses().is_empty());
+
+        let error = insight.complete(&request).unwrap_err();
+        assert!(error.to_string().contains("No recorded response for StrategicInsightAgent"), "{}", error);
+        assert!(player.provider("RiskAssessmentAgent").complete(&request).is_err());
+        assert_eq!(player.misses(), [
+            format!("StrategicInsightAgent: {}", prompt_hash(&request)),
+            format!("RiskAssessmentAgent: {}", prompt_hash(&request)),
+        ]);
+    }
+
+    #[test]
+    fn first_divergence_finds_changed_and_missing_facts() {
+        let recorded = vec![FactOutput::test("Seeds", "seed:a", "a"), FactOutput::test("Signals", "signal:1", "x")];
+        assert!(first_divergence(&recorded, &recorded).is_none());
+
+        let changed = vec![FactOutput::test("Seeds", "seed:a", "a"), FactOutput::test("Signals", "signal:1", "y")];
+        let divergence = first_divergence(&recorded, &changed).unwrap();
+        assert_eq!(divergence.position, 2);
+        assert_eq!(divergence.actual.unwrap().content, "y");
+
+        let divergence = first_divergence(&recorded, &recorded[..1]).unwrap();
+        assert_eq!(divergence.position, 2);
+        assert!(divergence.actual.is_none());
+    }
+}
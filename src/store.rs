@@ -0,0 +1,152 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! Local store of run outputs.
+//!
+//! Every `converge run` saves its [`RunOutput`] as
+//! `.converge/runs/<run_id>.json` so runs can be compared later with
+//! `converge diff` without having redirected `--json` output to a file.
+//! The run's manifest and LLM cassette are saved alongside it for
+//! `converge replay`.
+
+use anyhow::{Context as _, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::output::RunOutput;
+use crate::replay::{cassette_file_name, Cassette, RunManifest};
+
+/// Default store location, relative to the working directory.
+pub const DEFAULT_RUNS_DIR: &str = ".converge/runs";
+
+/// Directory of saved run outputs, one JSON file per run id.
+#[derive(Debug, Clone)]
+pub struct RunStore {
+    dir: PathBuf,
+}
+
+impl Default for RunStore {
+    fn default() -> Self {
+        Self::new(DEFAULT_RUNS_DIR)
+    }
+}
+
+impl RunStore {
+    /// Creates a store rooted at `dir` (created on first save).
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path_for(&self, run_id: &str) -> Result<PathBuf> {
+        check_run_id(run_id)?;
+        Ok(self.dir.join(format!("{}.json", run_id)))
+    }
+
+    /// Saves a run output, replacing any earlier output with the same run id.
+    pub fn save(&self, output: &RunOutput) -> Result<PathBuf> {
+        let path = self.path_for(&output.run_id)?;
+        fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create run store '{}'", self.dir.display()))?;
+        fs::write(&path, serde_json::to_string_pretty(output)?)
+            .with_context(|| format!("Failed to write '{}'", path.display()))?;
+        Ok(path)
+    }
+
+    /// Path of a run's manifest.
+    pub fn manifest_path(&self, run_id: &str) -> Result<PathBuf> {
+        check_run_id(run_id)?;
+        Ok(self.dir.join(format!("{}.manifest.json", run_id)))
+    }
+
+    /// Saves a run's manifest and the cassette it refers to.
+    pub fn save_manifest(&self, manifest: &RunManifest, cassette: &Cassette) -> Result<PathBuf> {
+        let path = self.manifest_path(&manifest.run_id)?;
+        fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create run store '{}'", self.dir.display()))?;
+        let cassette_path = self.dir.join(cassette_file_name(&manifest.run_id));
+        fs::write(&cassette_path, serde_json::to_string_pretty(cassette)?)
+            .with_context(|| format!("Failed to write '{}'", cassette_path.display()))?;
+        fs::write(&path, serde_json::to_string_pretty(manifest)?)
+            .with_context(|| format!("Failed to write '{}'", path.display()))?;
+        Ok(path)
+    }
+
+    /// Resolves a manifest reference: a path to a manifest file, or a run id
+    /// in the store. Returns the manifest and the path it was read from.
+    pub fn resolve_manifest(&self, reference: &str) -> Result<(RunManifest, PathBuf)> {
+        let path = Path::new(reference);
+        let path = if path.is_file() {
+            path.to_path_buf()
+        } else {
+            self.manifest_path(reference).unwrap_or_default()
+        };
+        if !path.is_file() {
+            anyhow::bail!(
+                "'{}' is neither a manifest file nor a run id with a manifest in '{}'",
+                reference,
+                self.dir.display()
+            );
+        }
+        Ok((RunManifest::load(&path)?, path))
+    }
+
+    /// Loads the recorded output of a manifest's run: `<run_id>.json` next
+    /// to the manifest if there is one, otherwise the run in the store.
+    pub fn load_for_manifest(&self, manifest: &RunManifest, manifest_path: &Path) -> Result<RunOutput> {
+        check_run_id(&manifest.run_id)?;
+        let sibling = manifest_path.with_file_name(format!("{}.json", manifest.run_id));
+        if sibling.is_file() {
+            return read_output(&sibling);
+        }
+        self.load(&manifest.run_id)
+    }
+
+    /// Loads a run by id.
+    pub fn load(&self, run_id: &str) -> Result<RunOutput> {
+        read_output(&self.path_for(run_id)?)
+    }
+
+    /// Resolves a run reference: a path to a saved JSON output, or a run id
+    /// in the store.
+    pub fn resolve(&self, reference: &str) -> Result<RunOutput> {
+        let path = Path::new(reference);
+        if path.is_file() {
+            return read_output(path);
+        }
+        self.load(reference).with_context(|| {
+            format!("'{}' is neither a JSON file nor a run id in '{}'", reference, self.dir.display())
+        })
+    }
+}
+
+/// Rejects run ids that would name a file outside the store.
+fn check_run_id(run_id: &str) -> Result<()> {
+    if run_id.is_empty() || run_id.contains(['/', '\\']) || run_id.starts_with('.') {
+        anyhow::bail!("Invalid run id '{}'", run_id);
+    }
+    Ok(())
+}
+
+fn read_output(path: &Path) -> Result<RunOutput> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read '{}'", path.display()))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("'{}' is not a converge run output", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_ids_cannot_leave_the_store() {
+        let store = RunStore::new("runs");
+        assert_eq!(store.manifest_path("run_1").unwrap(), Path::new("runs/run_1.manifest.json"));
+        for run_id in ["", "../escape", "a/b", "a\\b", ".hidden"] {
+            assert!(store.path_for(run_id).is_err(), "{}", run_id);
+            assert!(store.manifest_path(run_id).is_err(), "{}", run_id);
+        }
+    }
+}
@@ -17,18 +17,26 @@
 //! [cycle:3] converged | 3 cycles, 5 facts
 //! ```
 //!
+//! A run cancelled with Ctrl+C ends with `cancelled` instead.
+//!
 //! ## JSON (one object per line)
 //! ```json
 //! {"cycle":1,"type":"fact","key":"Seeds","id":"seed-1","content":"Initial market data"}
-//! {"cycle":3,"type":"status","converged":true,"cycles":3,"facts":5}
+//! {"cycle":3,"type":"status","status":"converged","converged":true,"cycles":3,"facts":5,"context_hash":{"overall":"9f2c…","by_key":{"Seeds":"41d0…"}}}
 //! ```
+//!
+//! The final status carries the hash of the final context (see
+//! `hashing::ContextHash`) so a consumer can verify it received every fact.
 
 use std::io::{self, Write};
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
 use converge_core::{Fact, StreamingCallback};
 use serde::Serialize;
 
+use crate::hashing::ContextHash;
+
 /// Output format for streaming.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OutputFormat {
@@ -70,27 +78,47 @@ impl StreamingHandler {
         self.fact_count.load(Ordering::SeqCst)
     }
 
-    /// Emits the final status line.
-    pub fn emit_final_status(&self, converged: bool, cycles: u32) {
+    /// Emits the final status line, with the hash of the final context.
+    pub fn emit_final_status(&self, converged: bool, cycles: u32, context_hash: Option<&ContextHash>) {
+        let status = if converged { "converged" } else { "halted" };
+        self.emit_status(status, converged, cycles, context_hash);
+    }
+
+    /// Emits the final status line for a run cancelled before convergence.
+    pub fn emit_cancelled(&self, cycles: u32) {
+        self.emit_status("cancelled", false, cycles, None);
+    }
+
+    fn emit_status(&self, status: &str, converged: bool, cycles: u32, context_hash: Option<&ContextHash>) {
         let facts = self.fact_count();
         match self.format {
-            OutputFormat::Human => {
-                let status = if converged { "converged" } else { "halted" };
-                println!("[cycle:{}] {} | {} cycles, {} facts", cycles, status, cycles, facts);
-            }
+            OutputFormat::Human => match context_hash {
+                Some(hash) => println!(
+                    "[cycle:{}] {} | {} cycles, {} facts, context {}",
+                    cycles,
+                    status,
+                    cycles,
+                    facts,
+                    hash.short()
+                ),
+                None => println!("[cycle:{}] {} | {} cycles, {} facts", cycles, status, cycles, facts),
+            },
             OutputFormat::Json => {
                 let status = StreamingStatus {
                     cycle: cycles,
                     event_type: "status".to_string(),
+                    status: status.to_string(),
                     converged,
                     cycles,
                     facts,
+                    context_hash: context_hash.cloned(),
                 };
                 if let Ok(json) = serde_json::to_string(&status) {
                     println!("{}", json);
                 }
             }
         }
+        let _ = io::stdout().flush();
     }
 }
 
@@ -136,6 +164,41 @@ impl StreamingCallback for StreamingHandler {
     }
 }
 
+/// Forwards engine callbacks to several handlers, in registration order.
+///
+/// The engine accepts a single streaming callback; this lets run telemetry
+/// and user-facing output observe the same run.
+pub struct FanoutCallback {
+    callbacks: Vec<Arc<dyn StreamingCallback + Send + Sync>>,
+}
+
+impl FanoutCallback {
+    /// Creates a callback forwarding to each of `callbacks`.
+    pub fn new(callbacks: Vec<Arc<dyn StreamingCallback + Send + Sync>>) -> Self {
+        Self { callbacks }
+    }
+}
+
+impl StreamingCallback for FanoutCallback {
+    fn on_cycle_start(&self, cycle: u32) {
+        for callback in &self.callbacks {
+            callback.on_cycle_start(cycle);
+        }
+    }
+
+    fn on_fact(&self, cycle: u32, fact: &Fact) {
+        for callback in &self.callbacks {
+            callback.on_fact(cycle, fact);
+        }
+    }
+
+    fn on_cycle_end(&self, cycle: u32, facts_added: usize) {
+        for callback in &self.callbacks {
+            callback.on_cycle_end(cycle, facts_added);
+        }
+    }
+}
+
 /// JSON structure for fact events.
 #[derive(Debug, Serialize)]
 struct StreamingFact {
@@ -153,9 +216,13 @@ struct StreamingStatus {
     cycle: u32,
     #[serde(rename = "type")]
     event_type: String,
+    /// "converged", "halted" or "cancelled"
+    status: String,
     converged: bool,
     cycles: u32,
     facts: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    context_hash: Option<ContextHash>,
 }
 
 #[cfg(test)]
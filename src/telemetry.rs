@@ -0,0 +1,399 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! Per-agent run statistics.
+//!
+//! Every registered agent is wrapped in [`Observed`] and every LLM provider
+//! handed to an agent in [`TimedProvider`]. Both report into a shared
+//! [`AgentStats`], which also tracks the current cycle through its
+//! `StreamingCallback` implementation.
+//!
+//! Statistics are telemetry: they are never read by agents and never
+//! influence convergence.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Instant;
+
+use converge_core::llm::{LlmError, LlmProvider, LlmRequest, LlmResponse};
+use converge_core::{Agent, AgentEffect, Context, ContextKey, Fact, StreamingCallback};
+
+use crate::output::ProviderInfo;
+
+/// Statistics for one agent within a run (or aggregated across runs).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AgentStat {
+    pub name: String,
+    /// Number of times the agent executed.
+    pub runs: u32,
+    /// Facts produced, per context key.
+    pub facts_by_key: BTreeMap<String, usize>,
+    /// Last cycle the agent executed in.
+    pub last_cycle: Option<u32>,
+    /// Number of LLM calls made on the agent's behalf.
+    pub llm_calls: u32,
+    /// Total LLM latency in milliseconds.
+    pub llm_latency_ms: u64,
+    /// Failed LLM calls.
+    pub errors: u32,
+    /// Total tokens reported by the LLM provider.
+    pub tokens: u64,
+    /// Context keys the agent depends on.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<String>,
+    /// Times `accepts` returned false.
+    pub declined: u32,
+    /// Dependency keys that had no facts at the last declined check.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub missing_dependencies: Vec<String>,
+}
+
+impl AgentStat {
+    /// Total facts produced across all keys.
+    pub fn facts_produced(&self) -> usize {
+        self.facts_by_key.values().sum()
+    }
+
+    /// Mean LLM latency in milliseconds, if the agent called an LLM.
+    pub fn mean_llm_latency_ms(&self) -> Option<u64> {
+        (self.llm_calls > 0).then(|| self.llm_latency_ms / u64::from(self.llm_calls))
+    }
+
+    /// Adds another run's statistics to these.
+    pub fn merge(&mut self, other: &AgentStat) {
+        self.runs += other.runs;
+        for (key, count) in &other.facts_by_key {
+            *self.facts_by_key.entry(key.clone()).or_default() += count;
+        }
+        if other.last_cycle.is_some() {
+            self.last_cycle = other.last_cycle;
+        }
+        self.llm_calls += other.llm_calls;
+        self.llm_latency_ms += other.llm_latency_ms;
+        self.errors += other.errors;
+        self.tokens += other.tokens;
+        if self.dependencies.is_empty() {
+            self.dependencies.clone_from(&other.dependencies);
+        }
+        self.declined += other.declined;
+        if other.declined > 0 {
+            self.missing_dependencies.clone_from(&other.missing_dependencies);
+        }
+    }
+}
+
+/// Shared statistics for all agents in one run.
+#[derive(Debug, Default)]
+pub struct AgentStats {
+    current_cycle: AtomicU32,
+    agents: Mutex<BTreeMap<String, AgentStat>>,
+    provider: Mutex<Option<ProviderInfo>>,
+    invariants: Mutex<Vec<String>>,
+}
+
+impl AgentStats {
+    /// Creates an empty statistics registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lists an agent even if it never runs.
+    pub fn register(&self, agent: &str) {
+        self.update(agent, |_| {});
+    }
+
+    /// Lists an invariant registered for the run.
+    pub fn register_invariant(&self, name: &str) {
+        self.invariants
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(name.to_string());
+    }
+
+    /// Invariants registered for the run, in registration order.
+    pub fn invariants(&self) -> Vec<String> {
+        self.invariants.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// Records the LLM provider the run's agents call.
+    pub fn set_provider(&self, name: &str, model: &str) {
+        *self.provider.lock().unwrap_or_else(PoisonError::into_inner) = Some(ProviderInfo {
+            name: name.to_string(),
+            model: model.to_string(),
+        });
+    }
+
+    /// The LLM provider recorded for the run, if any.
+    pub fn provider(&self) -> Option<ProviderInfo> {
+        self.provider.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// The cycle the engine is currently executing.
+    pub fn current_cycle(&self) -> u32 {
+        self.current_cycle.load(Ordering::SeqCst)
+    }
+
+    /// Returns a copy of every agent's statistics, ordered by name.
+    pub fn snapshot(&self) -> Vec<AgentStat> {
+        self.agents
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    fn update(&self, agent: &str, f: impl FnOnce(&mut AgentStat)) {
+        let mut agents = self.agents.lock().unwrap_or_else(PoisonError::into_inner);
+        let stat = agents.entry(agent.to_string()).or_insert_with(|| AgentStat {
+            name: agent.to_string(),
+            ..AgentStat::default()
+        });
+        f(stat);
+    }
+
+    fn record_declined(&self, agent: &str, missing: Vec<String>) {
+        self.update(agent, |stat| {
+            stat.declined += 1;
+            stat.missing_dependencies = missing;
+        });
+    }
+
+    fn begin_execute(&self, agent: &str) {
+        let cycle = self.current_cycle();
+        self.update(agent, |stat| {
+            stat.runs += 1;
+            stat.last_cycle = Some(cycle);
+        });
+    }
+
+    fn end_execute(&self, agent: &str, facts: &[Fact]) {
+        self.update(agent, |stat| {
+            for fact in facts {
+                *stat.facts_by_key.entry(format!("{:?}", fact.key)).or_default() += 1;
+            }
+        });
+    }
+
+    fn record_llm_call(&self, agent: &str, latency_ms: u64, tokens: Option<u64>) {
+        self.update(agent, |stat| {
+            stat.llm_calls += 1;
+            stat.llm_latency_ms += latency_ms;
+            match tokens {
+                Some(tokens) => stat.tokens += tokens,
+                None => stat.errors += 1,
+            }
+        });
+    }
+
+    /// Total LLM tokens across all agents.
+    pub fn total_tokens(&self) -> u64 {
+        self.agents
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .map(|stat| stat.tokens)
+            .sum()
+    }
+}
+
+impl StreamingCallback for AgentStats {
+    fn on_cycle_start(&self, cycle: u32) {
+        self.current_cycle.store(cycle, Ordering::SeqCst);
+    }
+
+    fn on_fact(&self, _cycle: u32, _fact: &Fact) {}
+
+    fn on_cycle_end(&self, _cycle: u32, _facts_added: usize) {}
+}
+
+/// Agent wrapper that records executions into [`AgentStats`].
+pub struct Observed<A> {
+    inner: A,
+    stats: Arc<AgentStats>,
+}
+
+impl<A: Agent> Observed<A> {
+    /// Wraps `inner`, listing it and its dependencies in `stats` straight
+    /// away.
+    pub fn new(inner: A, stats: Arc<AgentStats>) -> Self {
+        let dependencies = inner.dependencies().iter().map(|key| format!("{:?}", key)).collect();
+        stats.update(inner.name(), |stat| stat.dependencies = dependencies);
+        Self { inner, stats }
+    }
+}
+
+impl<A: Agent> Agent for Observed<A> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn dependencies(&self) -> &[ContextKey] {
+        self.inner.dependencies()
+    }
+
+    fn accepts(&self, ctx: &Context) -> bool {
+        let accepted = self.inner.accepts(ctx);
+        if !accepted {
+            let missing = self
+                .inner
+                .dependencies()
+                .iter()
+                .filter(|key| !ctx.has(**key))
+                .map(|key| format!("{:?}", key))
+                .collect();
+            self.stats.record_declined(self.inner.name(), missing);
+        }
+        accepted
+    }
+
+    fn execute(&self, ctx: &Context) -> AgentEffect {
+        self.stats.begin_execute(self.inner.name());
+        let effect = self.inner.execute(ctx);
+        self.stats.end_execute(self.inner.name(), &effect.facts);
+        effect
+    }
+}
+
+/// LLM provider wrapper that records call latency, token usage and failures for
+/// an agent.
+pub struct TimedProvider {
+    inner: Arc<dyn LlmProvider>,
+    agent: String,
+    stats: Arc<AgentStats>,
+}
+
+impl TimedProvider {
+    /// Attributes calls through `inner` to `agent`.
+    pub fn new(inner: Arc<dyn LlmProvider>, agent: impl Into<String>, stats: Arc<AgentStats>) -> Self {
+        Self {
+            inner,
+            agent: agent.into(),
+            stats,
+        }
+    }
+}
+
+impl LlmProvider for TimedProvider {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn model(&self) -> &str {
+        self.inner.model()
+    }
+
+    fn complete(&self, request: &LlmRequest) -> Result<LlmResponse, LlmError> {
+        let start = Instant::now();
+        let result = self.inner.complete(request);
+        let tokens = result.as_ref().ok().map(|response| u64::from(response.usage.total_tokens));
+        self.stats
+            .record_llm_call(&self.agent, start.elapsed().as_millis() as u64, tokens);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn observed_agent_counts_runs_and_facts_per_key() {
+        struct Emitter;
+
+        impl Agent for Emitter {
+            fn name(&self) -> &str {
+                "Emitter"
+            }
+
+            fn dependencies(&self) -> &[ContextKey] {
+                &[]
+            }
+
+            fn accepts(&self, _ctx: &Context) -> bool {
+                true
+            }
+
+            fn execute(&self, _ctx: &Context) -> AgentEffect {
+                AgentEffect::with_facts(vec![
+                    Fact::new(ContextKey::Signals, "signal:a", "a"),
+                    Fact::new(ContextKey::Signals, "signal:b", "b"),
+                    Fact::new(ContextKey::Competitors, "competitor:a", "a"),
+                ])
+            }
+        }
+
+        let stats = Arc::new(AgentStats::new());
+        let agent = Observed::new(Emitter, stats.clone());
+        assert_eq!(stats.snapshot()[0].runs, 0);
+
+        stats.on_cycle_start(3);
+        agent.execute(&Context::new());
+
+        let stat = &stats.snapshot()[0];
+        assert_eq!(stat.runs, 1);
+        assert_eq!(stat.last_cycle, Some(3));
+        assert_eq!(stat.facts_by_key.get("Signals"), Some(&2));
+        assert_eq!(stat.facts_produced(), 3);
+    }
+
+    #[test]
+    fn observed_agent_records_declined_accepts_with_missing_dependencies() {
+        struct Waiter;
+
+        impl Agent for Waiter {
+            fn name(&self) -> &str {
+                "Waiter"
+            }
+
+            fn dependencies(&self) -> &[ContextKey] {
+                &[ContextKey::Seeds, ContextKey::Strategies]
+            }
+
+            fn accepts(&self, ctx: &Context) -> bool {
+                ctx.has(ContextKey::Strategies)
+            }
+
+            fn execute(&self, _ctx: &Context) -> AgentEffect {
+                AgentEffect::with_facts(Vec::new())
+            }
+        }
+
+        let stats = Arc::new(AgentStats::new());
+        let agent = Observed::new(Waiter, stats.clone());
+        let mut ctx = Context::new();
+        ctx.add_fact(Fact::new(ContextKey::Seeds, "seed:a", "a")).unwrap();
+        assert!(!agent.accepts(&ctx));
+
+        let stat = &stats.snapshot()[0];
+        assert_eq!(stat.dependencies, ["Seeds", "Strategies"]);
+        assert_eq!(stat.declined, 1);
+        assert_eq!(stat.missing_dependencies, ["Strategies"]);
+    }
+
+    #[test]
+    fn merge_accumulates_across_runs() {
+        let mut total = AgentStat::default();
+        let mut run = AgentStat {
+            runs: 1,
+            llm_calls: 2,
+            llm_latency_ms: 300,
+            last_cycle: Some(4),
+            ..AgentStat::default()
+        };
+        run.facts_by_key.insert("Hypotheses".to_string(), 3);
+
+        total.merge(&run);
+        total.merge(&run);
+
+        assert_eq!(total.runs, 2);
+        assert_eq!(total.facts_produced(), 6);
+        assert_eq!(total.mean_llm_latency_ms(), Some(150));
+        assert_eq!(total.last_cycle, Some(4));
+    }
+}
@@ -9,24 +9,31 @@
 //! - View management and transitions
 
 use anyhow::Result;
-use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use ratatui::{backend::CrosstermBackend, widgets::{ListState, TableState}, Terminal};
 use std::io::Stdout;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use converge_core::{Context, ContextKey, Engine, Fact};
-use converge_core::llm::LlmProvider;
-use converge_provider::{AnthropicProvider, OpenAiProvider};
+use converge_core::{Context, ContextKey, Fact};
 use strum::IntoEnumIterator;
 
-use crate::agents::{MockInsightProvider, RiskAssessmentAgent, StrategicInsightAgent};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+use super::evals::{EvalBrowser, EvalRunState, DEFAULT_EVALS_DIR};
+use super::remote::{RemoteClient, RemoteEvent, REMOTE_POLL_INTERVAL};
+use super::seeds::{PresetStore, SeedEditor, SeedPromptKind};
+use super::worker::{spawn_evals, spawn_job, JobEvent, JobOutcome, JobRequest};
+use crate::confidence::{ConfidenceLedger, ReviewItem, ReviewRecord};
+use crate::control::JobControl;
+use crate::diff::{RunDiff, RunSide};
+use crate::export::{self, DEFAULT_EXPORTS_DIR};
+use crate::hashing::ContextHash;
+use crate::output::{ActorInfo, FactOutput, ProviderInfo, RunOutput, RunResultOutput};
 use crate::packs;
-use converge_domain::growth_strategy::{
-    BrandSafetyInvariant, CompetitorAgent, EvaluationAgent, MarketSignalAgent,
-    RequireEvaluationRationale, RequireMultipleStrategies, RequireStrategyEvaluations,
-    StrategyAgent,
-};
+use crate::provenance::FactProvenance;
+use crate::telemetry::AgentStat;
+use crate::wiring::{pack_agent_names, AgentWiring};
 
 pub type AppResult<T> = Result<T>;
 
@@ -39,6 +46,9 @@ pub enum View {
     Submit,
     Context,
     Agents,
+    Evals,
+    JobDiff,
+    Provenance,
 }
 
 impl View {
@@ -50,6 +60,7 @@ impl View {
             View::Submit,
             View::Context,
             View::Agents,
+            View::Evals,
         ]
     }
 
@@ -61,6 +72,18 @@ impl View {
             View::Submit => "Submit",
             View::Context => "Context",
             View::Agents => "Agents",
+            View::Evals => "Evals",
+            View::JobDiff => "Job Diff",
+            View::Provenance => "Provenance",
+        }
+    }
+
+    /// The tab a view belongs to (drill-down views belong to their parent)
+    pub fn tab(&self) -> View {
+        match self {
+            View::JobDetail | View::JobDiff => View::Jobs,
+            View::Provenance => View::Context,
+            other => *other,
         }
     }
 }
@@ -81,6 +104,7 @@ pub enum JobStatus {
     Converged,
     Failed,
     Paused,
+    Cancelled,
 }
 
 impl JobStatus {
@@ -91,6 +115,20 @@ impl JobStatus {
             JobStatus::Converged => "Converged",
             JobStatus::Failed => "Failed",
             JobStatus::Paused => "Paused",
+            JobStatus::Cancelled => "Cancelled",
+        }
+    }
+
+    /// Parse a status reported by a converge server (or a stream status
+    /// line); unknown values read as pending
+    pub fn parse(status: &str) -> Self {
+        match status.to_ascii_lowercase().as_str() {
+            "running" => JobStatus::Running,
+            "converged" => JobStatus::Converged,
+            "failed" | "halted" | "error" => JobStatus::Failed,
+            "paused" => JobStatus::Paused,
+            "cancelled" | "canceled" => JobStatus::Cancelled,
+            _ => JobStatus::Pending,
         }
     }
 }
@@ -121,8 +159,25 @@ pub struct PackInfo {
 pub struct AgentInfo {
     pub name: String,
     pub status: String,
+    /// Job the agent last executed in
     pub last_run: Option<String>,
-    pub facts_produced: usize,
+    /// Runs, facts per key, LLM latency and errors (per job, or summed across jobs)
+    pub stats: AgentStat,
+}
+
+impl AgentInfo {
+    /// An agent that has not run yet
+    fn ready(name: String) -> Self {
+        Self {
+            stats: AgentStat {
+                name: name.clone(),
+                ..AgentStat::default()
+            },
+            name,
+            status: "Ready".to_string(),
+            last_run: None,
+        }
+    }
 }
 
 /// Fact information
@@ -131,16 +186,25 @@ pub struct FactInfo {
     pub key: String,
     pub id: String,
     pub content: String,
-    pub confidence: f64,
+    /// LLM-reported confidence (`None` for deterministic or unscored facts)
+    pub confidence: Option<f64>,
+    /// Producing agent, cycle and source facts (`None` for seeds)
+    pub provenance: Option<FactProvenance>,
 }
 
 /// Job detail with full context
 #[derive(Debug, Clone)]
 pub struct JobDetail {
     pub info: JobInfo,
+    /// Seeds the job was submitted with (used to re-run it)
+    pub seeds: Vec<FactInfo>,
     pub facts: Vec<FactInfo>,
     pub agents: Vec<AgentInfo>,
     pub proposals: Vec<ProposalInfo>,
+    pub selected_proposal: usize,
+    pub audit: Vec<AuditEntry>,
+    /// LLM provider the job's agents used (known once it finishes)
+    pub provider: Option<ProviderInfo>,
 }
 
 /// Proposal awaiting review
@@ -150,14 +214,114 @@ pub struct ProposalInfo {
     pub agent: String,
     pub key: String,
     pub content: String,
-    pub confidence: f64,
+    pub confidence: Option<f64>,
+    /// Confidence threshold the proposal fell below
+    pub threshold: f64,
+    pub decision: Option<ReviewDecision>,
+}
+
+/// A reviewer's decision on a proposal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewDecision {
+    Approve,
+    Reject,
+    Edit,
+}
+
+impl ReviewDecision {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReviewDecision::Approve => "approved",
+            ReviewDecision::Reject => "rejected",
+            ReviewDecision::Edit => "edited",
+        }
+    }
+}
+
+/// Audit trail entry recording a review decision
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    pub at: String,
+    pub reviewer: String,
+    pub proposal_id: String,
+    pub decision: ReviewDecision,
+    pub reason: String,
+    /// Content committed to the context (None when rejected)
+    pub committed: Option<String>,
+}
+
+/// Search, key filter and collapsed groups of the Context view
+#[derive(Debug, Clone, Default)]
+pub struct ContextBrowser {
+    /// Case-insensitive text matched against fact ids and content
+    pub query: String,
+    /// True while `/` search input is open
+    pub searching: bool,
+    /// Only show facts with this context key
+    pub key_filter: Option<String>,
+    /// Keys whose groups are collapsed
+    pub collapsed: std::collections::HashSet<String>,
+}
+
+impl ContextBrowser {
+    /// True if any search or filter narrows the fact list
+    pub fn is_narrowed(&self) -> bool {
+        !self.query.is_empty() || self.key_filter.is_some()
+    }
+
+    fn matches(&self, fact: &FactInfo) -> bool {
+        if self.key_filter.as_ref().is_some_and(|key| *key != fact.key) {
+            return false;
+        }
+        let query = self.query.to_lowercase();
+        query.is_empty()
+            || fact.id.to_lowercase().contains(&query)
+            || fact.content.to_lowercase().contains(&query)
+    }
+}
+
+/// A row of the Context view
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContextRow {
+    /// Group header for a context key, with the number of matching facts
+    Group { key: String, count: usize, collapsed: bool },
+    /// Index into `context_facts`
+    Fact(usize),
+}
+
+/// A line of the provenance tree, from a fact down to its seeds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProvenanceRow {
+    /// Tree-drawing prefix (e.g. "│  └─ ")
+    pub prefix: String,
+    pub id: String,
+    /// Index into `context_facts` (`None` if the source is not in the context)
+    pub fact: Option<usize>,
+    /// Already expanded higher up the tree; its sources are not repeated
+    pub repeated: bool,
+}
+
+/// Which field of the review prompt is being edited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewStage {
+    Content,
+    Reason,
+}
+
+/// In-progress review input for the selected proposal
+#[derive(Debug, Clone)]
+pub struct ReviewInput {
+    pub decision: ReviewDecision,
+    pub stage: ReviewStage,
+    pub content: String,
+    pub reason: String,
 }
 
 /// Submit job form
 #[derive(Debug, Clone, Default)]
 pub struct SubmitForm {
     pub pack: String,
-    pub seeds: String,
+    pub seeds: SeedEditor,
     pub max_cycles: String,
     pub selected_field: usize,
     pub error: Option<String>,
@@ -168,7 +332,7 @@ impl SubmitForm {
     pub fn new() -> Self {
         Self {
             pack: String::new(),
-            seeds: String::new(),
+            seeds: SeedEditor::default(),
             max_cycles: "50".to_string(),
             selected_field: 0,
             error: None,
@@ -188,6 +352,14 @@ pub struct App {
     pub job_state: TableState,
     pub job_detail: Option<JobDetail>,
     pub job_details_cache: std::collections::HashMap<String, JobDetail>,
+    pub review_input: Option<ReviewInput>,
+    pub job_controls: std::collections::HashMap<String, Arc<JobControl>>,
+    /// Review threshold for local jobs (`validation.min_confidence`)
+    pub min_confidence: Option<f64>,
+    /// Jobs marked for diffing (at most two, oldest mark first)
+    pub marked_jobs: Vec<String>,
+    pub job_diff: Option<RunDiff>,
+    pub diff_scroll: u16,
 
     // Packs view
     pub packs: Vec<PackInfo>,
@@ -199,11 +371,30 @@ pub struct App {
     // Context view
     pub context_facts: Vec<FactInfo>,
     pub fact_state: ListState,
+    pub context_browser: ContextBrowser,
+    /// Fact the provenance tree starts from
+    pub provenance_root: Option<String>,
+    /// Earlier roots, for stepping back after following a source
+    pub provenance_trail: Vec<String>,
+    pub provenance_state: ListState,
 
     // Agents view
     pub agents: Vec<AgentInfo>,
     pub agent_state: TableState,
 
+    // Evals view
+    pub evals: EvalBrowser,
+
+    // Background jobs
+    pub job_events_tx: UnboundedSender<JobEvent>,
+    pub job_events_rx: UnboundedReceiver<JobEvent>,
+
+    // Remote mode (jobs run on a converge server)
+    pub remote: Option<RemoteClient>,
+    /// Jobs whose progress stream is open
+    pub remote_streams: std::collections::HashSet<String>,
+    pub last_remote_refresh: Option<Instant>,
+
     // Status
     pub status_message: Option<String>,
     pub loading: bool,
@@ -223,6 +414,8 @@ impl App {
         let mut agent_state = TableState::default();
         agent_state.select(Some(0));
 
+        let (job_events_tx, job_events_rx) = unbounded_channel();
+
         let mut app = Self {
             running: true,
             current_view: View::Jobs,
@@ -231,13 +424,29 @@ impl App {
             job_state,
             job_detail: None,
             job_details_cache: std::collections::HashMap::new(),
+            review_input: None,
+            job_controls: std::collections::HashMap::new(),
+            min_confidence: None,
+            marked_jobs: Vec::new(),
+            job_diff: None,
+            diff_scroll: 0,
             packs: Vec::new(),
             pack_state,
             submit_form: SubmitForm::new(),
             context_facts: Vec::new(),
             fact_state,
+            context_browser: ContextBrowser::default(),
+            provenance_root: None,
+            provenance_trail: Vec::new(),
+            provenance_state: ListState::default(),
             agents: Vec::new(),
             agent_state,
+            evals: EvalBrowser::new(DEFAULT_EVALS_DIR),
+            job_events_tx,
+            job_events_rx,
+            remote: None,
+            remote_streams: std::collections::HashSet::new(),
+            last_remote_refresh: None,
             status_message: None,
             loading: false,
         };
@@ -264,45 +473,8 @@ impl App {
             })
             .collect();
 
-        // Initialize agents list for growth-strategy (default pack)
-        self.agents = vec![
-            AgentInfo {
-                name: "MarketSignalAgent".to_string(),
-                status: "Ready".to_string(),
-                last_run: None,
-                facts_produced: 0,
-            },
-            AgentInfo {
-                name: "CompetitorAgent".to_string(),
-                status: "Ready".to_string(),
-                last_run: None,
-                facts_produced: 0,
-            },
-            AgentInfo {
-                name: "StrategyAgent".to_string(),
-                status: "Ready".to_string(),
-                last_run: None,
-                facts_produced: 0,
-            },
-            AgentInfo {
-                name: "EvaluationAgent".to_string(),
-                status: "Ready".to_string(),
-                last_run: None,
-                facts_produced: 0,
-            },
-            AgentInfo {
-                name: "StrategicInsightAgent".to_string(),
-                status: "Ready".to_string(),
-                last_run: None,
-                facts_produced: 0,
-            },
-            AgentInfo {
-                name: "RiskAssessmentAgent".to_string(),
-                status: "Ready".to_string(),
-                last_run: None,
-                facts_produced: 0,
-            },
-        ];
+        // Agents of the default pack, as registered by the wiring
+        self.agents = pack_agents("growth-strategy");
 
         // Start with empty jobs (no demo jobs)
         self.jobs = Vec::new();
@@ -365,6 +537,41 @@ impl App {
                     data_id: None,
                 });
             }
+            View::Evals => {
+                self.breadcrumb.push(BreadcrumbSegment {
+                    label: "Evals".to_string(),
+                    view: View::Evals,
+                    data_id: None,
+                });
+            }
+            View::Provenance => {
+                self.breadcrumb.push(BreadcrumbSegment {
+                    label: "Context".to_string(),
+                    view: View::Context,
+                    data_id: None,
+                });
+                if let Some(ref root) = self.provenance_root {
+                    self.breadcrumb.push(BreadcrumbSegment {
+                        label: format!("Provenance: {}", root),
+                        view: View::Provenance,
+                        data_id: Some(root.clone()),
+                    });
+                }
+            }
+            View::JobDiff => {
+                self.breadcrumb.push(BreadcrumbSegment {
+                    label: "Jobs".to_string(),
+                    view: View::Jobs,
+                    data_id: None,
+                });
+                if let Some(ref diff) = self.job_diff {
+                    self.breadcrumb.push(BreadcrumbSegment {
+                        label: format!("{} ↔ {}", diff.before.label, diff.after.label),
+                        view: View::JobDiff,
+                        data_id: None,
+                    });
+                }
+            }
         }
     }
 
@@ -423,7 +630,7 @@ impl App {
                 }
             }
             View::Context => {
-                let len = self.context_facts.len();
+                let len = self.context_rows().len();
                 if len > 0 {
                     let i = self.fact_state.selected().unwrap_or(0);
                     self.fact_state.select(Some((i + 1) % len));
@@ -437,9 +644,30 @@ impl App {
                 }
             }
             View::Submit => {
-                self.submit_form.selected_field = (self.submit_form.selected_field + 1) % 3;
+                // Down moves through seed rows before leaving the seeds field
+                if self.submit_form.selected_field != 1 || !self.submit_form.seeds.select_next() {
+                    self.submit_form.selected_field = (self.submit_form.selected_field + 1) % 3;
+                }
+            }
+            View::JobDetail => {
+                if let Some(ref mut detail) = self.job_detail {
+                    let len = detail.proposals.len();
+                    if len > 0 {
+                        detail.selected_proposal = (detail.selected_proposal + 1) % len;
+                    }
+                }
+            }
+            View::Evals => self.evals.select_next(),
+            View::JobDiff => {
+                self.diff_scroll = self.diff_scroll.saturating_add(1);
+            }
+            View::Provenance => {
+                let len = self.provenance_rows().len();
+                if len > 0 {
+                    let i = self.provenance_state.selected().unwrap_or(0);
+                    self.provenance_state.select(Some((i + 1) % len));
+                }
             }
-            _ => {}
         }
     }
 
@@ -461,7 +689,7 @@ impl App {
                 }
             }
             View::Context => {
-                let len = self.context_facts.len();
+                let len = self.context_rows().len();
                 if len > 0 {
                     let i = self.fact_state.selected().unwrap_or(0);
                     self.fact_state.select(Some(if i == 0 { len - 1 } else { i - 1 }));
@@ -475,18 +703,252 @@ impl App {
                 }
             }
             View::Submit => {
-                self.submit_form.selected_field = if self.submit_form.selected_field == 0 { 2 } else { self.submit_form.selected_field - 1 };
+                if self.submit_form.selected_field != 1 || !self.submit_form.seeds.select_prev() {
+                    self.submit_form.selected_field = if self.submit_form.selected_field == 0 { 2 } else { self.submit_form.selected_field - 1 };
+                }
             }
-            _ => {}
+            View::JobDetail => {
+                if let Some(ref mut detail) = self.job_detail {
+                    let len = detail.proposals.len();
+                    if len > 0 {
+                        let i = detail.selected_proposal;
+                        detail.selected_proposal = if i == 0 { len - 1 } else { i - 1 };
+                    }
+                }
+            }
+            View::Evals => self.evals.select_prev(),
+            View::JobDiff => {
+                self.diff_scroll = self.diff_scroll.saturating_sub(1);
+            }
+            View::Provenance => {
+                let len = self.provenance_rows().len();
+                if len > 0 {
+                    let i = self.provenance_state.selected().unwrap_or(0);
+                    self.provenance_state.select(Some(if i == 0 { len - 1 } else { i - 1 }));
+                }
+            }
+        }
+    }
+
+    /// Run the marked (or highlighted) eval fixtures in the background
+    pub fn run_evals(&mut self, all: bool) {
+        if self.evals.is_running() {
+            self.status_message = Some("Evals are already running".to_string());
+            return;
+        }
+        let batch = self.evals.take_batch(all);
+        if batch.is_empty() {
+            self.status_message = Some(format!("No eval fixtures in '{}'", self.evals.dir));
+            return;
+        }
+        let mode = if self.evals.force_mock { "mock LLM" } else { "fixture LLM settings" };
+        self.status_message = Some(format!("Running {} eval(s) with {}", batch.len(), mode));
+        spawn_evals(batch, self.job_events_tx.clone());
+    }
+
+    /// Mark or unmark the selected job for diffing; a third mark replaces the oldest
+    pub fn toggle_mark_selected_job(&mut self) {
+        let Some(job_id) = self.selected_job_id() else {
+            return;
+        };
+        if let Some(pos) = self.marked_jobs.iter().position(|id| *id == job_id) {
+            self.marked_jobs.remove(pos);
+        } else {
+            if self.marked_jobs.len() == 2 {
+                self.marked_jobs.remove(0);
+            }
+            self.marked_jobs.push(job_id);
         }
+        self.status_message = Some(match self.marked_jobs.as_slice() {
+            [] => "No jobs marked".to_string(),
+            [one] => format!("Marked {} (mark one more and press d to diff)", one),
+            [a, b] => format!("Marked {} and {} (press d to diff)", a, b),
+            _ => unreachable!("at most two jobs are marked"),
+        });
+    }
+
+    /// Diff the two marked jobs (or the marked job against the selected one)
+    pub fn diff_marked_jobs(&mut self) {
+        let pair = match self.marked_jobs.as_slice() {
+            [a, b] => Some((a.clone(), b.clone())),
+            [a] => self.selected_job_id().filter(|b| b != a).map(|b| (a.clone(), b)),
+            _ => None,
+        };
+        let Some((a, b)) = pair else {
+            self.status_message = Some("Mark two jobs with m to diff them".to_string());
+            return;
+        };
+        let (Some(before), Some(after)) = (self.job_details_cache.get(&a), self.job_details_cache.get(&b)) else {
+            self.status_message = Some("Marked job no longer available".to_string());
+            return;
+        };
+
+        self.job_diff = Some(RunDiff::new(
+            diff_side(before),
+            diff_side(after),
+            &fact_outputs(&before.facts),
+            &fact_outputs(&after.facts),
+        ));
+        self.diff_scroll = 0;
+        self.current_view = View::JobDiff;
+        self.update_breadcrumb();
+    }
+
+    /// Rows of the provenance tree of the current root
+    pub fn provenance_rows(&self) -> Vec<ProvenanceRow> {
+        self.provenance_root
+            .as_deref()
+            .map(|root| provenance_rows(&self.context_facts, root))
+            .unwrap_or_default()
+    }
+
+    /// Show the lineage of the fact selected in the Context view
+    pub fn open_provenance(&mut self) {
+        let Some(id) = self.selected_context_fact().map(|f| f.id.clone()) else {
+            self.status_message = Some("Select a fact to trace its provenance".to_string());
+            return;
+        };
+        self.provenance_root = Some(id);
+        self.provenance_trail.clear();
+        self.provenance_state.select(Some(0));
+        self.current_view = View::Provenance;
+        self.update_breadcrumb();
+    }
+
+    /// Re-root the tree on the selected source fact
+    pub fn follow_provenance(&mut self) {
+        let rows = self.provenance_rows();
+        let Some(row) = self.provenance_state.selected().and_then(|i| rows.get(i)) else {
+            return;
+        };
+        if row.fact.is_none() || self.provenance_root.as_deref() == Some(row.id.as_str()) {
+            return;
+        }
+        if let Some(previous) = self.provenance_root.replace(row.id.clone()) {
+            self.provenance_trail.push(previous);
+        }
+        self.provenance_state.select(Some(0));
+        self.update_breadcrumb();
+    }
+
+    /// Step back to the previous root, or leave the tree
+    pub fn provenance_back(&mut self) {
+        match self.provenance_trail.pop() {
+            Some(previous) => {
+                self.provenance_root = Some(previous);
+                self.provenance_state.select(Some(0));
+                self.update_breadcrumb();
+            }
+            None => self.navigate_back(),
+        }
+    }
+
+    /// Write the open job's report as Markdown, HTML and CSV
+    pub fn export_job_detail(&mut self) {
+        let Some(ref detail) = self.job_detail else {
+            return;
+        };
+        let output = run_output(detail);
+        self.status_message = Some(match export::write_all(&output, std::path::Path::new(DEFAULT_EXPORTS_DIR)) {
+            Ok(paths) => {
+                let paths: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+                format!("Exported {} to {}", detail.info.id, paths.join(", "))
+            }
+            Err(e) => format!("Export failed: {:#}", e),
+        });
+    }
+
+    /// Rows of the Context view after search, filter and collapsing
+    pub fn context_rows(&self) -> Vec<ContextRow> {
+        context_rows(&self.context_facts, &self.context_browser)
+    }
+
+    /// The fact on the selected Context view row, if it is not a group header
+    pub fn selected_context_fact(&self) -> Option<&FactInfo> {
+        let idx = self.fact_state.selected()?;
+        match self.context_rows().get(idx)? {
+            ContextRow::Fact(i) => self.context_facts.get(*i),
+            ContextRow::Group { .. } => None,
+        }
+    }
+
+    /// Open `/` search in the Context view
+    pub fn begin_context_search(&mut self) {
+        self.context_browser.searching = true;
+    }
+
+    /// Handle a key while Context view search is open
+    pub fn handle_search_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Esc => {
+                self.context_browser.query.clear();
+                self.context_browser.searching = false;
+            }
+            KeyCode::Enter => self.context_browser.searching = false,
+            KeyCode::Char(c) => self.context_browser.query.push(c),
+            KeyCode::Backspace => {
+                self.context_browser.query.pop();
+            }
+            _ => return,
+        }
+        self.fact_state.select(Some(0));
+    }
+
+    /// Cycle the Context view filter through the keys present in the context
+    pub fn cycle_key_filter(&mut self) {
+        let keys = present_keys(&self.context_facts);
+        let next = match self.context_browser.key_filter {
+            None => keys.first().cloned(),
+            Some(ref current) => keys
+                .iter()
+                .position(|k| k == current)
+                .and_then(|i| keys.get(i + 1))
+                .cloned(),
+        };
+        self.status_message = Some(match next {
+            Some(ref key) => format!("Showing {} facts", key),
+            None => "Showing all keys".to_string(),
+        });
+        self.context_browser.key_filter = next;
+        self.fact_state.select(Some(0));
+    }
+
+    /// Collapse or expand the group on the selected row (or the selected fact's group)
+    pub fn toggle_context_group(&mut self) {
+        let Some(idx) = self.fact_state.selected() else {
+            return;
+        };
+        let rows = self.context_rows();
+        let key = match rows.get(idx) {
+            Some(ContextRow::Group { key, .. }) => key.clone(),
+            Some(ContextRow::Fact(i)) => self.context_facts[*i].key.clone(),
+            None => return,
+        };
+        if !self.context_browser.collapsed.remove(&key) {
+            self.context_browser.collapsed.insert(key.clone());
+        }
+        // Keep the cursor on the group header
+        let header = self
+            .context_rows()
+            .iter()
+            .position(|row| matches!(row, ContextRow::Group { key: k, .. } if *k == key));
+        self.fact_state.select(header.or(Some(0)));
+    }
+
+    /// Clear the Context view search and key filter
+    pub fn clear_context_filters(&mut self) {
+        self.context_browser.query.clear();
+        self.context_browser.key_filter = None;
+        self.fact_state.select(Some(0));
     }
 
     /// Handle character input
     pub fn handle_char(&mut self, c: char) {
-        if self.current_view == View::Submit {
+        if self.current_view == View::Submit && self.submit_form.selected_field == 1 {
+            self.handle_seed_command(c);
+        } else if self.current_view == View::Submit {
             let field = match self.submit_form.selected_field {
                 0 => &mut self.submit_form.pack,
-                1 => &mut self.submit_form.seeds,
                 2 => &mut self.submit_form.max_cycles,
                 _ => return,
             };
@@ -495,12 +957,59 @@ impl App {
         }
     }
 
+    /// Seed editor commands while the seeds field is focused
+    fn handle_seed_command(&mut self, c: char) {
+        let seeds = &mut self.submit_form.seeds;
+        match c {
+            'a' => seeds.add(),
+            'e' => seeds.edit_selected(),
+            'd' => seeds.delete_selected(),
+            'o' => seeds.open_prompt(SeedPromptKind::LoadFile),
+            's' => seeds.open_prompt(SeedPromptKind::SavePreset),
+            'l' => {
+                let presets = PresetStore::default().list(&self.submit_form.pack);
+                seeds.message = Some(if presets.is_empty() {
+                    "No presets saved for this pack".to_string()
+                } else {
+                    format!("Presets: {}", presets.join(", "))
+                });
+                seeds.open_prompt(SeedPromptKind::LoadPreset);
+            }
+            _ => {}
+        }
+        self.submit_form.error = None;
+    }
+
+    /// Handle a key while the seed editor has an edit or prompt open
+    pub fn handle_seed_key(&mut self, key: KeyEvent) {
+        let form = &mut self.submit_form;
+        if form.seeds.edit.is_some() {
+            form.seeds.handle_edit_key(key);
+        } else {
+            form.seeds.handle_prompt_key(key.code, &form.pack);
+        }
+    }
+
+    /// Handle bracketed paste (text fields take it verbatim, seeds parse it)
+    pub fn handle_paste(&mut self, text: &str) {
+        if self.current_view != View::Submit {
+            return;
+        }
+        let form = &mut self.submit_form;
+        match form.selected_field {
+            0 => form.pack.push_str(text.trim()),
+            1 => form.seeds.paste(text),
+            2 => form.max_cycles.push_str(text.trim()),
+            _ => {}
+        }
+        form.error = None;
+    }
+
     /// Handle backspace
     pub fn handle_backspace(&mut self) {
         if self.current_view == View::Submit {
             let field = match self.submit_form.selected_field {
                 0 => &mut self.submit_form.pack,
-                1 => &mut self.submit_form.seeds,
                 2 => &mut self.submit_form.max_cycles,
                 _ => return,
             };
@@ -521,11 +1030,18 @@ impl App {
                     // No cached detail - create a minimal one
                     self.job_detail = Some(JobDetail {
                         info: job.clone(),
+                        seeds: Vec::new(),
                         facts: Vec::new(),
-                        agents: self.agents.clone(),
+                        agents: self.agents_for_pack(&job.pack),
                         proposals: Vec::new(),
+                        selected_proposal: 0,
+                        audit: Vec::new(),
+                        provider: None,
                     });
                 }
+                if let Some(ref remote) = self.remote {
+                    remote.spawn_detail(job.id.clone(), self.job_events_tx.clone());
+                }
                 self.current_view = View::JobDetail;
                 self.update_breadcrumb();
             }
@@ -545,118 +1061,685 @@ impl App {
             return;
         }
 
+        if let Some(ref remote) = self.remote {
+            let Ok(max_cycles) = self.submit_form.max_cycles.trim().parse::<u32>() else {
+                self.submit_form.error = Some("Max cycles must be a number".to_string());
+                return;
+            };
+            remote.spawn_submit(
+                self.submit_form.pack.clone(),
+                self.submit_form.seeds.rows.clone(),
+                max_cycles,
+                self.job_events_tx.clone(),
+            );
+            self.submit_form.success = Some(format!("Submitting to {}...", remote.base_url()));
+            self.submit_form.pack.clear();
+            self.submit_form.seeds.clear();
+            self.submit_form.max_cycles = "50".to_string();
+            return;
+        }
+
         let job_id = format!("job-{:03}", self.jobs.len() + 1);
         let pack_name = self.submit_form.pack.clone();
-        let seeds_json = self.submit_form.seeds.clone();
 
-        // Parse seeds if provided
+        // Build seeds from the editor rows
         let mut context = Context::new();
-        if !seeds_json.is_empty() {
-            match serde_json::from_str::<Vec<converge_runtime::templates::SeedFact>>(&seeds_json) {
-                Ok(seed_facts) => {
-                    for seed in seed_facts {
-                        let fact = Fact::new(ContextKey::Seeds, seed.id, seed.content);
-                        if let Err(e) = context.add_fact(fact) {
-                            self.submit_form.error = Some(format!("Failed to add seed: {}", e));
-                            return;
-                        }
+        match self.submit_form.seeds.seed_facts() {
+            Ok(seed_facts) => {
+                for seed in seed_facts {
+                    let fact = Fact::new(ContextKey::Seeds, seed.id, seed.content);
+                    if let Err(e) = context.add_fact(fact) {
+                        self.submit_form.error = Some(format!("Failed to add seed: {}", e));
+                        return;
                     }
                 }
-                Err(e) => {
-                    self.submit_form.error = Some(format!("Invalid seeds JSON: {}", e));
-                    return;
-                }
+            }
+            Err(e) => {
+                self.submit_form.error = Some(format!("Invalid seeds: {}", e));
+                return;
             }
         }
 
-        // Run convergence engine
-        let mut engine = Engine::new();
+        let created_at = chrono::Local::now().to_rfc3339();
+        self.start_job(&job_id, &pack_name, context, &created_at, Vec::new());
+        self.submit_form.success = Some(format!("Job {} submitted", job_id));
 
-        // Register agents for the pack
-        if let Err(e) = register_pack_agents(&mut engine, &pack_name) {
-            self.submit_form.error = Some(format!("Failed to register agents: {}", e));
-            return;
+        // Clear form
+        self.submit_form.pack.clear();
+        self.submit_form.seeds.clear();
+        self.submit_form.max_cycles = "50".to_string();
+    }
+
+    /// Record a job as pending and run it on a background worker.
+    ///
+    /// If an existing job has the same id (a resumed job) it is replaced;
+    /// its audit trail is carried over in `audit`.
+    fn start_job(
+        &mut self,
+        job_id: &str,
+        pack_name: &str,
+        context: Context,
+        created_at: &str,
+        audit: Vec<AuditEntry>,
+    ) {
+        let (previous_facts, previous_proposals) = self
+            .job_details_cache
+            .get(job_id)
+            .map(|d| (d.facts.clone(), d.proposals.clone()))
+            .unwrap_or_default();
+        let seeds: Vec<FactInfo> = context
+            .get(ContextKey::Seeds)
+            .iter()
+            .map(|fact| FactInfo {
+                key: format!("{:?}", fact.key),
+                id: fact.id.clone(),
+                content: fact.content.clone(),
+                confidence: None,
+                provenance: None,
+            })
+            .collect();
+
+        let job = JobInfo {
+            id: job_id.to_string(),
+            pack: pack_name.to_string(),
+            status: JobStatus::Pending,
+            cycles: 0,
+            facts: previous_facts.len(),
+            created_at: created_at.to_string(),
+        };
+        let detail = JobDetail {
+            info: job.clone(),
+            seeds,
+            facts: previous_facts,
+            agents: self.agents_for_pack(pack_name),
+            proposals: Vec::new(),
+            selected_proposal: 0,
+            audit,
+            provider: None,
+        };
+        self.store_job(job, detail);
+
+        // A resumed job must not re-run agents whose output was just reviewed
+        let ledger = ConfidenceLedger::new(self.min_confidence)
+            .with_reviewed_agents(previous_proposals.iter().map(|p| p.agent.clone()));
+        let ledger = Arc::new(ledger);
+        let control = Arc::new(JobControl::new());
+        self.job_controls.insert(job_id.to_string(), control.clone());
+        spawn_job(
+            JobRequest {
+                job_id: job_id.to_string(),
+                pack: pack_name.to_string(),
+                context,
+                wiring: AgentWiring::new(false)
+                    .with_ledger(ledger)
+                    .with_control(control),
+            },
+            self.job_events_tx.clone(),
+        );
+    }
+
+    /// Cancels every local job, waking paused ones, so their blocking
+    /// threads finish and the runtime can shut down.
+    pub fn cancel_all_jobs(&mut self) {
+        for control in self.job_controls.values() {
+            control.cancel();
         }
+    }
 
-        // Run the convergence loop
-        match engine.run(context) {
-            Ok(result) => {
-                // Calculate total facts
-                let total_facts: usize = ContextKey::iter()
-                    .map(|key| result.context.get(key).len())
-                    .sum();
+    /// Apply all pending progress events from background jobs
+    pub fn drain_job_events(&mut self) {
+        while let Ok(event) = self.job_events_rx.try_recv() {
+            self.apply_job_event(event);
+        }
+        if self.remote.is_some()
+            && self
+                .last_remote_refresh
+                .is_none_or(|at| at.elapsed() >= REMOTE_POLL_INTERVAL)
+        {
+            self.refresh_remote(false);
+        }
+    }
 
-                let status = if result.converged {
-                    JobStatus::Converged
-                } else {
-                    JobStatus::Failed
+    fn apply_job_event(&mut self, event: JobEvent) {
+        match event {
+            JobEvent::Cycle { job_id, cycle } => {
+                // A pause requested mid-cycle takes hold at this cycle's start
+                let paused = self.job_controls.get(&job_id).is_some_and(|c| c.is_paused());
+                self.update_job(&job_id, |detail| {
+                    detail.info.status = if paused { JobStatus::Paused } else { JobStatus::Running };
+                    detail.info.cycles = cycle;
+                });
+            }
+            JobEvent::Fact { job_id, fact, .. } => {
+                self.update_job(&job_id, |detail| {
+                    // Resumed jobs re-commit their carried-over facts
+                    if let Some(existing) = detail.facts.iter_mut().find(|f| f.id == fact.id) {
+                        *existing = fact;
+                    } else {
+                        detail.facts.push(fact);
+                    }
+                    detail.info.facts = detail.facts.len();
+                });
+            }
+            JobEvent::Agents { job_id, agents } => {
+                self.update_job(&job_id, |detail| apply_agent_stats(detail, agents));
+            }
+            JobEvent::EvalStarted { eval_id } => {
+                self.evals.runs.insert(eval_id, EvalRunState::Running);
+            }
+            JobEvent::EvalFinished { result } => {
+                let status = match result {
+                    r if r.passed => "passed",
+                    r if r.skipped.is_some() => "skipped",
+                    _ => "failed",
+                };
+                self.status_message = Some(format!("Eval {} {} in {}ms", result.eval_id, status, result.duration.as_millis()));
+                self.evals.runs.insert(result.eval_id.clone(), EvalRunState::Finished(result));
+                if !self.evals.is_running() {
+                    let (passed, failed) = self.evals.totals();
+                    self.status_message = Some(format!("Evals finished: {} passed, {} failed", passed, failed));
+                }
+            }
+            JobEvent::Remote(event) => self.apply_remote_event(event),
+            JobEvent::Finished { job_id, outcome } => {
+                self.job_controls.remove(&job_id);
+                let message = match outcome {
+                    Ok(outcome) => self.finish_job(&job_id, outcome),
+                    Err(e) => {
+                        self.update_job(&job_id, |detail| detail.info.status = JobStatus::Failed);
+                        format!("Job {}: {}", job_id, e)
+                    }
                 };
+                self.status_message = Some(message);
+            }
+        }
+        self.refresh_agent_status();
+    }
 
-                // Convert facts to FactInfo
-                let facts: Vec<FactInfo> = ContextKey::iter()
-                    .flat_map(|key| {
-                        result.context.get(key).iter().map(|fact| {
-                            FactInfo {
-                                key: format!("{:?}", fact.key),
-                                id: fact.id.clone(),
-                                content: fact.content.clone(),
-                                confidence: 1.0,
-                            }
-                        }).collect::<Vec<_>>()
-                    })
-                    .collect();
+    /// Update a job's info and cached detail (and the open detail view)
+    fn update_job(&mut self, job_id: &str, update: impl FnOnce(&mut JobDetail)) {
+        let Some(detail) = self.job_details_cache.get_mut(job_id) else {
+            return;
+        };
+        update(detail);
 
-                // Update context facts for the Context view
-                self.context_facts = facts.clone();
-
-                // Create job info
-                let job = JobInfo {
-                    id: job_id.clone(),
-                    pack: pack_name.clone(),
-                    status,
-                    cycles: result.cycles,
-                    facts: total_facts,
-                    created_at: chrono::Local::now().format("%Y-%m-%d %H:%M").to_string(),
-                };
+        let info = detail.info.clone();
+        if let Some(job) = self.jobs.iter_mut().find(|j| j.id == job_id) {
+            *job = info;
+        }
+        if self.job_detail.as_ref().is_some_and(|d| d.info.id == job_id) {
+            self.job_detail = Some(detail.clone());
+        }
+    }
 
-                // Create job detail
-                let detail = JobDetail {
-                    info: job.clone(),
-                    facts: facts.clone(),
-                    agents: self.agents.clone(),
-                    proposals: Vec::new(),
-                };
+    /// Record a finished job's final state and return a status message
+    fn finish_job(&mut self, job_id: &str, outcome: JobOutcome) -> String {
+        let pending = outcome.proposals.len();
+
+        // Jobs with proposals pause until a reviewer decides on them
+        let status = if outcome.cancelled {
+            JobStatus::Cancelled
+        } else if pending > 0 {
+            JobStatus::Paused
+        } else if outcome.converged {
+            JobStatus::Converged
+        } else {
+            JobStatus::Failed
+        };
 
-                // Store job and detail
-                self.job_details_cache.insert(job_id.clone(), detail.clone());
-                self.job_detail = Some(detail);
-                self.jobs.insert(0, job);
+        // Keep confidences and provenance of facts carried over from before
+        // a resume
+        let previous = self.job_details_cache.get(job_id);
+        // A run cancelled mid-cycle reports no final context; keep what
+        // was streamed
+        let final_facts = if outcome.cancelled && outcome.facts.is_empty() {
+            previous.map(|d| d.facts.clone()).unwrap_or_default()
+        } else {
+            outcome.facts
+        };
+        let total_facts = final_facts.len();
+        let facts: Vec<FactInfo> = final_facts
+            .into_iter()
+            .map(|mut fact| {
+                let carried = previous.and_then(|d| d.facts.iter().find(|f| f.id == fact.id));
+                if fact.confidence.is_none() {
+                    fact.confidence = carried.and_then(|f| f.confidence);
+                }
+                if fact.provenance.is_none() {
+                    fact.provenance = carried.and_then(|f| f.provenance.clone());
+                }
+                fact
+            })
+            .collect();
 
-                let status_msg = if result.converged {
-                    format!("Job {} converged in {} cycles with {} facts", job_id, result.cycles, total_facts)
-                } else {
-                    format!("Job {} halted after {} cycles with {} facts", job_id, result.cycles, total_facts)
+        // Update context facts for the Context view
+        self.context_facts = facts.clone();
+
+        let cycles = outcome.cycles;
+        let proposals = outcome.proposals;
+        let agents = outcome.agents;
+        let provider = outcome.provider;
+        self.update_job(job_id, move |detail| {
+            apply_agent_stats(detail, agents);
+            detail.provider = provider;
+            detail.info.status = status;
+            detail.info.cycles = cycles;
+            detail.info.facts = total_facts;
+            detail.facts = facts;
+            detail.proposals = proposals;
+            detail.selected_proposal = 0;
+        });
+
+        if outcome.cancelled {
+            format!("Job {} cancelled after {} cycles with {} facts", job_id, cycles, total_facts)
+        } else if pending > 0 {
+            format!("Job {} paused after {} cycles: {} proposal(s) awaiting review", job_id, cycles, pending)
+        } else if outcome.converged {
+            format!("Job {} converged in {} cycles with {} facts", job_id, cycles, total_facts)
+        } else {
+            format!("Job {} halted after {} cycles with {} facts", job_id, cycles, total_facts)
+        }
+    }
+
+    /// Number of jobs still pending or running
+    pub fn active_jobs(&self) -> usize {
+        self.jobs
+            .iter()
+            .filter(|j| matches!(j.status, JobStatus::Pending | JobStatus::Running))
+            .count()
+    }
+
+    /// Refresh agent status and totals across all jobs
+    ///
+    /// Agents show as running while any job is in flight; each job's
+    /// agents show as running while that job is.
+    fn refresh_agent_status(&mut self) {
+        for detail in self.job_details_cache.values_mut() {
+            let status = job_agent_status(&detail.info.status);
+            for agent in &mut detail.agents {
+                agent.status = status.to_string();
+            }
+        }
+
+        let status = if self.active_jobs() > 0 { "Running" } else { "Ready" };
+        for agent in &mut self.agents {
+            agent.status = status.to_string();
+            agent.stats = AgentStat {
+                name: agent.name.clone(),
+                ..AgentStat::default()
+            };
+            agent.last_run = None;
+            // Jobs are listed newest first; sum oldest first so the last
+            // cycle comes from the most recent run
+            for job in self.jobs.iter().rev() {
+                let Some(detail) = self.job_details_cache.get(&job.id) else {
+                    continue;
                 };
-                self.submit_form.success = Some(status_msg);
+                if let Some(job_agent) = detail.agents.iter().find(|a| a.name == agent.name) {
+                    agent.stats.merge(&job_agent.stats);
+                    if job_agent.stats.runs > 0 {
+                        agent.last_run = Some(job.id.clone());
+                    }
+                }
             }
-            Err(e) => {
-                // Create failed job entry
-                self.jobs.insert(0, JobInfo {
-                    id: job_id.clone(),
-                    pack: pack_name,
-                    status: JobStatus::Failed,
-                    cycles: 0,
-                    facts: 0,
-                    created_at: chrono::Local::now().format("%Y-%m-%d %H:%M").to_string(),
+        }
+
+        if let Some(ref mut open) = self.job_detail {
+            if let Some(detail) = self.job_details_cache.get(&open.info.id) {
+                open.agents = detail.agents.clone();
+            }
+        }
+    }
+
+    /// The job selected in the Jobs table
+    fn selected_job_id(&self) -> Option<String> {
+        let idx = self.job_state.selected()?;
+        self.jobs.get(idx).map(|j| j.id.clone())
+    }
+
+    /// Cancel the selected job at its next cycle boundary
+    pub fn cancel_selected_job(&mut self) {
+        let Some(job_id) = self.selected_job_id() else {
+            return;
+        };
+        if let Some(ref remote) = self.remote {
+            remote.spawn_cancel(job_id.clone(), self.job_events_tx.clone());
+            self.status_message = Some(format!("Cancelling job {}...", job_id));
+            return;
+        }
+        self.status_message = Some(match self.job_controls.get(&job_id) {
+            Some(control) => {
+                control.cancel();
+                format!("Cancelling job {}...", job_id)
+            }
+            None => format!("Job {} is not running", job_id),
+        });
+    }
+
+    /// Pause the selected job at its next cycle boundary, or resume it
+    pub fn toggle_pause_selected_job(&mut self) {
+        if self.remote_unsupported("Pausing jobs") {
+            return;
+        }
+        let Some(job_id) = self.selected_job_id() else {
+            return;
+        };
+        let Some(control) = self.job_controls.get(&job_id).cloned() else {
+            self.status_message = Some(format!("Job {} is not running", job_id));
+            return;
+        };
+
+        if control.is_paused() {
+            control.resume();
+            self.update_job(&job_id, |detail| detail.info.status = JobStatus::Running);
+            self.status_message = Some(format!("Job {} resumed", job_id));
+        } else {
+            control.pause();
+            self.update_job(&job_id, |detail| detail.info.status = JobStatus::Paused);
+            self.status_message = Some(format!("Job {} paused", job_id));
+        }
+    }
+
+    /// Re-run the selected job as a new job with the same pack and seeds
+    pub fn rerun_selected_job(&mut self) {
+        if self.remote_unsupported("Re-running jobs") {
+            return;
+        }
+        let Some(job_id) = self.selected_job_id() else {
+            return;
+        };
+        let Some(detail) = self.job_details_cache.get(&job_id) else {
+            return;
+        };
+
+        let pack_name = detail.info.pack.clone();
+        let mut context = Context::new();
+        for seed in &detail.seeds {
+            if let Err(e) = context.add_fact(Fact::new(ContextKey::Seeds, &seed.id, &seed.content)) {
+                self.status_message = Some(format!("Failed to add seed: {}", e));
+                return;
+            }
+        }
+
+        let new_id = format!("job-{:03}", self.jobs.len() + 1);
+        let created_at = chrono::Local::now().to_rfc3339();
+        self.start_job(&new_id, &pack_name, context, &created_at, Vec::new());
+        self.job_state.select(Some(0));
+        self.status_message = Some(format!("Job {} re-run as {}", job_id, new_id));
+    }
+
+    /// Switch to remote mode: packs, jobs and agents come from the server
+    pub fn connect_remote(&mut self, client: RemoteClient) {
+        self.status_message = Some(format!("Connecting to {}...", client.base_url()));
+        self.remote = Some(client);
+        self.packs.clear();
+        self.agents.clear();
+        self.jobs.clear();
+        self.job_details_cache.clear();
+        self.refresh_remote(true);
+    }
+
+    /// Re-fetch the job list (and packs when `include_packs`)
+    pub fn refresh_remote(&mut self, include_packs: bool) {
+        if let Some(ref remote) = self.remote {
+            remote.spawn_refresh(self.job_events_tx.clone(), include_packs);
+            self.last_remote_refresh = Some(Instant::now());
+        }
+    }
+
+    /// Show a notice and return true if an action needs local jobs
+    fn remote_unsupported(&mut self, action: &str) -> bool {
+        if self.remote.is_some() {
+            self.status_message = Some(format!("{} is not supported in remote mode", action));
+        }
+        self.remote.is_some()
+    }
+
+    /// Agents of a pack: registered locally, or as listed by the server
+    fn agents_for_pack(&self, pack_name: &str) -> Vec<AgentInfo> {
+        if self.remote.is_none() {
+            return pack_agents(pack_name);
+        }
+        self.packs
+            .iter()
+            .find(|p| p.name == pack_name)
+            .map(|p| p.agents.iter().cloned().map(AgentInfo::ready).collect())
+            .unwrap_or_default()
+    }
+
+    /// Follow a remote job's progress stream unless already following it
+    fn follow_remote_job(&mut self, job_id: &str) {
+        let Some(ref remote) = self.remote else {
+            return;
+        };
+        if self.remote_streams.insert(job_id.to_string()) {
+            remote.spawn_stream(job_id.to_string(), self.job_events_tx.clone());
+        }
+    }
+
+    fn apply_remote_event(&mut self, event: RemoteEvent) {
+        match event {
+            RemoteEvent::Packs(packs) => {
+                self.packs = packs;
+                self.agents = self
+                    .packs
+                    .iter()
+                    .flat_map(|p| p.agents.iter())
+                    .collect::<std::collections::BTreeSet<_>>()
+                    .into_iter()
+                    .cloned()
+                    .map(AgentInfo::ready)
+                    .collect();
+                self.status_message = self.remote.as_ref().map(|r| format!("Connected to {}", r.base_url()));
+            }
+            RemoteEvent::Jobs(jobs) => {
+                for job in &jobs {
+                    let agents = self.agents_for_pack(&job.pack);
+                    self.job_details_cache
+                        .entry(job.id.clone())
+                        .and_modify(|detail| detail.info = job.clone())
+                        .or_insert_with(|| JobDetail {
+                            info: job.clone(),
+                            seeds: Vec::new(),
+                            facts: Vec::new(),
+                            agents,
+                            proposals: Vec::new(),
+                            selected_proposal: 0,
+                            audit: Vec::new(),
+                            provider: None,
+                        });
+                }
+                self.job_details_cache.retain(|id, _| jobs.iter().any(|j| j.id == *id));
+                for job in jobs.iter().filter(|j| matches!(j.status, JobStatus::Pending | JobStatus::Running)) {
+                    self.follow_remote_job(&job.id);
+                }
+                self.jobs = jobs;
+                let selected = self.job_state.selected().unwrap_or(0);
+                self.job_state
+                    .select((!self.jobs.is_empty()).then(|| selected.min(self.jobs.len() - 1)));
+            }
+            RemoteEvent::Detail { info, facts, agents } => {
+                let job_id = info.id.clone();
+                if self.job_detail.as_ref().is_some_and(|d| d.info.id == job_id) {
+                    self.context_facts = facts.clone();
+                }
+                let known = self.job_details_cache.contains_key(&job_id);
+                if !known {
+                    self.job_details_cache.insert(
+                        job_id.clone(),
+                        JobDetail {
+                            agents: self.agents_for_pack(&info.pack),
+                            info: info.clone(),
+                            seeds: Vec::new(),
+                            facts: Vec::new(),
+                            proposals: Vec::new(),
+                            selected_proposal: 0,
+                            audit: Vec::new(),
+                            provider: None,
+                        },
+                    );
+                }
+                self.update_job(&job_id, move |detail| {
+                    detail.info = info;
+                    detail.seeds = facts.iter().filter(|f| f.key == "Seeds").cloned().collect();
+                    detail.facts = facts;
+                    apply_agent_stats(detail, agents);
                 });
-                self.submit_form.error = Some(format!("Job failed: {}", e));
+            }
+            RemoteEvent::Submitted { job_id } => {
+                self.status_message = Some(format!("Job {} submitted", job_id));
+                self.follow_remote_job(&job_id);
+                self.refresh_remote(false);
+            }
+            RemoteEvent::Status { job_id, status, cycles } => {
+                self.update_job(&job_id, |detail| {
+                    detail.info.status = status;
+                    detail.info.cycles = cycles;
+                });
+                self.status_message = Some(format!("Job {} {} after {} cycles", job_id, status.as_str().to_lowercase(), cycles));
+                // The stream carries no agent statistics; fetch the final state
+                if let Some(ref remote) = self.remote {
+                    remote.spawn_detail(job_id, self.job_events_tx.clone());
+                }
+            }
+            RemoteEvent::StreamClosed { job_id } => {
+                self.remote_streams.remove(&job_id);
+            }
+            RemoteEvent::Error(e) => {
+                self.status_message = Some(format!("Remote: {}", e));
             }
         }
+    }
 
-        // Clear form
-        self.submit_form.pack.clear();
-        self.submit_form.seeds.clear();
-        self.submit_form.max_cycles = "50".to_string();
+    /// Insert or replace a job and its cached detail
+    fn store_job(&mut self, job: JobInfo, detail: JobDetail) {
+        if let Some(existing) = self.jobs.iter_mut().find(|j| j.id == job.id) {
+            *existing = job.clone();
+        } else {
+            self.jobs.insert(0, job.clone());
+        }
+        self.job_details_cache.insert(job.id.clone(), detail.clone());
+        self.job_detail = Some(detail);
+    }
+
+    /// Start reviewing the selected proposal in the job detail view
+    pub fn begin_review(&mut self, decision: ReviewDecision) {
+        if self.remote_unsupported("Reviewing proposals") {
+            return;
+        }
+        let Some(ref detail) = self.job_detail else {
+            return;
+        };
+        let Some(proposal) = detail.proposals.get(detail.selected_proposal) else {
+            self.status_message = Some("No proposals to review".to_string());
+            return;
+        };
+        if proposal.decision.is_some() {
+            self.status_message = Some(format!("Proposal {} already decided", proposal.id));
+            return;
+        }
+
+        self.review_input = Some(ReviewInput {
+            decision,
+            stage: if decision == ReviewDecision::Edit {
+                ReviewStage::Content
+            } else {
+                ReviewStage::Reason
+            },
+            content: proposal.content.clone(),
+            reason: String::new(),
+        });
+    }
+
+    /// Handle a key press while the review prompt is open
+    pub fn handle_review_key(&mut self, code: KeyCode) {
+        let Some(ref mut input) = self.review_input else {
+            return;
+        };
+        let field = match input.stage {
+            ReviewStage::Content => &mut input.content,
+            ReviewStage::Reason => &mut input.reason,
+        };
+
+        match code {
+            KeyCode::Esc => self.review_input = None,
+            // Approving with changes: edit the content first
+            KeyCode::Tab if input.decision == ReviewDecision::Approve => {
+                input.decision = ReviewDecision::Edit;
+                input.stage = ReviewStage::Content;
+            }
+            KeyCode::Char(c) => field.push(c),
+            KeyCode::Backspace => {
+                field.pop();
+            }
+            KeyCode::Enter => {
+                if input.stage == ReviewStage::Content {
+                    input.stage = ReviewStage::Reason;
+                } else if input.reason.trim().is_empty() {
+                    self.status_message = Some("A reason is required".to_string());
+                } else if let Some(input) = self.review_input.take() {
+                    self.record_decision(input);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Record a decision in the audit trail and resume the job once every
+    /// proposal has been decided
+    fn record_decision(&mut self, input: ReviewInput) {
+        let Some(mut detail) = self.job_detail.take() else {
+            return;
+        };
+        let Some(proposal) = detail.proposals.get_mut(detail.selected_proposal) else {
+            self.job_detail = Some(detail);
+            return;
+        };
+
+        proposal.decision = Some(input.decision);
+        let committed = match input.decision {
+            ReviewDecision::Approve => Some(proposal.content.clone()),
+            ReviewDecision::Edit => {
+                proposal.content = input.content.trim().to_string();
+                Some(proposal.content.clone())
+            }
+            ReviewDecision::Reject => None,
+        };
+        detail.audit.push(AuditEntry {
+            at: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            reviewer: std::env::var("USER").unwrap_or_else(|_| "unknown".to_string()),
+            proposal_id: proposal.id.clone(),
+            decision: input.decision,
+            reason: input.reason.trim().to_string(),
+            committed,
+        });
+        self.status_message = Some(format!("Proposal {} {}", proposal.id, input.decision.as_str()));
+
+        // Move to the next undecided proposal
+        if let Some(next) = detail.proposals.iter().position(|p| p.decision.is_none()) {
+            detail.selected_proposal = next;
+            self.job_details_cache.insert(detail.info.id.clone(), detail.clone());
+            self.job_detail = Some(detail);
+            return;
+        }
+
+        self.resume_job(detail);
+    }
+
+    /// Resume a paused job with the reviewed proposals applied
+    fn resume_job(&mut self, detail: JobDetail) {
+        let context = match reviewed_context(&detail) {
+            Ok(context) => context,
+            Err(e) => {
+                self.status_message = Some(format!("Failed to resume job: {}", e));
+                self.job_detail = Some(detail);
+                return;
+            }
+        };
+
+        let job_id = detail.info.id.clone();
+        self.start_job(&job_id, &detail.info.pack, context, &detail.info.created_at, detail.audit);
+        self.status_message = Some(format!("Job {} resumed with review decisions applied", job_id));
     }
 }
 
@@ -672,17 +1755,38 @@ pub async fn run_app(
     mut app: App,
 ) -> AppResult<()> {
     loop {
+        app.drain_job_events();
         terminal.draw(|f| super::views::draw(f, &mut app))?;
 
         if event::poll(Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
+            let event = event::read()?;
+            if let Event::Paste(ref text) = event {
+                app.handle_paste(text);
+            }
+            if let Event::Key(key) = event {
+                if key.kind == KeyEventKind::Press && app.review_input.is_some() {
+                    app.handle_review_key(key.code);
+                } else if key.kind == KeyEventKind::Press && app.context_browser.searching {
+                    app.handle_search_key(key.code);
+                } else if key.kind == KeyEventKind::Press
+                    && app.current_view == View::Submit
+                    && app.submit_form.seeds.is_capturing()
+                {
+                    app.handle_seed_key(key);
+                } else if key.kind == KeyEventKind::Press {
                     match key.code {
                         // Quit
+                        KeyCode::Esc if app.current_view == View::Context && app.context_browser.is_narrowed() => {
+                            app.clear_context_filters();
+                        }
                         KeyCode::Char('q') | KeyCode::Esc => {
-                            if app.current_view == View::JobDetail {
+                            if app.current_view == View::Provenance {
+                                app.provenance_back();
+                            } else if matches!(app.current_view, View::JobDetail | View::JobDiff) {
                                 app.navigate_back();
-                            } else if app.current_view == View::Submit && !app.submit_form.pack.is_empty() {
+                            } else if app.current_view == View::Submit
+                                && (!app.submit_form.pack.is_empty() || !app.submit_form.seeds.is_empty())
+                            {
                                 // Clear form on first Esc, quit on second
                                 app.submit_form = SubmitForm::new();
                             } else {
@@ -699,8 +1803,11 @@ pub async fn run_app(
                         KeyCode::Right => {
                             app.next_view();
                         }
+                        KeyCode::Left if app.current_view == View::Provenance => {
+                            app.provenance_back();
+                        }
                         KeyCode::Left => {
-                            if app.current_view == View::JobDetail {
+                            if matches!(app.current_view, View::JobDetail | View::JobDiff) {
                                 app.navigate_back();
                             } else {
                                 app.prev_view();
@@ -722,6 +1829,9 @@ pub async fn run_app(
                         KeyCode::Char('5') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                             app.goto_view(4);
                         }
+                        KeyCode::Char('6') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                            app.goto_view(5);
+                        }
                         // List navigation
                         KeyCode::Down | KeyCode::Char('j') => {
                             app.select_next();
@@ -738,14 +1848,84 @@ pub async fn run_app(
                                 View::Submit => {
                                     if app.submit_form.selected_field == 2 {
                                         app.submit_job();
+                                    } else if app.submit_form.selected_field == 1 {
+                                        app.submit_form.seeds.edit_selected();
                                     } else {
                                         app.submit_form.selected_field += 1;
                                     }
                                 }
+                                View::Context => {
+                                    app.toggle_context_group();
+                                }
+                                View::Evals => {
+                                    app.run_evals(false);
+                                }
+                                View::Provenance => {
+                                    app.follow_provenance();
+                                }
                                 _ => {}
                             }
                         }
+                        // Context browsing
+                        KeyCode::Char('/') if app.current_view == View::Context => {
+                            app.begin_context_search();
+                        }
+                        KeyCode::Char('f') if app.current_view == View::Context => {
+                            app.cycle_key_filter();
+                        }
+                        KeyCode::Char(' ') if app.current_view == View::Context => {
+                            app.toggle_context_group();
+                        }
+                        KeyCode::Char('p') if app.current_view == View::Context => {
+                            app.open_provenance();
+                        }
+                        // Evals
+                        KeyCode::Char(' ') if app.current_view == View::Evals => {
+                            app.evals.toggle_mark();
+                        }
+                        KeyCode::Char('a') if app.current_view == View::Evals => {
+                            app.run_evals(true);
+                        }
+                        KeyCode::Char('m') if app.current_view == View::Evals => {
+                            app.evals.force_mock = !app.evals.force_mock;
+                        }
+                        KeyCode::Char('r') if app.current_view == View::Evals => {
+                            app.evals.reload();
+                            app.status_message = Some(format!("Loaded {} eval fixture(s)", app.evals.fixtures.len()));
+                        }
+                        // Job actions
+                        KeyCode::Char('c') if app.current_view == View::Jobs => {
+                            app.cancel_selected_job();
+                        }
+                        KeyCode::Char('p') if app.current_view == View::Jobs => {
+                            app.toggle_pause_selected_job();
+                        }
+                        KeyCode::Char('r') if app.current_view == View::Jobs => {
+                            app.rerun_selected_job();
+                        }
+                        KeyCode::Char('m') if app.current_view == View::Jobs => {
+                            app.toggle_mark_selected_job();
+                        }
+                        KeyCode::Delete if app.current_view == View::Submit && app.submit_form.selected_field == 1 => {
+                            app.submit_form.seeds.delete_selected();
+                        }
+                        KeyCode::Char('d') if app.current_view == View::Jobs => {
+                            app.diff_marked_jobs();
+                        }
+                        // Proposal review
+                        KeyCode::Char('y') if app.current_view == View::JobDetail => {
+                            app.begin_review(ReviewDecision::Approve);
+                        }
+                        KeyCode::Char('n') if app.current_view == View::JobDetail => {
+                            app.begin_review(ReviewDecision::Reject);
+                        }
+                        KeyCode::Char('e') if app.current_view == View::JobDetail => {
+                            app.export_job_detail();
+                        }
                         // Back navigation
+                        KeyCode::Char('b') if app.current_view == View::Provenance => {
+                            app.provenance_back();
+                        }
                         KeyCode::Char('b') => {
                             if app.breadcrumb.len() > 1 {
                                 app.navigate_back();
@@ -765,11 +1945,214 @@ pub async fn run_app(
         }
 
         if !app.running {
+            app.cancel_all_jobs();
             return Ok(());
         }
     }
 }
 
+/// Headline of a job for a diff
+fn diff_side(detail: &JobDetail) -> RunSide {
+    RunSide {
+        label: detail.info.id.clone(),
+        converged: detail.info.status == JobStatus::Converged,
+        cycles: detail.info.cycles,
+        total_facts: detail.facts.len(),
+    }
+}
+
+/// A job in run output form (for reports)
+fn run_output(detail: &JobDetail) -> RunOutput {
+    let hostname = hostname::get()
+        .map(|h| h.to_string_lossy().to_string())
+        .unwrap_or_else(|_| "unknown".to_string());
+    let username = std::env::var("USER").unwrap_or_else(|_| "unknown".to_string());
+    let facts = fact_outputs(&detail.facts);
+    RunOutput {
+        run_id: detail.info.id.clone(),
+        correlation_id: detail.info.id.clone(),
+        timestamp: detail.info.created_at.clone(),
+        template: detail.info.pack.clone(),
+        actor: ActorInfo {
+            actor_type: "user".to_string(),
+            device_id: format!("tui:{}:{}", hostname, username),
+            cli_version: env!("CARGO_PKG_VERSION").to_string(),
+        },
+        provider: detail.provider.clone(),
+        result: RunResultOutput {
+            converged: detail.info.status == JobStatus::Converged,
+            cycles: detail.info.cycles,
+            total_facts: facts.len(),
+        },
+        context_hash: Some(ContextHash::of(&facts)),
+        facts,
+        review: detail
+            .proposals
+            .iter()
+            .filter(|p| p.decision.is_none())
+            .map(|p| ReviewItem {
+                id: p.id.clone(),
+                key: p.key.clone(),
+                agent: p.agent.clone(),
+                content: p.content.clone(),
+                confidence: p.confidence,
+                threshold: p.threshold,
+            })
+            .collect(),
+        decisions: detail
+            .audit
+            .iter()
+            .map(|entry| ReviewRecord {
+                at: entry.at.clone(),
+                reviewer: entry.reviewer.clone(),
+                id: entry.proposal_id.clone(),
+                decision: entry.decision.as_str().to_string(),
+                reason: entry.reason.clone(),
+                committed: entry.committed.clone(),
+            })
+            .collect(),
+        agents: detail.agents.iter().map(|a| a.stats.clone()).collect(),
+        invariants: Vec::new(),
+    }
+}
+
+/// A job's facts in run output form
+fn fact_outputs(facts: &[FactInfo]) -> Vec<FactOutput> {
+    facts
+        .iter()
+        .enumerate()
+        .map(|(i, fact)| FactOutput {
+            sequence: i + 1,
+            key: fact.key.clone(),
+            id: fact.id.clone(),
+            content: fact.content.clone(),
+            confidence: fact.confidence,
+            provenance: fact.provenance.clone(),
+        })
+        .collect()
+}
+
+/// Provenance tree of `root`: the fact, then each fact it was derived
+/// from, recursively down to seeds. A fact reached twice is shown again but
+/// not re-expanded (this also guards against cycles).
+fn provenance_rows(facts: &[FactInfo], root: &str) -> Vec<ProvenanceRow> {
+    let mut rows = Vec::new();
+    let mut expanded = std::collections::HashSet::new();
+    push_provenance(facts, root, String::new(), "", &mut expanded, &mut rows);
+    rows
+}
+
+fn push_provenance(
+    facts: &[FactInfo],
+    id: &str,
+    prefix: String,
+    indent: &str,
+    expanded: &mut std::collections::HashSet<String>,
+    rows: &mut Vec<ProvenanceRow>,
+) {
+    let fact = facts.iter().position(|f| f.id == id);
+    let repeated = !expanded.insert(id.to_string());
+    rows.push(ProvenanceRow {
+        prefix,
+        id: id.to_string(),
+        fact,
+        repeated,
+    });
+    if repeated {
+        return;
+    }
+
+    let sources = fact
+        .and_then(|i| facts[i].provenance.as_ref())
+        .map(|p| p.derived_from.as_slice())
+        .unwrap_or_default();
+    for (i, source) in sources.iter().enumerate() {
+        let last = i + 1 == sources.len();
+        let branch = if last { "└─ " } else { "├─ " };
+        let child_indent = format!("{}{}", indent, if last { "   " } else { "│  " });
+        push_provenance(facts, source, format!("{}{}", indent, branch), &child_indent, expanded, rows);
+    }
+}
+
+/// Context keys present in `facts`, in `ContextKey` order
+///
+/// Keys the engine does not know (shouldn't happen) sort last.
+fn present_keys(facts: &[FactInfo]) -> Vec<String> {
+    let order: Vec<String> = ContextKey::iter().map(|k| format!("{:?}", k)).collect();
+    let mut keys: Vec<String> = Vec::new();
+    for fact in facts {
+        if !keys.contains(&fact.key) {
+            keys.push(fact.key.clone());
+        }
+    }
+    keys.sort_by_key(|key| order.iter().position(|k| k == key).unwrap_or(usize::MAX));
+    keys
+}
+
+/// Group matching facts by key; collapsed groups show only their header
+fn context_rows(facts: &[FactInfo], browser: &ContextBrowser) -> Vec<ContextRow> {
+    let mut rows = Vec::new();
+    for key in present_keys(facts) {
+        let matching: Vec<usize> = facts
+            .iter()
+            .enumerate()
+            .filter(|(_, fact)| fact.key == key && browser.matches(fact))
+            .map(|(i, _)| i)
+            .collect();
+        if matching.is_empty() {
+            continue;
+        }
+        let collapsed = browser.collapsed.contains(&key);
+        rows.push(ContextRow::Group {
+            key,
+            count: matching.len(),
+            collapsed,
+        });
+        if !collapsed {
+            rows.extend(matching.into_iter().map(ContextRow::Fact));
+        }
+    }
+    rows
+}
+
+/// Agents of a pack, none of which has run yet
+fn pack_agents(pack_name: &str) -> Vec<AgentInfo> {
+    pack_agent_names(pack_name)
+        .unwrap_or_else(|_| get_pack_agents(pack_name))
+        .into_iter()
+        .map(AgentInfo::ready)
+        .collect()
+}
+
+/// Replace a job's agent statistics with a fresh snapshot
+fn apply_agent_stats(detail: &mut JobDetail, stats: Vec<AgentStat>) {
+    let status = job_agent_status(&detail.info.status);
+    for stat in stats {
+        let idx = match detail.agents.iter().position(|a| a.name == stat.name) {
+            Some(idx) => idx,
+            None => {
+                detail.agents.push(AgentInfo::ready(stat.name.clone()));
+                detail.agents.len() - 1
+            }
+        };
+        let agent = &mut detail.agents[idx];
+        if stat.runs > 0 {
+            agent.last_run = Some(detail.info.id.clone());
+        }
+        agent.status = status.to_string();
+        agent.stats = stat;
+    }
+}
+
+/// Status shown for a job's agents
+fn job_agent_status(status: &JobStatus) -> &'static str {
+    match status {
+        JobStatus::Pending | JobStatus::Running => "Running",
+        JobStatus::Paused => "Paused",
+        _ => "Idle",
+    }
+}
+
 /// Get the list of agents for a pack
 fn get_pack_agents(pack_name: &str) -> Vec<String> {
     match pack_name {
@@ -790,50 +2173,223 @@ fn get_pack_agents(pack_name: &str) -> Vec<String> {
     }
 }
 
-/// Creates an LLM provider from environment variables.
-fn create_llm_provider() -> Arc<dyn LlmProvider> {
-    tokio::task::block_in_place(|| {
-        // Try Anthropic first
-        if let Ok(provider) = AnthropicProvider::from_env("claude-sonnet-4-20250514") {
-            return Arc::new(provider) as Arc<dyn LlmProvider>;
+/// Rebuild a paused job's context with approved and edited proposals added
+fn reviewed_context(detail: &JobDetail) -> Result<Context, String> {
+    let approved = detail
+        .proposals
+        .iter()
+        .filter(|p| matches!(p.decision, Some(ReviewDecision::Approve | ReviewDecision::Edit)))
+        .map(|p| (p.key.as_str(), p.id.as_str(), p.content.as_str()));
+    let existing = detail
+        .facts
+        .iter()
+        .map(|f| (f.key.as_str(), f.id.as_str(), f.content.as_str()));
+
+    let mut context = Context::new();
+    for (key_name, id, content) in existing.chain(approved) {
+        let key = context_key_from_name(key_name)
+            .ok_or_else(|| format!("unknown context key '{}'", key_name))?;
+        context
+            .add_fact(Fact::new(key, id, content))
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(context)
+}
+
+/// Resolve a `ContextKey` from its debug name (e.g. "Hypotheses")
+fn context_key_from_name(name: &str) -> Option<ContextKey> {
+    ContextKey::iter().find(|key| format!("{:?}", key) == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fact(key: &str, id: &str, content: &str) -> FactInfo {
+        FactInfo {
+            key: key.to_string(),
+            id: id.to_string(),
+            content: content.to_string(),
+            confidence: None,
+            provenance: None,
+        }
+    }
+
+    /// A paused job with two proposals, so one decision does not resume it
+    fn paused_detail() -> JobDetail {
+        let proposal = |id: &str| ProposalInfo {
+            id: id.to_string(),
+            agent: "InsightAgent".to_string(),
+            key: "Hypotheses".to_string(),
+            content: format!("{} content", id),
+            confidence: Some(0.4),
+            threshold: 0.7,
+            decision: None,
+        };
+        JobDetail {
+            info: JobInfo {
+                id: "job-1".to_string(),
+                pack: "growth-strategy".to_string(),
+                status: JobStatus::Paused,
+                cycles: 2,
+                facts: 0,
+                created_at: String::new(),
+            },
+            seeds: Vec::new(),
+            facts: Vec::new(),
+            agents: Vec::new(),
+            proposals: vec![proposal("insight:1"), proposal("insight:2")],
+            selected_proposal: 0,
+            audit: Vec::new(),
+            provider: None,
         }
+    }
 
-        // Try OpenAI second
-        if let Ok(provider) = OpenAiProvider::from_env("gpt-4o") {
-            return Arc::new(provider) as Arc<dyn LlmProvider>;
+    fn review(app: &mut App, decision: ReviewDecision, keys: &[KeyCode], reason: &str) {
+        app.begin_review(decision);
+        for &key in keys {
+            app.handle_review_key(key);
+        }
+        for c in reason.chars() {
+            app.handle_review_key(KeyCode::Char(c));
         }
+        app.handle_review_key(KeyCode::Enter);
+    }
 
-        // Fall back to mock provider
-        Arc::new(MockInsightProvider::default_insights()) as Arc<dyn LlmProvider>
-    })
-}
+    #[test]
+    fn approving_commits_the_proposal_unchanged() {
+        let mut app = App::new();
+        app.job_detail = Some(paused_detail());
+        review(&mut app, ReviewDecision::Approve, &[KeyCode::Enter], "");
+        assert_eq!(app.status_message.as_deref(), Some("A reason is required"));
+
+        review(&mut app, ReviewDecision::Approve, &[], "Well grounded");
+        let detail = app.job_detail.as_ref().unwrap();
+        assert_eq!(detail.proposals[0].decision, Some(ReviewDecision::Approve));
+        assert_eq!(detail.selected_proposal, 1);
+        assert_eq!(detail.audit[0].reason, "Well grounded");
+        assert_eq!(detail.audit[0].committed.as_deref(), Some("insight:1 content"));
+    }
 
-/// Register agents and invariants for a specific domain pack.
-fn register_pack_agents(engine: &mut Engine, pack_name: &str) -> Result<()> {
-    match pack_name {
-        "growth-strategy" => {
-            // Register deterministic agents
-            engine.register(MarketSignalAgent);
-            engine.register(CompetitorAgent);
-            engine.register(StrategyAgent);
-            engine.register(EvaluationAgent);
+    #[test]
+    fn rejecting_commits_nothing_and_is_kept_in_the_run_output() {
+        let mut app = App::new();
+        app.job_detail = Some(paused_detail());
+        review(&mut app, ReviewDecision::Reject, &[], "Not grounded");
+
+        let detail = app.job_detail.as_ref().unwrap();
+        assert_eq!(detail.proposals[0].decision, Some(ReviewDecision::Reject));
+        assert_eq!(detail.audit[0].committed, None);
+
+        let output = run_output(detail);
+        assert_eq!(output.decisions.len(), 1);
+        assert_eq!(output.decisions[0].id, "insight:1");
+        assert_eq!(output.decisions[0].decision, "rejected");
+        assert_eq!(output.decisions[0].reason, "Not grounded");
+        let pending: Vec<&str> = output.review.iter().map(|item| item.id.as_str()).collect();
+        assert_eq!(pending, ["insight:2"]);
+    }
 
-            // Create LLM provider (shared by all LLM agents)
-            let llm_provider = create_llm_provider();
+    #[test]
+    fn tab_turns_an_approval_into_an_edit() {
+        let mut app = App::new();
+        app.job_detail = Some(paused_detail());
+        let edit: Vec<KeyCode> = [KeyCode::Tab, KeyCode::Backspace]
+            .into_iter()
+            .chain("t, Nordics only".chars().map(KeyCode::Char))
+            .chain([KeyCode::Enter])
+            .collect();
+        review(&mut app, ReviewDecision::Approve, &edit, "Narrowed scope");
 
-            // Register LLM-powered agents
-            engine.register(StrategicInsightAgent::new(llm_provider.clone()));
-            engine.register(RiskAssessmentAgent::new(llm_provider));
+        let detail = app.job_detail.as_ref().unwrap();
+        assert_eq!(detail.proposals[0].decision, Some(ReviewDecision::Edit));
+        assert_eq!(detail.proposals[0].content, "insight:1 content, Nordics only");
+        assert_eq!(detail.audit[0].committed.as_deref(), Some("insight:1 content, Nordics only"));
+        assert_eq!(run_output(detail).decisions[0].decision, "edited");
+    }
 
-            // Register Invariants
-            engine.register_invariant(BrandSafetyInvariant::default());
-            engine.register_invariant(RequireMultipleStrategies);
-            engine.register_invariant(RequireStrategyEvaluations);
-            engine.register_invariant(RequireEvaluationRationale);
-        }
-        _ => {
-            return Err(anyhow::anyhow!("Pack '{}' not implemented", pack_name));
-        }
+    #[test]
+    fn quitting_cancels_paused_jobs() {
+        let mut app = App::new();
+        let control = Arc::new(JobControl::new());
+        control.pause();
+        app.job_controls.insert("job-1".to_string(), control.clone());
+
+        let waiter = {
+            let control = control.clone();
+            std::thread::spawn(move || control.checkpoint())
+        };
+        app.cancel_all_jobs();
+
+        assert!(!waiter.join().unwrap());
+        assert!(!control.is_paused());
+    }
+
+    #[test]
+    fn context_rows_group_by_key_in_context_key_order() {
+        let facts = vec![
+            fact("Strategies", "strategy:1", "Expand to Nordics"),
+            fact("Seeds", "seed:1", "Nordic B2B SaaS"),
+            fact("Strategies", "strategy:2", "Partner channel"),
+        ];
+        let mut browser = ContextBrowser::default();
+
+        let rows = context_rows(&facts, &browser);
+        assert_eq!(rows[0], ContextRow::Group { key: "Seeds".to_string(), count: 1, collapsed: false });
+        assert_eq!(rows[1], ContextRow::Fact(1));
+        assert_eq!(rows[2], ContextRow::Group { key: "Strategies".to_string(), count: 2, collapsed: false });
+        assert_eq!(rows.len(), 5);
+
+        browser.collapsed.insert("Strategies".to_string());
+        let rows = context_rows(&facts, &browser);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[2], ContextRow::Group { key: "Strategies".to_string(), count: 2, collapsed: true });
+    }
+
+    #[test]
+    fn context_rows_apply_search_and_key_filter() {
+        let facts = vec![
+            fact("Seeds", "seed:nordic", "Market entry"),
+            fact("Strategies", "strategy:1", "Expand to NORDICS"),
+            fact("Strategies", "strategy:2", "Partner channel"),
+        ];
+        let mut browser = ContextBrowser {
+            query: "nordic".to_string(),
+            ..ContextBrowser::default()
+        };
+
+        // Matches id as well as content, case-insensitively
+        let rows = context_rows(&facts, &browser);
+        assert_eq!(rows.iter().filter(|r| matches!(r, ContextRow::Fact(_))).count(), 2);
+
+        browser.key_filter = Some("Strategies".to_string());
+        let rows = context_rows(&facts, &browser);
+        assert_eq!(rows, vec![
+            ContextRow::Group { key: "Strategies".to_string(), count: 1, collapsed: false },
+            ContextRow::Fact(1),
+        ]);
+    }
+
+    #[test]
+    fn provenance_rows_trace_sources_down_to_seeds() {
+        let derived = |id: &str, key: &str, agent: &str, sources: &[&str]| FactInfo {
+            provenance: Some(FactProvenance {
+                agent: agent.to_string(),
+                cycle: 1,
+                derived_from: sources.iter().map(|s| s.to_string()).collect(),
+            }),
+            ..fact(key, id, "")
+        };
+        let facts = vec![
+            fact("Seeds", "seed:a", "a"),
+            derived("signal:1", "Signals", "MarketSignalAgent", &["seed:a"]),
+            derived("strategy:1", "Strategies", "StrategyAgent", &["signal:1", "seed:a", "gone"]),
+        ];
+
+        let rows = provenance_rows(&facts, "strategy:1");
+        let lines: Vec<String> = rows.iter().map(|r| format!("{}{}", r.prefix, r.id)).collect();
+        assert_eq!(lines, ["strategy:1", "├─ signal:1", "│  └─ seed:a", "├─ seed:a", "└─ gone"]);
+        assert!(rows[3].repeated);
+        assert_eq!(rows[4].fact, None);
     }
-    Ok(())
 }
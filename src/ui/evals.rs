@@ -0,0 +1,222 @@
+//! Evals Tab
+//!
+//! Lists eval fixtures from the evals directory and tracks runs started
+//! from the TUI. Runs execute on a background worker (see
+//! `worker::spawn_evals`); each fixture moves through
+//! `Queued` → `Running` → `Finished` as progress events arrive.
+
+use ratatui::widgets::TableState;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use crate::evals::{self, EvalFixture, EvalResult};
+
+/// Default fixtures directory (same as `converge eval run`)
+pub const DEFAULT_EVALS_DIR: &str = "evals";
+
+/// State of one fixture's most recent TUI run
+#[derive(Debug, Clone)]
+pub enum EvalRunState {
+    Queued,
+    Running,
+    Finished(EvalResult),
+}
+
+impl EvalRunState {
+    pub fn label(&self) -> &'static str {
+        match self {
+            EvalRunState::Queued => "Queued",
+            EvalRunState::Running => "Running",
+            EvalRunState::Finished(result) if result.passed => "PASS",
+            EvalRunState::Finished(result) if result.skipped.is_some() => "SKIP",
+            EvalRunState::Finished(_) => "FAIL",
+        }
+    }
+}
+
+/// Fixtures, selection and run state of the Evals tab
+#[derive(Debug, Default)]
+pub struct EvalBrowser {
+    pub dir: String,
+    pub fixtures: Vec<EvalFixture>,
+    pub state: TableState,
+    /// Fixtures marked with Space for the next run
+    pub marked: HashSet<String>,
+    pub runs: HashMap<String, EvalRunState>,
+    /// Force the mock LLM for every fixture (otherwise each fixture decides)
+    pub force_mock: bool,
+    /// Error from the last fixture load
+    pub load_error: Option<String>,
+}
+
+impl EvalBrowser {
+    /// Creates a browser over `dir` and loads its fixtures
+    pub fn new(dir: impl Into<String>) -> Self {
+        let mut browser = Self {
+            dir: dir.into(),
+            force_mock: true,
+            ..Self::default()
+        };
+        browser.reload();
+        browser
+    }
+
+    /// Re-read fixtures from disk (keeps run results)
+    pub fn reload(&mut self) {
+        match evals::load_fixtures_from_dir(Path::new(&self.dir)) {
+            Ok(fixtures) => {
+                self.fixtures = fixtures;
+                self.load_error = None;
+            }
+            Err(e) => {
+                self.fixtures.clear();
+                self.load_error = Some(format!("{:#}", e));
+            }
+        }
+        self.marked.retain(|id| self.fixtures.iter().any(|f| f.eval_id == *id));
+        let selected = self.state.selected().unwrap_or(0);
+        self.state
+            .select((!self.fixtures.is_empty()).then(|| selected.min(self.fixtures.len() - 1)));
+    }
+
+    pub fn selected(&self) -> Option<&EvalFixture> {
+        self.fixtures.get(self.state.selected()?)
+    }
+
+    pub fn select_next(&mut self) {
+        let len = self.fixtures.len();
+        if len > 0 {
+            let i = self.state.selected().unwrap_or(0);
+            self.state.select(Some((i + 1) % len));
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        let len = self.fixtures.len();
+        if len > 0 {
+            let i = self.state.selected().unwrap_or(0);
+            self.state.select(Some(if i == 0 { len - 1 } else { i - 1 }));
+        }
+    }
+
+    /// Mark or unmark the highlighted fixture
+    pub fn toggle_mark(&mut self) {
+        let Some(id) = self.selected().map(|f| f.eval_id.clone()) else {
+            return;
+        };
+        if !self.marked.remove(&id) {
+            self.marked.insert(id);
+        }
+    }
+
+    /// True while any fixture is queued or running
+    pub fn is_running(&self) -> bool {
+        self.runs
+            .values()
+            .any(|run| matches!(run, EvalRunState::Queued | EvalRunState::Running))
+    }
+
+    /// Fixtures to run: the marked ones, or the highlighted one if none are
+    /// marked (all of them when `all`); marks them queued
+    pub fn take_batch(&mut self, all: bool) -> Vec<EvalFixture> {
+        let mut batch: Vec<EvalFixture> = if all {
+            self.fixtures.clone()
+        } else if self.marked.is_empty() {
+            self.selected().cloned().into_iter().collect()
+        } else {
+            self.fixtures
+                .iter()
+                .filter(|f| self.marked.contains(&f.eval_id))
+                .cloned()
+                .collect()
+        };
+        if self.force_mock {
+            for fixture in &mut batch {
+                fixture.use_mock_llm = true;
+            }
+        }
+        for fixture in &batch {
+            self.runs.insert(fixture.eval_id.clone(), EvalRunState::Queued);
+        }
+        batch
+    }
+
+    /// (passed, failed) among finished runs; skipped runs count as neither
+    pub fn totals(&self) -> (usize, usize) {
+        self.runs.values().fold((0, 0), |(p, f), run| match run {
+            EvalRunState::Finished(result) if result.passed => (p + 1, f),
+            EvalRunState::Finished(result) if result.skipped.is_some() => (p, f),
+            EvalRunState::Finished(_) => (p, f + 1),
+            _ => (p, f),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn browser(ids: &[&str]) -> EvalBrowser {
+        let mut browser = EvalBrowser {
+            fixtures: ids.iter().map(|id| EvalFixture::test(id)).collect(),
+            force_mock: true,
+            ..EvalBrowser::default()
+        };
+        browser.state.select(Some(0));
+        browser
+    }
+
+    fn ids(batch: &[EvalFixture]) -> Vec<&str> {
+        batch.iter().map(|f| f.eval_id.as_str()).collect()
+    }
+
+    #[test]
+    fn selection_wraps_around() {
+        let mut browser = browser(&["a", "b", "c"]);
+        browser.select_prev();
+        assert_eq!(browser.selected().unwrap().eval_id, "c");
+        browser.select_next();
+        browser.select_next();
+        assert_eq!(browser.selected().unwrap().eval_id, "b");
+
+        let mut empty = EvalBrowser::default();
+        empty.select_next();
+        assert!(empty.selected().is_none());
+    }
+
+    #[test]
+    fn batch_is_marked_fixtures_or_the_highlighted_one() {
+        let mut browser = browser(&["a", "b", "c"]);
+        assert_eq!(ids(&browser.take_batch(false)), ["a"]);
+
+        browser.select_next();
+        browser.toggle_mark();
+        browser.select_next();
+        browser.toggle_mark();
+        browser.toggle_mark();
+        assert_eq!(ids(&browser.take_batch(false)), ["b"]);
+
+        let batch = browser.take_batch(true);
+        assert_eq!(ids(&batch), ["a", "b", "c"]);
+        assert!(batch.iter().all(|f| f.use_mock_llm));
+        assert!(browser.is_running());
+        assert!(matches!(browser.runs["c"], EvalRunState::Queued));
+    }
+
+    #[test]
+    fn totals_count_finished_runs_only() {
+        let mut browser = browser(&["a", "b", "c", "d"]);
+        browser.runs.insert("a".to_string(), EvalRunState::Finished(EvalResult::test("a", true)));
+        browser.runs.insert("b".to_string(), EvalRunState::Finished(EvalResult::test("b", false)));
+        browser.runs.insert(
+            "c".to_string(),
+            EvalRunState::Finished(EvalResult::skipped("c", Uuid::new_v4(), "no credentials".to_string())),
+        );
+        browser.runs.insert("d".to_string(), EvalRunState::Running);
+
+        assert_eq!(browser.totals(), (1, 1));
+        assert_eq!(browser.runs["c"].label(), "SKIP");
+        assert!(browser.is_running());
+    }
+}
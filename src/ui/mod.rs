@@ -4,6 +4,10 @@
 //! allowing interactive job submission, monitoring, and context visualization.
 
 pub mod app;
+pub mod evals;
+pub mod remote;
+pub mod seeds;
 pub mod views;
+pub mod worker;
 
 pub use app::{run_app, App};
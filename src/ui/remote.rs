@@ -0,0 +1,437 @@
+//! Remote Mode
+//!
+//! `converge tui --remote <url>` drives the TUI from a running converge
+//! server instead of the in-process engine. The client talks to the
+//! server's job API:
+//!
+//! - `GET  /api/v1/packs` - available packs
+//! - `GET  /api/v1/jobs` - job list
+//! - `GET  /api/v1/jobs/{id}` - job with its context facts and agent stats
+//! - `POST /api/v1/jobs` - submit a job (`{pack, seeds, max_cycles}`)
+//! - `POST /api/v1/jobs/{id}/cancel` - cancel a job
+//! - `GET  /api/v1/jobs/{id}/stream` - live progress as server-sent events
+//!
+//! Stream events carry the same JSON objects as `converge run --stream
+//! --json` (`{"type":"fact",...}` / `{"type":"status",...}`). Request and
+//! response bodies are specified in `docs/REMOTE_API.md`. The API has no
+//! pause, re-run or review endpoints, so those actions stay local-only.
+//!
+//! Every call runs on a spawned task and reports back over the app's job
+//! event channel, so the UI never blocks on the network.
+
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::app::{FactInfo, JobInfo, JobStatus, PackInfo};
+use super::seeds::SeedRow;
+use super::worker::JobEvent;
+use crate::provenance::FactProvenance;
+use crate::telemetry::AgentStat;
+
+/// How often the job list is re-fetched
+pub const REMOTE_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Results of remote calls, delivered as `JobEvent::Remote`
+#[derive(Debug, Clone)]
+pub enum RemoteEvent {
+    Packs(Vec<PackInfo>),
+    Jobs(Vec<JobInfo>),
+    Detail {
+        info: JobInfo,
+        facts: Vec<FactInfo>,
+        agents: Vec<AgentStat>,
+    },
+    Submitted { job_id: String },
+    /// A streamed job reported its final status
+    Status { job_id: String, status: JobStatus, cycles: u32 },
+    /// A job's stream ended (it can be re-subscribed)
+    StreamClosed { job_id: String },
+    Error(String),
+}
+
+/// Pack as listed by the server
+#[derive(Debug, Deserialize)]
+struct RemotePack {
+    name: String,
+    #[serde(default)]
+    version: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    agents: Vec<String>,
+    #[serde(default)]
+    invariants: Vec<String>,
+}
+
+/// Job as listed by the server
+#[derive(Debug, Deserialize)]
+struct RemoteJob {
+    id: String,
+    pack: String,
+    status: String,
+    #[serde(default)]
+    cycles: u32,
+    #[serde(default)]
+    facts: usize,
+    #[serde(default)]
+    created_at: String,
+}
+
+impl From<RemoteJob> for JobInfo {
+    fn from(job: RemoteJob) -> Self {
+        JobInfo {
+            id: job.id,
+            pack: job.pack,
+            status: JobStatus::parse(&job.status),
+            cycles: job.cycles,
+            facts: job.facts,
+            created_at: job.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RemoteFact {
+    key: String,
+    id: String,
+    content: String,
+    #[serde(default)]
+    confidence: Option<f64>,
+    #[serde(default)]
+    provenance: Option<FactProvenance>,
+}
+
+/// Job detail returned by `GET /api/v1/jobs/{id}`
+#[derive(Debug, Deserialize)]
+struct RemoteJobDetail {
+    #[serde(flatten)]
+    job: RemoteJob,
+    #[serde(default, rename = "context")]
+    facts: Vec<RemoteFact>,
+    #[serde(default)]
+    agents: Vec<AgentStat>,
+}
+
+#[derive(Debug, Serialize)]
+struct SubmitRequest<'a> {
+    pack: &'a str,
+    seeds: &'a [SeedRow],
+    max_cycles: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct SubmitResponse {
+    id: String,
+}
+
+/// One event of a job's progress stream
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum StreamEvent {
+    Cycle {
+        cycle: u32,
+    },
+    Fact {
+        cycle: u32,
+        key: String,
+        id: String,
+        content: String,
+    },
+    Status {
+        cycle: u32,
+        status: String,
+        cycles: u32,
+    },
+}
+
+/// HTTP client for a converge server
+#[derive(Debug, Clone)]
+pub struct RemoteClient {
+    base_url: String,
+    http: reqwest::Client,
+}
+
+impl RemoteClient {
+    /// Creates a client for `base_url` (e.g. `http://localhost:8080`)
+    pub fn new(base_url: &str) -> Result<Self> {
+        let base_url = base_url.trim_end_matches('/').to_string();
+        if !base_url.starts_with("http://") && !base_url.starts_with("https://") {
+            anyhow::bail!("Remote URL must start with http:// or https://, got '{}'", base_url);
+        }
+        let http = reqwest::Client::builder()
+            .connect_timeout(Duration::from_secs(5))
+            .build()
+            .context("Failed to create HTTP client")?;
+        Ok(Self { base_url, http })
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/api/v1{}", self.base_url, path)
+    }
+
+    async fn get<T: serde::de::DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let url = self.url(path);
+        let response = self
+            .http
+            .get(&url)
+            .timeout(Duration::from_secs(10))
+            .send()
+            .await
+            .with_context(|| format!("GET {} failed", url))?
+            .error_for_status()
+            .with_context(|| format!("GET {} failed", url))?;
+        response
+            .json()
+            .await
+            .with_context(|| format!("Unexpected response from {}", url))
+    }
+
+    async fn packs(&self) -> Result<Vec<PackInfo>> {
+        let packs: Vec<RemotePack> = self.get("/packs").await?;
+        Ok(packs
+            .into_iter()
+            .map(|p| PackInfo {
+                name: p.name,
+                version: p.version,
+                description: p.description,
+                agents: p.agents,
+                invariants: p.invariants,
+            })
+            .collect())
+    }
+
+    async fn jobs(&self) -> Result<Vec<JobInfo>> {
+        let jobs: Vec<RemoteJob> = self.get("/jobs").await?;
+        Ok(jobs.into_iter().map(JobInfo::from).collect())
+    }
+
+    async fn detail(&self, job_id: &str) -> Result<RemoteEvent> {
+        let detail: RemoteJobDetail = self.get(&format!("/jobs/{}", job_id)).await?;
+        Ok(RemoteEvent::Detail {
+            info: detail.job.into(),
+            facts: detail
+                .facts
+                .into_iter()
+                .map(|f| FactInfo {
+                    key: f.key,
+                    id: f.id,
+                    content: f.content,
+                    confidence: f.confidence,
+                    provenance: f.provenance,
+                })
+                .collect(),
+            agents: detail.agents,
+        })
+    }
+
+    async fn submit(&self, pack: &str, seeds: &[SeedRow], max_cycles: u32) -> Result<String> {
+        let url = self.url("/jobs");
+        let response: SubmitResponse = self
+            .http
+            .post(&url)
+            .timeout(Duration::from_secs(10))
+            .json(&SubmitRequest { pack, seeds, max_cycles })
+            .send()
+            .await
+            .with_context(|| format!("POST {} failed", url))?
+            .error_for_status()
+            .with_context(|| format!("POST {} failed", url))?
+            .json()
+            .await
+            .with_context(|| format!("Unexpected response from {}", url))?;
+        Ok(response.id)
+    }
+
+    async fn cancel(&self, job_id: &str) -> Result<()> {
+        let url = self.url(&format!("/jobs/{}/cancel", job_id));
+        self.http
+            .post(&url)
+            .timeout(Duration::from_secs(10))
+            .send()
+            .await
+            .with_context(|| format!("POST {} failed", url))?
+            .error_for_status()
+            .with_context(|| format!("POST {} failed", url))?;
+        Ok(())
+    }
+
+    /// Fetch packs and the job list
+    pub fn spawn_refresh(&self, tx: UnboundedSender<JobEvent>, include_packs: bool) {
+        let client = self.clone();
+        tokio::spawn(async move {
+            if include_packs {
+                send(&tx, client.packs().await.map(RemoteEvent::Packs));
+            }
+            send(&tx, client.jobs().await.map(RemoteEvent::Jobs));
+        });
+    }
+
+    /// Fetch one job's facts and agent statistics
+    pub fn spawn_detail(&self, job_id: String, tx: UnboundedSender<JobEvent>) {
+        let client = self.clone();
+        tokio::spawn(async move {
+            send(&tx, client.detail(&job_id).await);
+        });
+    }
+
+    /// Submit a job; its id comes back as `RemoteEvent::Submitted`
+    pub fn spawn_submit(&self, pack: String, seeds: Vec<SeedRow>, max_cycles: u32, tx: UnboundedSender<JobEvent>) {
+        let client = self.clone();
+        tokio::spawn(async move {
+            let result = client.submit(&pack, &seeds, max_cycles).await;
+            send(&tx, result.map(|job_id| RemoteEvent::Submitted { job_id }));
+        });
+    }
+
+    /// Ask the server to cancel a job
+    pub fn spawn_cancel(&self, job_id: String, tx: UnboundedSender<JobEvent>) {
+        let client = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = client.cancel(&job_id).await {
+                send(&tx, Err(e));
+            }
+        });
+    }
+
+    /// Follow a job's progress stream, forwarding cycles and facts as the
+    /// same `JobEvent`s local workers send
+    pub fn spawn_stream(&self, job_id: String, tx: UnboundedSender<JobEvent>) {
+        let client = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = client.stream(&job_id, &tx).await {
+                send(&tx, Err(e));
+            }
+            let _ = tx.send(JobEvent::Remote(RemoteEvent::StreamClosed { job_id }));
+        });
+    }
+
+    async fn stream(&self, job_id: &str, tx: &UnboundedSender<JobEvent>) -> Result<()> {
+        let url = self.url(&format!("/jobs/{}/stream", job_id));
+        let mut response = self
+            .http
+            .get(&url)
+            .header("Accept", "text/event-stream")
+            .send()
+            .await
+            .with_context(|| format!("GET {} failed", url))?
+            .error_for_status()
+            .with_context(|| format!("GET {} failed", url))?;
+
+        let mut buffer = Vec::new();
+        while let Some(chunk) = response.chunk().await.context("Stream interrupted")? {
+            buffer.extend_from_slice(&chunk);
+            for line in complete_lines(&mut buffer) {
+                if let Some(event) = parse_stream_line(&line) {
+                    forward(job_id, event, tx);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn send(tx: &UnboundedSender<JobEvent>, result: Result<RemoteEvent>) {
+    let event = result.unwrap_or_else(|e| RemoteEvent::Error(format!("{:#}", e)));
+    let _ = tx.send(JobEvent::Remote(event));
+}
+
+/// Take the complete lines out of `buffer`, leaving a trailing partial
+/// line. Lines are decoded only once complete, so a character split across
+/// network chunks is not mangled.
+fn complete_lines(buffer: &mut Vec<u8>) -> Vec<String> {
+    let Some(last_newline) = buffer.iter().rposition(|&b| b == b'\n') else {
+        return Vec::new();
+    };
+    let mut complete: Vec<u8> = buffer.drain(..=last_newline).collect();
+    complete.pop();
+    complete
+        .split(|&b| b == b'\n')
+        .map(|line| String::from_utf8_lossy(line).into_owned())
+        .collect()
+}
+
+/// Parse one line of the stream: an SSE `data:` line or a bare JSON line.
+/// Comments, other SSE fields and blank lines are skipped.
+fn parse_stream_line(line: &str) -> Option<StreamEvent> {
+    let line = line.trim();
+    let payload = line.strip_prefix("data:").map_or(line, str::trim_start);
+    if !payload.starts_with('{') {
+        return None;
+    }
+    serde_json::from_str(payload).ok()
+}
+
+fn forward(job_id: &str, event: StreamEvent, tx: &UnboundedSender<JobEvent>) {
+    let job_id = job_id.to_string();
+    let event = match event {
+        StreamEvent::Cycle { cycle } => JobEvent::Cycle { job_id, cycle },
+        StreamEvent::Fact { cycle, key, id, content } => JobEvent::Fact {
+            job_id,
+            cycle,
+            fact: FactInfo {
+                key,
+                id,
+                content,
+                confidence: None,
+                provenance: None,
+            },
+        },
+        StreamEvent::Status { status, cycles, .. } => JobEvent::Remote(RemoteEvent::Status {
+            job_id,
+            status: JobStatus::parse(&status),
+            cycles,
+        }),
+    };
+    let _ = tx.send(event);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_sse_and_json_lines() {
+        let fact = parse_stream_line(r#"data: {"cycle":1,"type":"fact","key":"Seeds","id":"seed-1","content":"x"}"#);
+        assert!(matches!(fact, Some(StreamEvent::Fact { cycle: 1, .. })));
+
+        let status = parse_stream_line(
+            r#"{"cycle":3,"type":"status","status":"converged","converged":true,"cycles":3,"facts":5}"#,
+        );
+        assert!(matches!(status, Some(StreamEvent::Status { cycles: 3, .. })));
+
+        assert!(parse_stream_line(": keep-alive").is_none());
+        assert!(parse_stream_line("event: fact").is_none());
+        assert!(parse_stream_line("").is_none());
+    }
+
+    #[test]
+    fn lines_split_inside_a_character_decode_intact() {
+        let line = "data: {\"type\":\"fact\",\"cycle\":1,\"key\":\"Seeds\",\"id\":\"s\",\"content\":\"Malmö\"}\n";
+        let split = line.find('ö').unwrap() + 1;
+        let mut buffer = Vec::new();
+
+        buffer.extend_from_slice(&line.as_bytes()[..split]);
+        assert!(complete_lines(&mut buffer).is_empty());
+        buffer.extend_from_slice(&line.as_bytes()[split..]);
+        let lines = complete_lines(&mut buffer);
+
+        assert!(buffer.is_empty());
+        assert!(matches!(
+            parse_stream_line(&lines[0]),
+            Some(StreamEvent::Fact { ref content, .. }) if content == "Malmö"
+        ));
+    }
+
+    #[test]
+    fn rejects_urls_without_scheme() {
+        assert!(RemoteClient::new("localhost:8080").is_err());
+        let client = RemoteClient::new("http://localhost:8080/").unwrap();
+        assert_eq!(client.url("/jobs"), "http://localhost:8080/api/v1/jobs");
+    }
+}
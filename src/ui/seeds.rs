@@ -0,0 +1,527 @@
+//! Seed Editor
+//!
+//! Structured editing of a job's seeds in the Submit view. Seeds are rows
+//! of id/content that can be:
+//!
+//! - Added, edited (with cursor movement) and deleted
+//! - Pasted (a JSON seed array, or one seed per line)
+//! - Loaded from a JSON file (the same format as `converge run --seeds @file`)
+//! - Saved and loaded as presets per pack under `.converge/presets/<pack>/`
+//!
+//! The editor produces the same `Vec<SeedFact>` the raw JSON field did.
+
+use anyhow::{Context as _, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use converge_runtime::templates::SeedFact;
+
+/// Default preset location, relative to the working directory.
+pub const DEFAULT_PRESETS_DIR: &str = ".converge/presets";
+
+/// One seed row
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeedRow {
+    pub id: String,
+    pub content: String,
+}
+
+/// Field of a seed row being edited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeedField {
+    Id,
+    Content,
+}
+
+/// In-progress edit of a seed row
+#[derive(Debug, Clone)]
+pub struct SeedEdit {
+    /// Row being edited (`None` for a new row)
+    pub row: Option<usize>,
+    pub field: SeedField,
+    pub id: String,
+    pub content: String,
+    /// Cursor position in the focused field, in characters
+    pub cursor: usize,
+}
+
+impl SeedEdit {
+    fn focused(&mut self) -> &mut String {
+        match self.field {
+            SeedField::Id => &mut self.id,
+            SeedField::Content => &mut self.content,
+        }
+    }
+
+    fn focus(&mut self, field: SeedField) {
+        self.field = field;
+        self.cursor = self.focused().chars().count();
+    }
+
+    fn insert(&mut self, text: &str) {
+        // Ids are single-line; content keeps its line breaks
+        let text = match self.field {
+            SeedField::Id => text.replace(['\n', '\r'], " "),
+            SeedField::Content => text.replace("\r\n", "\n").replace('\r', "\n"),
+        };
+        let cursor = self.cursor;
+        let field = self.focused();
+        let at = byte_index(field, cursor);
+        field.insert_str(at, &text);
+        self.cursor += text.chars().count();
+    }
+
+    fn backspace(&mut self) {
+        if self.cursor == 0 {
+            return;
+        }
+        let cursor = self.cursor;
+        let field = self.focused();
+        let at = byte_index(field, cursor - 1);
+        field.remove(at);
+        self.cursor -= 1;
+    }
+
+    fn delete(&mut self) {
+        let cursor = self.cursor;
+        let field = self.focused();
+        if cursor < field.chars().count() {
+            let at = byte_index(field, cursor);
+            field.remove(at);
+        }
+    }
+}
+
+/// Byte offset of the `chars`-th character of `s`
+fn byte_index(s: &str, chars: usize) -> usize {
+    s.char_indices().nth(chars).map_or(s.len(), |(i, _)| i)
+}
+
+/// Which one-line prompt is open
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeedPromptKind {
+    LoadFile,
+    SavePreset,
+    LoadPreset,
+}
+
+impl SeedPromptKind {
+    pub fn title(&self) -> &'static str {
+        match self {
+            SeedPromptKind::LoadFile => "Load seeds from file",
+            SeedPromptKind::SavePreset => "Save preset as",
+            SeedPromptKind::LoadPreset => "Load preset",
+        }
+    }
+}
+
+/// One-line prompt for a file path or preset name
+#[derive(Debug, Clone)]
+pub struct SeedPrompt {
+    pub kind: SeedPromptKind,
+    pub input: String,
+}
+
+/// Seed rows plus the edit or prompt currently open
+#[derive(Debug, Clone, Default)]
+pub struct SeedEditor {
+    pub rows: Vec<SeedRow>,
+    pub selected: usize,
+    pub edit: Option<SeedEdit>,
+    pub prompt: Option<SeedPrompt>,
+    /// Feedback from the last action (load/save/paste)
+    pub message: Option<String>,
+}
+
+impl SeedEditor {
+    /// True while an edit or prompt should receive all keys
+    pub fn is_capturing(&self) -> bool {
+        self.edit.is_some() || self.prompt.is_some()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Move the selection; returns false when already at the edge
+    pub fn select_next(&mut self) -> bool {
+        if self.selected + 1 < self.rows.len() {
+            self.selected += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Move the selection; returns false when already at the edge
+    pub fn select_prev(&mut self) -> bool {
+        if self.selected > 0 {
+            self.selected -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Start editing a new row
+    pub fn add(&mut self) {
+        self.edit = Some(SeedEdit {
+            row: None,
+            field: SeedField::Id,
+            id: String::new(),
+            content: String::new(),
+            cursor: 0,
+        });
+    }
+
+    /// Start editing the selected row (or a new one if there are none)
+    pub fn edit_selected(&mut self) {
+        let Some(row) = self.rows.get(self.selected) else {
+            self.add();
+            return;
+        };
+        let mut edit = SeedEdit {
+            row: Some(self.selected),
+            field: SeedField::Content,
+            id: row.id.clone(),
+            content: row.content.clone(),
+            cursor: 0,
+        };
+        edit.focus(SeedField::Content);
+        self.edit = Some(edit);
+    }
+
+    pub fn delete_selected(&mut self) {
+        if self.selected < self.rows.len() {
+            let row = self.rows.remove(self.selected);
+            self.message = Some(format!("Deleted seed '{}'", row.id));
+            self.selected = self.selected.min(self.rows.len().saturating_sub(1));
+        }
+    }
+
+    pub fn open_prompt(&mut self, kind: SeedPromptKind) {
+        self.prompt = Some(SeedPrompt {
+            kind,
+            input: String::new(),
+        });
+    }
+
+    /// Handle a key while an edit is open
+    pub fn handle_edit_key(&mut self, key: KeyEvent) {
+        let Some(ref mut edit) = self.edit else {
+            return;
+        };
+        match key.code {
+            KeyCode::Esc => self.edit = None,
+            KeyCode::Enter if key.modifiers.contains(KeyModifiers::ALT) && edit.field == SeedField::Content => {
+                edit.insert("\n");
+            }
+            KeyCode::Enter => self.commit_edit(),
+            KeyCode::Tab | KeyCode::BackTab => {
+                let next = match edit.field {
+                    SeedField::Id => SeedField::Content,
+                    SeedField::Content => SeedField::Id,
+                };
+                edit.focus(next);
+            }
+            KeyCode::Left => edit.cursor = edit.cursor.saturating_sub(1),
+            KeyCode::Right => {
+                let len = edit.focused().chars().count();
+                edit.cursor = (edit.cursor + 1).min(len);
+            }
+            KeyCode::Home => edit.cursor = 0,
+            KeyCode::End => edit.cursor = edit.focused().chars().count(),
+            KeyCode::Backspace => edit.backspace(),
+            KeyCode::Delete => edit.delete(),
+            KeyCode::Char(c) => edit.insert(c.encode_utf8(&mut [0; 4])),
+            _ => {}
+        }
+    }
+
+    /// Validate and store the open edit
+    fn commit_edit(&mut self) {
+        let Some(ref edit) = self.edit else {
+            return;
+        };
+        let id = edit.id.trim().to_string();
+        if id.is_empty() {
+            self.message = Some("Seed id is required".to_string());
+            return;
+        }
+        if self
+            .rows
+            .iter()
+            .enumerate()
+            .any(|(i, row)| row.id == id && Some(i) != edit.row)
+        {
+            self.message = Some(format!("Duplicate seed id '{}'", id));
+            return;
+        }
+
+        let row = SeedRow {
+            id,
+            content: edit.content.clone(),
+        };
+        match edit.row {
+            Some(i) => self.rows[i] = row,
+            None => {
+                self.rows.push(row);
+                self.selected = self.rows.len() - 1;
+            }
+        }
+        self.edit = None;
+        self.message = None;
+    }
+
+    /// Handle pasted text: into the open edit or prompt, otherwise as new rows
+    pub fn paste(&mut self, text: &str) {
+        if let Some(ref mut edit) = self.edit {
+            edit.insert(text);
+        } else if let Some(ref mut prompt) = self.prompt {
+            prompt.input.push_str(text.trim());
+        } else {
+            let rows = parse_pasted(text, self.rows.len());
+            self.message = Some(format!("Pasted {} seed(s)", rows.len()));
+            self.append(rows);
+        }
+    }
+
+    /// Append rows, renaming ids that would clash with existing rows
+    fn append(&mut self, rows: Vec<SeedRow>) {
+        for mut row in rows {
+            if self.rows.iter().any(|r| r.id == row.id) {
+                row.id = format!("{}-{}", row.id, self.rows.len() + 1);
+            }
+            self.rows.push(row);
+        }
+        self.selected = self.rows.len().saturating_sub(1);
+    }
+
+    /// Handle a key while a prompt is open; `pack` scopes presets
+    pub fn handle_prompt_key(&mut self, code: KeyCode, pack: &str) {
+        let Some(ref mut prompt) = self.prompt else {
+            return;
+        };
+        match code {
+            KeyCode::Esc => self.prompt = None,
+            KeyCode::Char(c) => prompt.input.push(c),
+            KeyCode::Backspace => {
+                prompt.input.pop();
+            }
+            KeyCode::Enter => {
+                let Some(prompt) = self.prompt.take() else {
+                    return;
+                };
+                let presets = PresetStore::default();
+                let input = prompt.input.trim();
+                let result = match prompt.kind {
+                    SeedPromptKind::LoadFile => load_seed_file(Path::new(input)).map(|rows| {
+                        self.rows = rows;
+                        self.selected = 0;
+                        format!("Loaded {} seed(s) from {}", self.rows.len(), input)
+                    }),
+                    SeedPromptKind::SavePreset => presets
+                        .save(pack, input, &self.rows)
+                        .map(|path| format!("Saved preset to {}", path.display())),
+                    SeedPromptKind::LoadPreset => presets.load(pack, input).map(|rows| {
+                        self.rows = rows;
+                        self.selected = 0;
+                        format!("Loaded preset '{}'", input)
+                    }),
+                };
+                self.message = Some(result.unwrap_or_else(|e| format!("{:#}", e)));
+            }
+            _ => {}
+        }
+    }
+
+    /// The seeds as submitted to the engine
+    pub fn seed_facts(&self) -> Result<Vec<SeedFact>> {
+        // Round-trip through the JSON format accepted by `--seeds`
+        let json = serde_json::to_value(&self.rows)?;
+        Ok(serde_json::from_value(json)?)
+    }
+}
+
+/// Parse pasted text: a JSON seed array, or one seed per non-empty line
+/// (`id: content` or bare content with a generated id)
+fn parse_pasted(text: &str, existing: usize) -> Vec<SeedRow> {
+    if let Ok(rows) = serde_json::from_str::<Vec<SeedRow>>(text.trim()) {
+        return rows;
+    }
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .enumerate()
+        .map(|(i, line)| match line.split_once(": ") {
+            Some((id, content)) if !id.contains(' ') => SeedRow {
+                id: id.to_string(),
+                content: content.to_string(),
+            },
+            _ => SeedRow {
+                id: format!("seed-{}", existing + i + 1),
+                content: line.to_string(),
+            },
+        })
+        .collect()
+}
+
+/// Load a JSON seed array from a file
+pub fn load_seed_file(path: &Path) -> Result<Vec<SeedRow>> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read '{}'", path.display()))?;
+    serde_json::from_str(&content).with_context(|| format!("'{}' is not a JSON seed array", path.display()))
+}
+
+/// Saved seed presets, one JSON seed array per pack and name
+#[derive(Debug, Clone)]
+pub struct PresetStore {
+    dir: PathBuf,
+}
+
+impl Default for PresetStore {
+    fn default() -> Self {
+        Self::new(DEFAULT_PRESETS_DIR)
+    }
+}
+
+impl PresetStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path_for(&self, pack: &str, name: &str) -> Result<PathBuf> {
+        if pack.is_empty() {
+            anyhow::bail!("Enter a pack before using presets");
+        }
+        if !is_file_name(pack) {
+            anyhow::bail!("Invalid pack name '{}'", pack);
+        }
+        if !is_file_name(name) {
+            anyhow::bail!("Invalid preset name '{}'", name);
+        }
+        Ok(self.dir.join(pack).join(format!("{}.json", name)))
+    }
+
+    pub fn save(&self, pack: &str, name: &str, rows: &[SeedRow]) -> Result<PathBuf> {
+        let path = self.path_for(pack, name)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create '{}'", parent.display()))?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(rows)?)
+            .with_context(|| format!("Failed to write '{}'", path.display()))?;
+        Ok(path)
+    }
+
+    pub fn load(&self, pack: &str, name: &str) -> Result<Vec<SeedRow>> {
+        load_seed_file(&self.path_for(pack, name)?)
+    }
+
+    /// Preset names saved for a pack, sorted
+    pub fn list(&self, pack: &str) -> Vec<String> {
+        if !is_file_name(pack) {
+            return Vec::new();
+        }
+        let Ok(entries) = fs::read_dir(self.dir.join(pack)) else {
+            return Vec::new();
+        };
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .filter_map(|path| path.file_stem()?.to_str().map(String::from))
+            .collect();
+        names.sort();
+        names
+    }
+}
+
+/// Whether `name` is a single path component that stays inside its
+/// directory (no separators, no `.`/`..` or hidden files)
+fn is_file_name(name: &str) -> bool {
+    !name.is_empty() && !name.contains(['/', '\\']) && !name.starts_with('.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn type_text(editor: &mut SeedEditor, text: &str) {
+        for c in text.chars() {
+            editor.handle_edit_key(key(KeyCode::Char(c)));
+        }
+    }
+
+    #[test]
+    fn add_and_edit_rows_with_cursor_movement() {
+        let mut editor = SeedEditor::default();
+        editor.add();
+        type_text(&mut editor, "market");
+        editor.handle_edit_key(key(KeyCode::Tab));
+        type_text(&mut editor, "Nordic SaaS");
+        editor.handle_edit_key(key(KeyCode::Home));
+        type_text(&mut editor, "B2B ");
+        editor.handle_edit_key(key(KeyCode::Enter));
+
+        assert!(editor.edit.is_none());
+        assert_eq!(editor.rows, vec![SeedRow {
+            id: "market".to_string(),
+            content: "B2B Nordic SaaS".to_string(),
+        }]);
+
+        // Duplicate ids are rejected and the edit stays open
+        editor.add();
+        type_text(&mut editor, "market");
+        editor.handle_edit_key(key(KeyCode::Enter));
+        assert!(editor.edit.is_some());
+        assert_eq!(editor.rows.len(), 1);
+    }
+
+    #[test]
+    fn paste_accepts_json_arrays_and_lines() {
+        let mut editor = SeedEditor::default();
+        editor.paste(r#"[{"id": "company", "content": "Fintech"}]"#);
+        editor.paste("goal: Grow 50% YoY\nEnter Germany\n");
+
+        let ids: Vec<&str> = editor.rows.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["company", "goal", "seed-3"]);
+        assert_eq!(editor.rows[2].content, "Enter Germany");
+    }
+
+    #[test]
+    fn paste_into_content_keeps_line_breaks() {
+        let mut editor = SeedEditor::default();
+        editor.add();
+        editor.paste("id\nwith break");
+        editor.handle_edit_key(key(KeyCode::Tab));
+        editor.paste("line one\r\nline two");
+        editor.handle_edit_key(key(KeyCode::Enter));
+
+        assert_eq!(editor.rows[0].id, "id with break");
+        assert_eq!(editor.rows[0].content, "line one\nline two");
+    }
+
+    #[test]
+    fn preset_paths_stay_inside_the_store() {
+        let store = PresetStore::new("presets");
+        assert_eq!(
+            store.path_for("growth-strategy", "nordic").unwrap(),
+            Path::new("presets").join("growth-strategy").join("nordic.json")
+        );
+        for (pack, name) in [("..", "nordic"), ("../x", "nordic"), ("a\\b", "nordic"), ("growth-strategy", "../x")] {
+            assert!(store.path_for(pack, name).is_err(), "{}/{}", pack, name);
+        }
+        assert!(store.list("..").is_empty());
+    }
+}
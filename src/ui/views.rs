@@ -4,13 +4,18 @@
 //! It handles the visual presentation of:
 //!
 //! - Jobs list with status
+//! - Side-by-side diff of two jobs
 //! - Job detail with context and agents
 //! - Packs list with descriptions
-//! - Submit form
-//! - Context facts visualization
-//! - Agent status display
+//! - Submit form with seed editor
+//! - Context facts browser (search, key filter, grouping)
+//! - Agent status and run statistics
+//! - Eval fixtures with expected vs actual checks
 
-use super::app::{App, JobStatus, View};
+use super::app::{App, ContextRow, FactInfo, JobStatus, ReviewDecision, ReviewStage, View};
+use super::evals::EvalRunState;
+use super::seeds::{SeedEditor, SeedField};
+use crate::telemetry::AgentStat;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -19,6 +24,57 @@ use ratatui::{
     Frame,
 };
 
+/// Formats an optional confidence as a percentage
+fn format_confidence(confidence: Option<f64>) -> String {
+    confidence.map_or_else(|| "n/a".to_string(), |c| format!("{:.0}%", c * 100.0))
+}
+
+/// Colour for a confidence value: green when high, red when low
+fn confidence_color(confidence: Option<f64>) -> Color {
+    match confidence {
+        Some(c) if c >= 0.75 => Color::Green,
+        Some(c) if c >= 0.5 => Color::Yellow,
+        Some(_) => Color::Red,
+        None => Color::DarkGray,
+    }
+}
+
+/// Mean LLM latency, or "-" for agents that made no LLM calls
+/// Job creation time (RFC 3339) in local time, to the minute
+fn format_created_at(created_at: &str) -> String {
+    chrono::DateTime::parse_from_rfc3339(created_at).map_or_else(
+        |_| created_at.to_string(),
+        |t| t.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string(),
+    )
+}
+
+fn format_latency(stat: &AgentStat) -> String {
+    stat.mean_llm_latency_ms()
+        .map_or_else(|| "-".to_string(), |ms| format!("{}ms", ms))
+}
+
+/// Facts per context key, e.g. "Hypotheses:3 Constraints:1"
+fn format_facts_by_key(stat: &AgentStat) -> String {
+    if stat.facts_by_key.is_empty() {
+        return "-".to_string();
+    }
+    stat.facts_by_key
+        .iter()
+        .map(|(key, count)| format!("{}:{}", key, count))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Colour for an agent status
+fn agent_status_color(status: &str) -> Color {
+    match status {
+        "Running" => Color::Yellow,
+        "Paused" => Color::Magenta,
+        "Idle" => Color::Gray,
+        _ => Color::Green,
+    }
+}
+
 /// Main draw function - renders the entire UI
 pub fn draw(f: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
@@ -43,8 +99,7 @@ fn draw_tabs(f: &mut Frame, app: &App, area: Rect) {
         .enumerate()
         .map(|(i, v)| {
             let num = format!("[{}] ", i + 1);
-            let style = if *v == app.current_view ||
-                       (app.current_view == View::JobDetail && *v == View::Jobs) {
+            let style = if *v == app.current_view.tab() {
                 Style::default()
                     .fg(Color::Yellow)
                     .add_modifier(Modifier::BOLD)
@@ -58,18 +113,17 @@ fn draw_tabs(f: &mut Frame, app: &App, area: Rect) {
         })
         .collect();
 
+    let title = match app.remote {
+        Some(ref remote) => format!(" Converge @ {}  [Tab or Ctrl+1-6 to switch] ", remote.base_url()),
+        None => " Converge  [Tab or Ctrl+1-6 to switch] ".to_string(),
+    };
     let tabs = Tabs::new(titles)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(" Converge  [Tab or Ctrl+1-5 to switch] "),
-        )
+        .block(Block::default().borders(Borders::ALL).title(title))
         .highlight_style(Style::default().fg(Color::Yellow))
         .select(
             View::all()
                 .iter()
-                .position(|v| *v == app.current_view ||
-                         (app.current_view == View::JobDetail && *v == View::Jobs))
+                .position(|v| *v == app.current_view.tab())
                 .unwrap_or(0),
         );
 
@@ -117,6 +171,9 @@ fn draw_main(f: &mut Frame, app: &mut App, area: Rect) {
         View::Submit => draw_submit(f, app, area),
         View::Context => draw_context(f, app, area),
         View::Agents => draw_agents(f, app, area),
+        View::Evals => draw_evals(f, app, area),
+        View::JobDiff => draw_job_diff(f, app, area),
+        View::Provenance => draw_provenance(f, app, area),
     }
 }
 
@@ -124,7 +181,12 @@ fn draw_jobs(f: &mut Frame, app: &mut App, area: Rect) {
     let selected_idx = app.job_state.selected().unwrap_or(0);
     let total = app.jobs.len();
 
-    let title = format!(" Jobs ({}/{}) [Enter to view details] ", selected_idx + 1, total);
+    let active = app.active_jobs();
+    let title = if active > 0 {
+        format!(" Jobs ({}/{}, {} running) [Enter to view details] ", selected_idx + 1, total, active)
+    } else {
+        format!(" Jobs ({}/{}) [Enter to view details] ", selected_idx + 1, total)
+    };
 
     let header = Row::new(vec![
         Cell::from("ID").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
@@ -155,17 +217,19 @@ fn draw_jobs(f: &mut Frame, app: &mut App, area: Rect) {
                 JobStatus::Failed => Style::default().fg(Color::Red),
                 JobStatus::Paused => Style::default().fg(Color::Magenta),
                 JobStatus::Pending => Style::default().fg(Color::Gray),
+                JobStatus::Cancelled => Style::default().fg(Color::DarkGray),
             };
 
             let prefix = if selected { "▶ " } else { "  " };
+            let mark = if app.marked_jobs.contains(&job.id) { "*" } else { "" };
 
             Row::new(vec![
-                Cell::from(format!("{}{}", prefix, job.id)).style(row_style),
+                Cell::from(format!("{}{}{}", prefix, job.id, mark)).style(row_style),
                 Cell::from(job.pack.clone()).style(row_style),
                 Cell::from(job.status.as_str()).style(if selected { row_style } else { status_style }),
                 Cell::from(format!("{}", job.cycles)).style(row_style),
                 Cell::from(format!("{}", job.facts)).style(row_style),
-                Cell::from(job.created_at.clone()).style(row_style),
+                Cell::from(format_created_at(&job.created_at)).style(row_style),
             ])
             .style(row_style)
         })
@@ -221,7 +285,9 @@ fn draw_job_detail(f: &mut Frame, app: &mut App, area: Rect) {
                 JobStatus::Converged => Color::Green,
                 JobStatus::Running => Color::Yellow,
                 JobStatus::Failed => Color::Red,
-                _ => Color::White,
+                JobStatus::Paused => Color::Magenta,
+                JobStatus::Pending => Color::White,
+                JobStatus::Cancelled => Color::DarkGray,
             })),
         ]),
         Line::from(vec![
@@ -243,11 +309,18 @@ fn draw_job_detail(f: &mut Frame, app: &mut App, area: Rect) {
         .facts
         .iter()
         .map(|fact| {
+            let mut header = vec![
+                Span::styled(&fact.key, Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(format!(" [{}]", fact.id), Style::default().fg(Color::DarkGray)),
+            ];
+            if fact.confidence.is_some() {
+                header.push(Span::styled(
+                    format!(" {}", format_confidence(fact.confidence)),
+                    Style::default().fg(confidence_color(fact.confidence)),
+                ));
+            }
             ListItem::new(vec![
-                Line::from(vec![
-                    Span::styled(&fact.key, Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
-                    Span::styled(format!(" [{}]", fact.id), Style::default().fg(Color::DarkGray)),
-                ]),
+                Line::from(header),
                 Line::from(Span::styled(&fact.content, Style::default().fg(Color::White))),
             ])
         })
@@ -257,10 +330,14 @@ fn draw_job_detail(f: &mut Frame, app: &mut App, area: Rect) {
         .block(Block::default().borders(Borders::ALL).title(format!(" Facts ({}) ", detail.facts.len())));
     f.render_widget(facts_list, left_chunks[1]);
 
-    // Right side: Agents + Proposals
+    // Right side: Agents + Proposals + Audit trail
     let right_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .constraints([
+            Constraint::Percentage(35),
+            Constraint::Percentage(45),
+            Constraint::Percentage(20),
+        ])
         .split(chunks[1]);
 
     // Agents
@@ -268,15 +345,26 @@ fn draw_job_detail(f: &mut Frame, app: &mut App, area: Rect) {
         .agents
         .iter()
         .map(|agent| {
-            let status_color = if agent.status == "Running" { Color::Yellow } else { Color::Green };
+            let stat = &agent.stats;
+            let last_cycle = stat.last_cycle.map_or_else(|| "-".to_string(), |c| c.to_string());
+            let error_color = if stat.errors > 0 { Color::Red } else { Color::Gray };
             ListItem::new(vec![
                 Line::from(vec![
                     Span::styled(&agent.name, Style::default().fg(Color::White).add_modifier(Modifier::BOLD)),
-                    Span::styled(format!(" [{}]", agent.status), Style::default().fg(status_color)),
+                    Span::styled(format!(" [{}]", agent.status), Style::default().fg(agent_status_color(&agent.status))),
+                ]),
+                Line::from(vec![
+                    Span::styled("Runs: ", Style::default().fg(Color::Gray)),
+                    Span::styled(format!("{}", stat.runs), Style::default().fg(Color::White)),
+                    Span::styled("  Last cycle: ", Style::default().fg(Color::Gray)),
+                    Span::styled(last_cycle, Style::default().fg(Color::White)),
+                    Span::styled("  LLM: ", Style::default().fg(Color::Gray)),
+                    Span::styled(format_latency(stat), Style::default().fg(Color::White)),
+                    Span::styled(format!("  Errors: {}", stat.errors), Style::default().fg(error_color)),
                 ]),
                 Line::from(vec![
-                    Span::styled("Facts produced: ", Style::default().fg(Color::Gray)),
-                    Span::styled(format!("{}", agent.facts_produced), Style::default().fg(Color::White)),
+                    Span::styled(format!("Facts ({}): ", stat.facts_produced()), Style::default().fg(Color::Gray)),
+                    Span::styled(format_facts_by_key(stat), Style::default().fg(Color::Cyan)),
                 ]),
             ])
         })
@@ -286,28 +374,107 @@ fn draw_job_detail(f: &mut Frame, app: &mut App, area: Rect) {
         .block(Block::default().borders(Borders::ALL).title(format!(" Agents ({}) ", detail.agents.len())));
     f.render_widget(agents_list, right_chunks[0]);
 
-    // Proposals
+    // Proposals (with the review prompt underneath while one is open)
+    let proposal_area = if app.review_input.is_some() {
+        let split = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(4)])
+            .split(right_chunks[1]);
+        draw_review_prompt(f, app, split[1]);
+        split[0]
+    } else {
+        right_chunks[1]
+    };
+
     let proposal_items: Vec<ListItem> = detail
         .proposals
         .iter()
-        .map(|prop| {
+        .enumerate()
+        .map(|(i, prop)| {
+            let selected = i == detail.selected_proposal;
+            let prefix = if selected { "▶ " } else { "  " };
+            let (decision, decision_color) = match prop.decision {
+                None => ("pending", Color::Yellow),
+                Some(ReviewDecision::Approve) => ("approved", Color::Green),
+                Some(ReviewDecision::Edit) => ("edited", Color::Cyan),
+                Some(ReviewDecision::Reject) => ("rejected", Color::Red),
+            };
+            let key_style = if selected {
+                Style::default().bg(Color::Blue).fg(Color::White).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)
+            };
             ListItem::new(vec![
                 Line::from(vec![
-                    Span::styled(&prop.key, Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)),
-                    Span::styled(format!(" by {}", prop.agent), Style::default().fg(Color::DarkGray)),
+                    Span::styled(format!("{}{}", prefix, prop.key), key_style),
+                    Span::styled(format!(" [{}] by {}", prop.id, prop.agent), Style::default().fg(Color::DarkGray)),
+                    Span::styled(format!(" {}", decision), Style::default().fg(decision_color)),
                 ]),
-                Line::from(Span::styled(&prop.content, Style::default().fg(Color::White))),
+                Line::from(Span::styled(format!("  {}", prop.content), Style::default().fg(Color::White))),
                 Line::from(vec![
-                    Span::styled("Confidence: ", Style::default().fg(Color::Gray)),
-                    Span::styled(format!("{:.0}%", prop.confidence * 100.0), Style::default().fg(Color::Yellow)),
+                    Span::styled("  Confidence: ", Style::default().fg(Color::Gray)),
+                    Span::styled(format_confidence(prop.confidence), Style::default().fg(confidence_color(prop.confidence))),
                 ]),
             ])
         })
         .collect();
 
+    let pending = detail.proposals.iter().filter(|p| p.decision.is_none()).count();
     let proposals_list = List::new(proposal_items)
-        .block(Block::default().borders(Borders::ALL).title(format!(" Proposals ({}) [y/n to approve/reject] ", detail.proposals.len())));
-    f.render_widget(proposals_list, right_chunks[1]);
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            " Proposals ({} pending) [y/n to approve/reject] ",
+            pending
+        )));
+    f.render_widget(proposals_list, proposal_area);
+
+    // Audit trail
+    let audit_items: Vec<ListItem> = detail
+        .audit
+        .iter()
+        .rev()
+        .map(|entry| {
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{} ", entry.at), Style::default().fg(Color::DarkGray)),
+                Span::styled(format!("{} ", entry.proposal_id), Style::default().fg(Color::White)),
+                Span::styled(entry.decision.as_str(), Style::default().fg(Color::Yellow)),
+                Span::styled(format!(" by {}: {}", entry.reviewer, entry.reason), Style::default().fg(Color::Gray)),
+            ]))
+        })
+        .collect();
+
+    let audit_list = List::new(audit_items)
+        .block(Block::default().borders(Borders::ALL).title(format!(" Audit Trail ({}) ", detail.audit.len())));
+    f.render_widget(audit_list, right_chunks[2]);
+}
+
+fn draw_review_prompt(f: &mut Frame, app: &App, area: Rect) {
+    let Some(ref input) = app.review_input else {
+        return;
+    };
+
+    let (label, value) = match input.stage {
+        ReviewStage::Content => ("Edited content", input.content.as_str()),
+        ReviewStage::Reason => ("Reason", input.reason.as_str()),
+    };
+
+    let prompt = Paragraph::new(vec![
+        Line::from(vec![
+            Span::styled(format!("{}: ", label), Style::default().fg(Color::Gray)),
+            Span::styled(format!("{}_", value), Style::default().fg(Color::White)),
+        ]),
+    ])
+    .wrap(Wrap { trim: false })
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow))
+            .title(if input.decision == ReviewDecision::Approve {
+                format!(" {} proposal [Enter:Confirm  Tab:Edit content  Esc:Cancel] ", input.decision.as_str())
+            } else {
+                format!(" {} proposal [Enter:Confirm  Esc:Cancel] ", input.decision.as_str())
+            }),
+    );
+    f.render_widget(prompt, area);
 }
 
 fn draw_packs(f: &mut Frame, app: &mut App, area: Rect) {
@@ -379,8 +546,8 @@ fn draw_submit(f: &mut Frame, app: &mut App, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3), // Pack field
-            Constraint::Length(5), // Seeds field
+            Constraint::Length(3),  // Pack field
+            Constraint::Length(12), // Seeds editor
             Constraint::Length(3), // Max cycles field
             Constraint::Length(3), // Status/error
             Constraint::Min(0),    // Help
@@ -402,19 +569,8 @@ fn draw_submit(f: &mut Frame, app: &mut App, area: Rect) {
             .border_style(pack_style));
     f.render_widget(pack_input, chunks[0]);
 
-    // Seeds field
-    let seeds_style = if form.selected_field == 1 {
-        Style::default().fg(Color::Yellow)
-    } else {
-        Style::default()
-    };
-    let seeds_input = Paragraph::new(form.seeds.as_str())
-        .block(Block::default()
-            .borders(Borders::ALL)
-            .title(" Seeds (JSON) ")
-            .border_style(seeds_style))
-        .wrap(Wrap { trim: false });
-    f.render_widget(seeds_input, chunks[1]);
+    // Seeds editor
+    draw_seed_editor(f, &form.seeds, form.selected_field == 1, chunks[1]);
 
     // Max cycles field
     let cycles_style = if form.selected_field == 2 {
@@ -449,41 +605,570 @@ fn draw_submit(f: &mut Frame, app: &mut App, area: Rect) {
         Line::from(Span::styled("    - sdr-pipeline", Style::default().fg(Color::Cyan))),
         Line::from(""),
         Line::from(Span::styled("  ↑/↓: Navigate fields  Enter: Submit  Esc: Clear", Style::default().fg(Color::DarkGray))),
+        Line::from(Span::styled(
+            "  Seeds: a:Add  e/Enter:Edit  d:Delete  o:Load file  s:Save preset  l:Load preset  Paste: add rows",
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::from(Span::styled(
+            "  Editing: Tab:Id/Content  ←/→/Home/End:Move  Alt+Enter:New line  Enter:Save  Esc:Cancel",
+            Style::default().fg(Color::DarkGray),
+        )),
     ])
     .block(Block::default().borders(Borders::ALL).title(" Submit Job "));
     f.render_widget(help, chunks[4]);
 }
 
+/// Text of a field with a block cursor at `cursor` (in characters)
+fn with_cursor(text: &str, cursor: usize) -> Vec<Span<'static>> {
+    let at = text.char_indices().nth(cursor).map_or(text.len(), |(i, _)| i);
+    let (before, rest) = text.split_at(at);
+    let mut chars = rest.chars();
+    let under = chars.next().map_or(" ".to_string(), |c| if c == '\n' { " ".to_string() } else { c.to_string() });
+    vec![
+        Span::raw(before.replace('\n', "⏎")),
+        Span::styled(under, Style::default().bg(Color::Yellow).fg(Color::Black)),
+        Span::raw(chars.as_str().replace('\n', "⏎")),
+    ]
+}
+
+fn draw_seed_editor(f: &mut Frame, seeds: &SeedEditor, focused: bool, area: Rect) {
+    let border_style = if focused {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default()
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" Seeds ({}) ", seeds.rows.len()))
+        .border_style(border_style);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    // Rows on top, edit/prompt/message line at the bottom
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .split(inner);
+
+    let items: Vec<ListItem> = if seeds.rows.is_empty() {
+        vec![ListItem::new(Span::styled(
+            "No seeds. Press a to add, o to load a file, or paste.",
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        seeds
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let selected = focused && i == seeds.selected;
+                let style = if selected {
+                    Style::default().bg(Color::Blue).fg(Color::White)
+                } else {
+                    Style::default()
+                };
+                let prefix = if selected { "▶ " } else { "  " };
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{}{:<16}", prefix, row.id), style.fg(Color::Cyan)),
+                    Span::styled(row.content.replace('\n', " ⏎ "), style),
+                ]))
+            })
+            .collect()
+    };
+    f.render_widget(List::new(items), chunks[0]);
+
+    let footer: Vec<Line> = if let Some(ref edit) = seeds.edit {
+        let field_line = |label: &str, field: SeedField, text: &str| {
+            let mut spans = vec![Span::styled(format!("{:<9}", label), Style::default().fg(Color::Gray))];
+            if edit.field == field {
+                spans.extend(with_cursor(text, edit.cursor));
+            } else {
+                spans.push(Span::raw(text.replace('\n', "⏎")));
+            }
+            Line::from(spans)
+        };
+        vec![
+            field_line("Id:", SeedField::Id, &edit.id),
+            field_line("Content:", SeedField::Content, &edit.content),
+            Line::from(Span::styled(
+                seeds.message.clone().unwrap_or_default(),
+                Style::default().fg(Color::Red),
+            )),
+        ]
+    } else if let Some(ref prompt) = seeds.prompt {
+        vec![
+            Line::from(vec![
+                Span::styled(format!("{}: ", prompt.kind.title()), Style::default().fg(Color::Yellow)),
+                Span::raw(prompt.input.clone()),
+                Span::styled(" ", Style::default().bg(Color::Yellow)),
+            ]),
+            Line::from(Span::styled(seeds.message.clone().unwrap_or_default(), Style::default().fg(Color::Gray))),
+        ]
+    } else {
+        vec![Line::from(Span::styled(seeds.message.clone().unwrap_or_default(), Style::default().fg(Color::Gray)))]
+    };
+    f.render_widget(Paragraph::new(footer).wrap(Wrap { trim: false }), chunks[1]);
+}
+
 fn draw_context(f: &mut Frame, app: &mut App, area: Rect) {
-    let items: Vec<ListItem> = app
-        .context_facts
+    let browser = &app.context_browser;
+    let show_search = browser.searching || !browser.query.is_empty();
+
+    // Search bar (while searching or a query is set) above list + detail
+    let rows_area = if show_search {
+        let split = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+        let cursor = if browser.searching { "_" } else { "" };
+        let search = Paragraph::new(Line::from(vec![
+            Span::styled("/", Style::default().fg(Color::Yellow)),
+            Span::styled(format!("{}{}", browser.query, cursor), Style::default().fg(Color::White)),
+        ]))
+        .block(Block::default().borders(Borders::ALL).title(" Search "));
+        f.render_widget(search, split[0]);
+        split[1]
+    } else {
+        area
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+        .split(rows_area);
+
+    let rows = app.context_rows();
+    let shown = rows.iter().filter(|row| matches!(row, ContextRow::Fact(_))).count();
+    let items: Vec<ListItem> = rows
         .iter()
         .enumerate()
-        .map(|(i, fact)| {
+        .map(|(i, row)| {
             let selected = app.fact_state.selected() == Some(i);
             let style = if selected {
                 Style::default().bg(Color::Blue).fg(Color::White)
             } else {
                 Style::default()
             };
-            let prefix = if selected { "▶ " } else { "  " };
-            ListItem::new(vec![
-                Line::from(vec![
-                    Span::styled(format!("{}{}", prefix, fact.key), style.add_modifier(Modifier::BOLD).fg(Color::Cyan)),
-                    Span::styled(format!(" [{}]", fact.id), Style::default().fg(Color::DarkGray)),
-                ]),
-                Line::from(Span::styled(format!("  {}", fact.content), style)),
-                Line::from(vec![
-                    Span::styled("  Confidence: ", Style::default().fg(Color::Gray)),
-                    Span::styled(format!("{:.0}%", fact.confidence * 100.0), Style::default().fg(Color::Yellow)),
-                ]),
-            ])
+            match row {
+                ContextRow::Group { key, count, collapsed } => {
+                    let marker = if *collapsed { "▸" } else { "▾" };
+                    ListItem::new(Line::from(vec![
+                        Span::styled(format!("{} {}", marker, key), style.add_modifier(Modifier::BOLD).fg(Color::Cyan)),
+                        Span::styled(format!(" ({})", count), Style::default().fg(Color::DarkGray)),
+                    ]))
+                }
+                ContextRow::Fact(idx) => {
+                    let fact = &app.context_facts[*idx];
+                    ListItem::new(Line::from(vec![
+                        Span::styled(format!("    {}", fact.id), style.fg(Color::White)),
+                        Span::styled(
+                            format!(" {}", format_confidence(fact.confidence)),
+                            Style::default().fg(confidence_color(fact.confidence)),
+                        ),
+                    ]))
+                }
+            }
+        })
+        .collect();
+
+    let mut title = format!(" Context Facts ({}/{}) ", shown, app.context_facts.len());
+    if let Some(ref key) = app.context_browser.key_filter {
+        title = format!("{}[key: {}] ", title, key);
+    }
+    let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+    f.render_stateful_widget(list, chunks[0], &mut app.fact_state);
+
+    // Detail pane: full content of the selected fact
+    let detail = match app.selected_context_fact() {
+        Some(fact) => vec![
+            Line::from(vec![
+                Span::styled("Key: ", Style::default().fg(Color::Gray)),
+                Span::styled(fact.key.clone(), Style::default().fg(Color::Cyan)),
+            ]),
+            Line::from(vec![
+                Span::styled("ID: ", Style::default().fg(Color::Gray)),
+                Span::styled(fact.id.clone(), Style::default().fg(Color::White)),
+            ]),
+            Line::from(vec![
+                Span::styled("Confidence: ", Style::default().fg(Color::Gray)),
+                Span::styled(format_confidence(fact.confidence), Style::default().fg(confidence_color(fact.confidence))),
+            ]),
+            Line::from(vec![
+                Span::styled("Produced by: ", Style::default().fg(Color::Gray)),
+                Span::styled(format_provenance(fact), Style::default().fg(Color::Magenta)),
+            ]),
+            Line::from(""),
+            Line::from(fact.content.clone()),
+        ],
+        None if rows.is_empty() && app.context_browser.is_narrowed() => {
+            vec![Line::from(Span::styled("No facts match (Esc to clear)", Style::default().fg(Color::DarkGray)))]
+        }
+        None => vec![Line::from(Span::styled(
+            "Select a fact to see its content (Enter/Space collapses a group)",
+            Style::default().fg(Color::DarkGray),
+        ))],
+    };
+    let detail_pane = Paragraph::new(detail)
+        .wrap(Wrap { trim: false })
+        .block(Block::default().borders(Borders::ALL).title(" Fact "));
+    f.render_widget(detail_pane, chunks[1]);
+}
+
+/// Producing agent and cycle of a fact, with its number of sources
+fn format_provenance(fact: &FactInfo) -> String {
+    match fact.provenance {
+        Some(ref p) => format!("{} (cycle {}, {} source fact(s))", p.agent, p.cycle, p.derived_from.len()),
+        None if fact.key == "Seeds" => "seed".to_string(),
+        None => "unknown".to_string(),
+    }
+}
+
+fn draw_provenance(f: &mut Frame, app: &mut App, area: Rect) {
+    let rows = app.provenance_rows();
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+        .split(area);
+
+    let items: Vec<ListItem> = rows
+        .iter()
+        .map(|row| {
+            let mut spans = vec![
+                Span::styled(row.prefix.clone(), Style::default().fg(Color::DarkGray)),
+                Span::styled(row.id.clone(), Style::default().fg(Color::White)),
+            ];
+            match row.fact.map(|i| &app.context_facts[i]) {
+                Some(fact) => {
+                    spans.push(Span::styled(format!(" [{}]", fact.key), Style::default().fg(Color::Cyan)));
+                    if let Some(ref p) = fact.provenance {
+                        spans.push(Span::styled(format!(" {} @{}", p.agent, p.cycle), Style::default().fg(Color::Magenta)));
+                    }
+                }
+                None => spans.push(Span::styled(" (not in context)", Style::default().fg(Color::Red))),
+            }
+            if row.repeated {
+                spans.push(Span::styled(" (see above)", Style::default().fg(Color::DarkGray)));
+            }
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
+    let title = format!(
+        " Provenance of {} ({} step(s) back: b) ",
+        app.provenance_root.as_deref().unwrap_or("-"),
+        app.provenance_trail.len()
+    );
     let list = List::new(items)
-        .block(Block::default().borders(Borders::ALL).title(format!(" Context Facts ({}) ", app.context_facts.len())));
-    f.render_stateful_widget(list, area, &mut app.fact_state);
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(Style::default().bg(Color::Blue).fg(Color::White));
+    f.render_stateful_widget(list, chunks[0], &mut app.provenance_state);
+
+    let selected = app
+        .provenance_state
+        .selected()
+        .and_then(|i| rows.get(i))
+        .and_then(|row| row.fact)
+        .map(|i| &app.context_facts[i]);
+    let detail = match selected {
+        Some(fact) => vec![
+            Line::from(vec![
+                Span::styled("Key: ", Style::default().fg(Color::Gray)),
+                Span::styled(fact.key.clone(), Style::default().fg(Color::Cyan)),
+            ]),
+            Line::from(vec![
+                Span::styled("ID: ", Style::default().fg(Color::Gray)),
+                Span::styled(fact.id.clone(), Style::default().fg(Color::White)),
+            ]),
+            Line::from(vec![
+                Span::styled("Produced by: ", Style::default().fg(Color::Gray)),
+                Span::styled(format_provenance(fact), Style::default().fg(Color::Magenta)),
+            ]),
+            Line::from(""),
+            Line::from(fact.content.clone()),
+        ],
+        None => vec![Line::from(Span::styled(
+            "Source fact is not in this context",
+            Style::default().fg(Color::DarkGray),
+        ))],
+    };
+    let detail_pane = Paragraph::new(detail)
+        .wrap(Wrap { trim: false })
+        .block(Block::default().borders(Borders::ALL).title(" Fact "));
+    f.render_widget(detail_pane, chunks[1]);
+}
+
+fn draw_job_diff(f: &mut Frame, app: &mut App, area: Rect) {
+    let Some(ref diff) = app.job_diff else {
+        let empty = Paragraph::new("No diff. Mark two jobs with m and press d.")
+            .block(Block::default().borders(Borders::ALL).title(" Job Diff "));
+        f.render_widget(empty, area);
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(5), Constraint::Min(0)])
+        .split(area);
+
+    // Headline: cycles, convergence and fact counts side by side
+    let differs = |same: bool| if same { Style::default().fg(Color::White) } else { Style::default().fg(Color::Yellow) };
+    let (added, removed, changed) = diff.totals();
+    let summary = vec![
+        Line::from(vec![
+            Span::styled(format!("{:<24}", ""), Style::default()),
+            Span::styled(format!("{:<24}", diff.before.label), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::styled(diff.after.label.clone(), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+        ]),
+        Line::from(vec![
+            Span::styled(format!("{:<24}", "Converged / cycles"), Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("{:<24}", format!("{} / {}", diff.before.converged, diff.before.cycles)),
+                Style::default().fg(Color::White),
+            ),
+            Span::styled(
+                format!("{} / {}", diff.after.converged, diff.after.cycles),
+                differs(diff.before.converged == diff.after.converged && diff.before.cycles == diff.after.cycles),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(format!("{:<24}", "Facts"), Style::default().fg(Color::Gray)),
+            Span::styled(format!("{:<24}", diff.before.total_facts), Style::default().fg(Color::White)),
+            Span::styled(format!("{}", diff.after.total_facts), differs(diff.before.total_facts == diff.after.total_facts)),
+            Span::styled(
+                format!("   +{} -{} ~{}", added, removed, changed),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]),
+    ];
+    let header = Paragraph::new(summary).block(Block::default().borders(Borders::ALL).title(" Job Diff "));
+    f.render_widget(header, chunks[0]);
+
+    // Facts side by side, grouped by key
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[1]);
+
+    let mut left: Vec<Line> = Vec::new();
+    let mut right: Vec<Line> = Vec::new();
+    let blank = || Line::from("");
+    for key in &diff.keys {
+        let heading = Line::from(Span::styled(
+            format!("[{}] {} unchanged", key.key, key.unchanged),
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+        ));
+        left.push(heading.clone());
+        right.push(heading);
+        for fact in &key.removed {
+            left.push(Line::from(Span::styled(format!("- {}: {}", fact.id, fact.content), Style::default().fg(Color::Red))));
+            right.push(blank());
+        }
+        for fact in &key.added {
+            left.push(blank());
+            right.push(Line::from(Span::styled(format!("+ {}: {}", fact.id, fact.content), Style::default().fg(Color::Green))));
+        }
+        for fact in &key.changed {
+            left.push(Line::from(Span::styled(format!("~ {}: {}", fact.id, fact.before), Style::default().fg(Color::Yellow))));
+            right.push(Line::from(Span::styled(format!("~ {}: {}", fact.id, fact.after), Style::default().fg(Color::Yellow))));
+        }
+    }
+    if diff.is_identical() {
+        left.push(Line::from(Span::styled("Jobs are identical", Style::default().fg(Color::Green))));
+    }
+
+    // Unwrapped so the two columns stay line-aligned
+    let before = Paragraph::new(left)
+        .scroll((app.diff_scroll, 0))
+        .block(Block::default().borders(Borders::ALL).title(format!(" {} ", diff.before.label)));
+    let after = Paragraph::new(right)
+        .scroll((app.diff_scroll, 0))
+        .block(Block::default().borders(Borders::ALL).title(format!(" {} ", diff.after.label)));
+    f.render_widget(before, columns[0]);
+    f.render_widget(after, columns[1]);
+}
+
+fn draw_evals(f: &mut Frame, app: &mut App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    let header = Row::new(vec![
+        Cell::from("Eval").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        Cell::from("Pack").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        Cell::from("LLM").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        Cell::from("Status").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        Cell::from("Time").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+    ])
+    .height(1)
+    .bottom_margin(1);
+
+    let evals = &app.evals;
+    let rows: Vec<Row> = evals
+        .fixtures
+        .iter()
+        .enumerate()
+        .map(|(i, fixture)| {
+            let selected = evals.state.selected() == Some(i);
+            let row_style = if selected {
+                Style::default().bg(Color::Blue).fg(Color::White)
+            } else {
+                Style::default()
+            };
+            let run = evals.runs.get(&fixture.eval_id);
+            let (status, status_color) = match run {
+                None => ("-", Color::DarkGray),
+                Some(EvalRunState::Queued) => ("Queued", Color::Gray),
+                Some(EvalRunState::Running) => ("Running", Color::Yellow),
+                Some(state @ EvalRunState::Finished(result)) => {
+                    let color = match result {
+                        r if r.passed => Color::Green,
+                        r if r.skipped.is_some() => Color::Yellow,
+                        _ => Color::Red,
+                    };
+                    (state.label(), color)
+                }
+            };
+            let time = match run {
+                Some(EvalRunState::Finished(result)) => format!("{}ms", result.duration.as_millis()),
+                _ => "-".to_string(),
+            };
+            let llm = if evals.force_mock || fixture.use_mock_llm { "mock" } else { "real" };
+            let mark = if evals.marked.contains(&fixture.eval_id) { "● " } else { "  " };
+
+            Row::new(vec![
+                Cell::from(format!("{}{}", mark, fixture.eval_id)).style(row_style),
+                Cell::from(fixture.pack.clone()).style(row_style),
+                Cell::from(llm).style(row_style),
+                Cell::from(status).style(if selected { row_style } else { Style::default().fg(status_color) }),
+                Cell::from(time).style(row_style),
+            ])
+            .style(row_style)
+        })
+        .collect();
+
+    let (passed, failed) = evals.totals();
+    let mode = if evals.force_mock { "mock" } else { "per fixture" };
+    let title = format!(
+        " Evals ({}) [{}] LLM: {} | {} passed, {} failed ",
+        evals.fixtures.len(),
+        evals.dir,
+        mode,
+        passed,
+        failed
+    );
+    let table = Table::new(rows, [
+        Constraint::Min(30),
+        Constraint::Length(16),
+        Constraint::Length(5),
+        Constraint::Length(8),
+        Constraint::Length(8),
+    ])
+    .header(header)
+    .block(Block::default().borders(Borders::ALL).title(title));
+    f.render_stateful_widget(table, chunks[0], &mut app.evals.state);
+
+    // Checks of the highlighted fixture: expected vs actual
+    let evals = &app.evals;
+    let Some(fixture) = evals.selected() else {
+        let message = evals
+            .load_error
+            .clone()
+            .unwrap_or_else(|| format!("No eval fixtures found in '{}'", evals.dir));
+        let empty = Paragraph::new(message)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::ALL).title(" Checks "));
+        f.render_widget(empty, chunks[1]);
+        return;
+    };
+
+    let detail = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(6), Constraint::Min(0)])
+        .split(chunks[1]);
+
+    let mut summary = vec![
+        Line::from(Span::styled(fixture.description.clone(), Style::default().fg(Color::White))),
+        Line::from(vec![
+            Span::styled("Seeds: ", Style::default().fg(Color::Gray)),
+            Span::raw(format!("{}", fixture.seeds.len())),
+        ]),
+    ];
+    if let Some(EvalRunState::Finished(result)) = evals.runs.get(&fixture.eval_id) {
+        summary.push(Line::from(vec![
+            Span::styled("Result: ", Style::default().fg(Color::Gray)),
+            Span::raw(format!(
+                "converged={} cycles={} facts={} run={}",
+                result.converged, result.cycles, result.fact_count, result.run_id
+            )),
+        ]));
+        if let Some(ref hash) = result.context_hash {
+            summary.push(Line::from(vec![
+                Span::styled("Context: ", Style::default().fg(Color::Gray)),
+                Span::raw(hash.short().to_string()),
+            ]));
+        }
+    }
+    let summary = Paragraph::new(summary)
+        .wrap(Wrap { trim: false })
+        .block(Block::default().borders(Borders::ALL).title(format!(" {} ", fixture.eval_id)));
+    f.render_widget(summary, detail[0]);
+
+    let check_header = Row::new(vec![
+        Cell::from("Check").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        Cell::from("Expected").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        Cell::from("Actual").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        Cell::from("").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+    ])
+    .height(1)
+    .bottom_margin(1);
+
+    let check_rows: Vec<Row> = match evals.runs.get(&fixture.eval_id) {
+        Some(EvalRunState::Finished(result)) => {
+            let mut rows: Vec<Row> = result
+                .checks
+                .iter()
+                .map(|check| {
+                    let (mark, color) = if check.passed { ("PASS", Color::Green) } else { ("FAIL", Color::Red) };
+                    Row::new(vec![
+                        Cell::from(check.name.clone()),
+                        Cell::from(check.expected.clone()),
+                        Cell::from(check.actual.clone()).style(Style::default().fg(color)),
+                        Cell::from(mark).style(Style::default().fg(color)),
+                    ])
+                })
+                .collect();
+            if let Some(ref error) = result.error {
+                rows.push(Row::new(vec![
+                    Cell::from("error"),
+                    Cell::from("-"),
+                    Cell::from(error.clone()).style(Style::default().fg(Color::Red)),
+                    Cell::from("FAIL").style(Style::default().fg(Color::Red)),
+                ]));
+            }
+            if let Some(ref reason) = result.skipped {
+                rows.push(Row::new(vec![
+                    Cell::from("run"),
+                    Cell::from("-"),
+                    Cell::from(reason.clone()).style(Style::default().fg(Color::Yellow)),
+                    Cell::from("SKIP").style(Style::default().fg(Color::Yellow)),
+                ]));
+            }
+            rows
+        }
+        Some(state) => vec![Row::new(vec![Cell::from(state.label()).style(Style::default().fg(Color::Yellow))])],
+        None => vec![Row::new(vec![Cell::from("Not run yet (Enter to run)").style(Style::default().fg(Color::DarkGray))])],
+    };
+
+    let checks = Table::new(check_rows, [
+        Constraint::Length(22),
+        Constraint::Percentage(35),
+        Constraint::Percentage(35),
+        Constraint::Length(5),
+    ])
+    .header(check_header)
+    .block(Block::default().borders(Borders::ALL).title(" Checks "));
+    f.render_widget(checks, detail[1]);
 }
 
 fn draw_agents(f: &mut Frame, app: &mut App, area: Rect) {
@@ -491,6 +1176,11 @@ fn draw_agents(f: &mut Frame, app: &mut App, area: Rect) {
         Cell::from("Agent").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
         Cell::from("Status").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
         Cell::from("Last Run").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        Cell::from("Runs").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        Cell::from("Cycle").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        Cell::from("LLM Calls").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        Cell::from("Latency").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        Cell::from("Errors").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
         Cell::from("Facts").style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
     ])
     .height(1)
@@ -508,11 +1198,8 @@ fn draw_agents(f: &mut Frame, app: &mut App, area: Rect) {
                 Style::default()
             };
 
-            let status_style = if agent.status == "Running" {
-                Style::default().fg(Color::Yellow)
-            } else {
-                Style::default().fg(Color::Green)
-            };
+            let status_style = Style::default().fg(agent_status_color(&agent.status));
+            let stat = &agent.stats;
 
             let prefix = if selected { "▶ " } else { "  " };
 
@@ -520,7 +1207,12 @@ fn draw_agents(f: &mut Frame, app: &mut App, area: Rect) {
                 Cell::from(format!("{}{}", prefix, agent.name)).style(row_style),
                 Cell::from(agent.status.clone()).style(if selected { row_style } else { status_style }),
                 Cell::from(agent.last_run.clone().unwrap_or_else(|| "-".to_string())).style(row_style),
-                Cell::from(format!("{}", agent.facts_produced)).style(row_style),
+                Cell::from(format!("{}", stat.runs)).style(row_style),
+                Cell::from(stat.last_cycle.map_or_else(|| "-".to_string(), |c| c.to_string())).style(row_style),
+                Cell::from(format!("{}", stat.llm_calls)).style(row_style),
+                Cell::from(format_latency(stat)).style(row_style),
+                Cell::from(format!("{}", stat.errors)).style(row_style),
+                Cell::from(format!("{} ({})", stat.facts_produced(), format_facts_by_key(stat))).style(row_style),
             ])
             .style(row_style)
         })
@@ -528,8 +1220,13 @@ fn draw_agents(f: &mut Frame, app: &mut App, area: Rect) {
 
     let table = Table::new(rows, [
         Constraint::Length(25),
-        Constraint::Length(12),
-        Constraint::Length(15),
+        Constraint::Length(10),
+        Constraint::Length(10),
+        Constraint::Length(6),
+        Constraint::Length(6),
+        Constraint::Length(10),
+        Constraint::Length(9),
+        Constraint::Length(7),
         Constraint::Min(8),
     ])
     .header(header)
@@ -539,17 +1236,33 @@ fn draw_agents(f: &mut Frame, app: &mut App, area: Rect) {
 }
 
 fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
+    // The remote job API has no pause, re-run or review endpoints
     let help_text = match app.current_view {
-        View::Jobs => " ↑/↓:Select  Enter:Details  Tab:Switch view  q:Quit ",
-        View::JobDetail => " b:Back  ←:Back  y/n:Approve/Reject proposal  q:Quit ",
+        View::Jobs if app.remote.is_some() => {
+            " ↑/↓:Select  Enter:Details  c:Cancel  m:Mark  d:Diff  q:Quit  (remote: no pause, re-run or review) "
+        }
+        View::Jobs => " ↑/↓:Select  Enter:Details  c:Cancel  p:Pause/Resume  r:Re-run  m:Mark  d:Diff  q:Quit ",
+        View::JobDiff => " ↑/↓:Scroll  b:Back  q:Quit ",
+        View::JobDetail if app.remote.is_some() => " e:Export  b:Back  q:Quit  (remote: no proposal review) ",
+        View::JobDetail => " j/k:Select proposal  y:Approve (Tab to edit)  n:Reject  e:Export  b:Back  q:Quit ",
         View::Packs => " ↑/↓:Select  Tab:Switch view  q:Quit ",
         View::Submit => " ↑/↓:Fields  Enter:Submit  Esc:Clear  Tab:Switch view ",
-        View::Context => " ↑/↓:Select  Tab:Switch view  q:Quit ",
+        View::Context => " ↑/↓:Select  /:Search  f:Filter key  Enter/Space:Collapse  p:Provenance  Esc:Clear  q:Quit ",
+        View::Provenance => " ↑/↓:Select  Enter:Trace source  b:Back  q:Quit ",
         View::Agents => " ↑/↓:Select  Tab:Switch view  q:Quit ",
+        View::Evals => " ↑/↓:Select  Space:Mark  Enter:Run  a:Run all  m:Mock on/off  r:Reload  q:Quit ",
+    };
+
+    let line = match app.status_message {
+        Some(ref msg) => Line::from(vec![
+            Span::styled(format!(" {} ", msg), Style::default().fg(Color::Yellow)),
+            Span::styled("|", Style::default().fg(Color::DarkGray)),
+            Span::styled(help_text, Style::default().fg(Color::DarkGray)),
+        ]),
+        None => Line::from(Span::styled(help_text, Style::default().fg(Color::DarkGray))),
     };
 
-    let status = Paragraph::new(help_text)
-        .style(Style::default().fg(Color::DarkGray))
+    let status = Paragraph::new(line)
         .block(Block::default().borders(Borders::ALL));
 
     f.render_widget(status, area);
@@ -0,0 +1,225 @@
+//! Background Job Execution
+//!
+//! Convergence runs on a blocking worker task so the TUI event loop stays
+//! responsive. Workers report progress back to the app over a channel:
+//!
+//! - `Cycle` when the engine starts a new cycle
+//! - `Fact` for every fact committed to the context
+//! - `Agents` with per-agent statistics at the end of every cycle
+//! - `Finished` with the final outcome (or the engine error)
+//!
+//! Eval runs started from the Evals tab report `EvalStarted` and
+//! `EvalFinished` over the same channel, and in remote mode the server
+//! client reports `Remote` results (see `remote`).
+//!
+//! The app drains the channel on every tick of the event loop.
+
+use std::sync::Arc;
+
+use converge_core::{Context, ContextKey, Engine, Fact, StreamingCallback};
+use strum::IntoEnumIterator;
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::app::{FactInfo, ProposalInfo};
+use super::remote::RemoteEvent;
+use crate::confidence::ConfidenceLedger;
+use crate::evals::{run_eval, EvalFixture, EvalResult};
+use crate::output::ProviderInfo;
+use crate::provenance::ProvenanceLog;
+use crate::telemetry::{AgentStat, AgentStats};
+use crate::wiring::{register_pack_agents, AgentWiring};
+
+/// Progress reported by a job worker
+#[derive(Debug, Clone)]
+pub enum JobEvent {
+    /// The engine started a cycle
+    Cycle { job_id: String, cycle: u32 },
+    /// A fact was committed to the job's context
+    Fact { job_id: String, cycle: u32, fact: FactInfo },
+    /// Per-agent statistics after a cycle
+    Agents { job_id: String, agents: Vec<AgentStat> },
+    /// The job finished (Err holds the engine error)
+    Finished {
+        job_id: String,
+        outcome: Result<JobOutcome, String>,
+    },
+    /// An eval fixture started running
+    EvalStarted { eval_id: String },
+    /// An eval fixture finished
+    EvalFinished { result: EvalResult },
+    /// Result of a call to a remote converge server
+    Remote(RemoteEvent),
+}
+
+/// Final state of a finished job
+#[derive(Debug, Clone)]
+pub struct JobOutcome {
+    pub converged: bool,
+    /// The job was cancelled before reaching a fixed point
+    pub cancelled: bool,
+    pub cycles: u32,
+    pub facts: Vec<FactInfo>,
+    pub proposals: Vec<ProposalInfo>,
+    /// Final per-agent statistics
+    pub agents: Vec<AgentStat>,
+    /// LLM provider the agents used
+    pub provider: Option<ProviderInfo>,
+}
+
+/// Everything a worker needs to run a job
+pub struct JobRequest {
+    pub job_id: String,
+    pub pack: String,
+    pub context: Context,
+    pub wiring: AgentWiring,
+}
+
+/// Streaming callback that forwards engine progress to the app
+struct ChannelStreamer {
+    job_id: String,
+    ledger: Arc<ConfidenceLedger>,
+    stats: Arc<AgentStats>,
+    provenance: Arc<ProvenanceLog>,
+    tx: UnboundedSender<JobEvent>,
+}
+
+impl StreamingCallback for ChannelStreamer {
+    fn on_cycle_start(&self, cycle: u32) {
+        let _ = self.tx.send(JobEvent::Cycle {
+            job_id: self.job_id.clone(),
+            cycle,
+        });
+    }
+
+    fn on_fact(&self, cycle: u32, fact: &Fact) {
+        let _ = self.tx.send(JobEvent::Fact {
+            job_id: self.job_id.clone(),
+            cycle,
+            fact: fact_info(fact, &self.ledger, &self.provenance),
+        });
+    }
+
+    fn on_cycle_end(&self, _cycle: u32, _facts_added: usize) {
+        let _ = self.tx.send(JobEvent::Agents {
+            job_id: self.job_id.clone(),
+            agents: self.stats.snapshot(),
+        });
+    }
+}
+
+/// Convert an engine fact to its display form
+pub fn fact_info(fact: &Fact, ledger: &ConfidenceLedger, provenance: &ProvenanceLog) -> FactInfo {
+    FactInfo {
+        key: format!("{:?}", fact.key),
+        id: fact.id.clone(),
+        content: fact.content.clone(),
+        confidence: ledger.confidence(&fact.id),
+        provenance: provenance.get(&fact.id),
+    }
+}
+
+/// Spawn a job on a blocking worker task
+///
+/// Agents are registered on the worker so provider construction does not
+/// block the UI either.
+pub fn spawn_job(request: JobRequest, tx: UnboundedSender<JobEvent>) {
+    tokio::task::spawn_blocking(move || {
+        let JobRequest {
+            job_id,
+            pack,
+            context,
+            wiring,
+        } = request;
+
+        let outcome = run_job(&job_id, &pack, context, &wiring, &tx);
+        let _ = tx.send(JobEvent::Finished { job_id, outcome });
+    });
+}
+
+fn run_job(
+    job_id: &str,
+    pack: &str,
+    context: Context,
+    wiring: &AgentWiring,
+    tx: &UnboundedSender<JobEvent>,
+) -> Result<JobOutcome, String> {
+    let ledger = &wiring.ledger;
+    let wiring = wiring.clone().with_streaming(Arc::new(ChannelStreamer {
+        job_id: job_id.to_string(),
+        ledger: ledger.clone(),
+        stats: wiring.stats.clone(),
+        provenance: wiring.provenance.clone(),
+        tx: tx.clone(),
+    }));
+
+    let mut engine = Engine::new();
+    register_pack_agents(&mut engine, pack, &wiring)
+        .map_err(|e| format!("Failed to register agents: {}", e))?;
+
+    let result = match engine.run(context) {
+        Ok(result) => result,
+        // Cancelling stops agents early, so an acceptance invariant may
+        // fail; that is still a cancellation. The facts streamed so far
+        // stay with the job.
+        Err(_) if wiring.control.is_cancelled() => {
+            return Ok(JobOutcome {
+                converged: false,
+                cancelled: true,
+                cycles: wiring.stats.current_cycle(),
+                facts: Vec::new(),
+                proposals: Vec::new(),
+                agents: wiring.stats.snapshot(),
+                provider: wiring.stats.provider(),
+            });
+        }
+        Err(e) => return Err(format!("Job failed: {}", e)),
+    };
+
+    let facts = ContextKey::iter()
+        .flat_map(|key| {
+            result
+                .context
+                .get(key)
+                .iter()
+                .map(|fact| fact_info(fact, ledger, &wiring.provenance))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    let proposals = ledger
+        .pending_review()
+        .into_iter()
+        .map(|item| ProposalInfo {
+            id: item.id,
+            agent: item.agent,
+            key: item.key,
+            content: item.content,
+            confidence: item.confidence,
+            threshold: item.threshold,
+            decision: None,
+        })
+        .collect();
+
+    Ok(JobOutcome {
+        converged: result.converged,
+        cancelled: wiring.control.is_cancelled(),
+        cycles: result.cycles,
+        facts,
+        proposals,
+        agents: wiring.stats.snapshot(),
+        provider: wiring.stats.provider(),
+    })
+}
+
+/// Run eval fixtures one after another on a blocking worker task
+pub fn spawn_evals(fixtures: Vec<EvalFixture>, tx: UnboundedSender<JobEvent>) {
+    tokio::task::spawn_blocking(move || {
+        for fixture in fixtures {
+            let _ = tx.send(JobEvent::EvalStarted {
+                eval_id: fixture.eval_id.clone(),
+            });
+            let result = run_eval(&fixture);
+            let _ = tx.send(JobEvent::EvalFinished { result });
+        }
+    });
+}
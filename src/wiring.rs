@@ -0,0 +1,267 @@
+// Copyright 2024-2025 Aprio One AB, Sweden
+// Author: Kenneth Pernyer, kenneth@aprio.one
+// SPDX-License-Identifier: MIT
+// See LICENSE file in the project root for full license information.
+
+//! Agent wiring for domain packs.
+//!
+//! This is the single bridge between the distribution layer and the domain
+//! packs, shared by `converge run`, the TUI and the eval runner. It:
+//!
+//! - Registers a pack's agents and invariants on an `Engine`
+//! - Selects the LLM provider (real or mock)
+//! - Threads per-run hooks (confidence ledger, job control, statistics,
+//!   provenance) into every agent and LLM provider
+//! - Installs the engine's streaming callback (statistics and provenance
+//!   plus any caller-supplied output handler)
+//!
+//! # Architecture Note
+//!
+//! Wiring composes agents already defined in `converge-domain` and this
+//! crate; it does not decide what any agent does.
+
+use anyhow::Result;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use converge_core::llm::LlmProvider;
+use converge_core::{Agent, Engine, Invariant, StreamingCallback};
+use converge_domain::growth_strategy::{
+    BrandSafetyInvariant, CompetitorAgent, EvaluationAgent, MarketSignalAgent,
+    RequireEvaluationRationale, RequireMultipleStrategies, RequireStrategyEvaluations,
+    StrategyAgent,
+};
+use converge_provider::{AnthropicProvider, OpenAiProvider};
+
+use crate::agents::{MockInsightProvider, RiskAssessmentAgent, StrategicInsightAgent};
+use crate::confidence::ConfidenceLedger;
+use crate::control::{Controlled, JobControl};
+use crate::provenance::{ProvenanceLog, Traced};
+use crate::replay::CassetteMode;
+use crate::streaming::FanoutCallback;
+use crate::telemetry::{AgentStats, Observed, TimedProvider};
+
+/// Per-run hooks threaded into every agent of a pack.
+#[derive(Clone)]
+pub struct AgentWiring {
+    /// Use the mock LLM provider for deterministic output.
+    pub use_mock: bool,
+    /// Receives LLM fact confidences and items diverted to review.
+    pub ledger: Arc<ConfidenceLedger>,
+    /// Cancel/pause switch checked between cycles.
+    pub control: Arc<JobControl>,
+    /// Per-agent run statistics.
+    pub stats: Arc<AgentStats>,
+    /// Producing agent, cycle and source facts of every emitted fact.
+    pub provenance: Arc<ProvenanceLog>,
+    /// Caller-supplied streaming output, fed alongside the statistics.
+    pub streaming: Option<Arc<dyn StreamingCallback + Send + Sync>>,
+    /// Records LLM responses, or replays recorded ones instead of calling a provider.
+    pub cassette: Option<CassetteMode>,
+}
+
+impl AgentWiring {
+    /// Creates wiring with a fresh ledger (no review threshold) and control.
+    pub fn new(use_mock: bool) -> Self {
+        Self {
+            use_mock,
+            ledger: Arc::new(ConfidenceLedger::default()),
+            control: Arc::new(JobControl::new()),
+            stats: Arc::new(AgentStats::new()),
+            provenance: Arc::new(ProvenanceLog::new()),
+            streaming: None,
+            cassette: None,
+        }
+    }
+
+    /// Uses `ledger` for LLM fact confidences.
+    #[must_use]
+    pub fn with_ledger(mut self, ledger: Arc<ConfidenceLedger>) -> Self {
+        self.ledger = ledger;
+        self
+    }
+
+    /// Uses `control` to cancel or pause the run.
+    #[must_use]
+    pub fn with_control(mut self, control: Arc<JobControl>) -> Self {
+        self.control = control;
+        self
+    }
+
+    /// Records per-agent statistics in `stats`.
+    #[must_use]
+    pub fn with_stats(mut self, stats: Arc<AgentStats>) -> Self {
+        self.stats = stats;
+        self
+    }
+
+    /// Records fact provenance in `provenance`.
+    #[must_use]
+    pub fn with_provenance(mut self, provenance: Arc<ProvenanceLog>) -> Self {
+        self.provenance = provenance;
+        self
+    }
+
+    /// Streams engine progress to `handler` as well as the statistics.
+    #[must_use]
+    pub fn with_streaming(mut self, handler: Arc<dyn StreamingCallback + Send + Sync>) -> Self {
+        self.streaming = Some(handler);
+        self
+    }
+
+    /// Records LLM calls into, or answers them from, `cassette`.
+    #[must_use]
+    pub fn with_cassette(mut self, cassette: CassetteMode) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    /// Wraps an agent with the per-run hooks.
+    fn wrap<A: Agent>(&self, agent: A) -> Observed<Traced<Controlled<A>>> {
+        let controlled = Controlled::new(agent, self.control.clone());
+        Observed::new(Traced::new(controlled, self.provenance.clone()), self.stats.clone())
+    }
+
+    /// Registers an invariant, listing it in the run statistics.
+    fn register_invariant<I: Invariant + 'static>(&self, engine: &mut Engine, invariant: I) {
+        let type_name = std::any::type_name::<I>();
+        self.stats
+            .register_invariant(type_name.rsplit("::").next().unwrap_or(type_name));
+        engine.register_invariant(invariant);
+    }
+
+    /// Attributes LLM calls through `provider` to `agent`.
+    fn provider_for(&self, provider: &Arc<dyn LlmProvider>, agent: &str) -> Arc<dyn LlmProvider> {
+        let provider: Arc<dyn LlmProvider> = match self.cassette {
+            Some(CassetteMode::Record(ref recorder)) => Arc::new(recorder.provider(provider.clone(), agent)),
+            Some(CassetteMode::Replay(ref player)) => Arc::new(player.provider(agent)),
+            None => provider.clone(),
+        };
+        Arc::new(TimedProvider::new(provider, agent, self.stats.clone()))
+    }
+
+    /// The engine's streaming callback: statistics and provenance first,
+    /// then the handler.
+    fn streaming_callback(&self) -> FanoutCallback {
+        let mut callbacks: Vec<Arc<dyn StreamingCallback + Send + Sync>> =
+            vec![self.stats.clone(), self.provenance.clone()];
+        callbacks.extend(self.streaming.clone());
+        FanoutCallback::new(callbacks)
+    }
+}
+
+/// Model used with the Anthropic provider.
+pub const ANTHROPIC_MODEL: &str = "claude-sonnet-4-20250514";
+
+/// Model used with the OpenAI provider.
+pub const OPENAI_MODEL: &str = "gpt-4o";
+
+/// Environment variables holding credentials of the real LLM providers.
+pub const LLM_API_KEY_VARS: [&str; 2] = ["ANTHROPIC_API_KEY", "OPENAI_API_KEY"];
+
+/// Whether credentials for a real LLM provider are set.
+pub fn has_llm_credentials() -> bool {
+    LLM_API_KEY_VARS
+        .iter()
+        .any(|var| std::env::var(var).is_ok_and(|v| !v.trim().is_empty()))
+}
+
+/// Creates an LLM provider from environment variables.
+///
+/// Tries providers in order of preference:
+/// 1. Anthropic (ANTHROPIC_API_KEY) - Claude models
+/// 2. OpenAI (OPENAI_API_KEY) - GPT models
+/// 3. Falls back to MockInsightProvider if no API keys are set
+///
+/// Note: This function uses `block_in_place` because the underlying providers
+/// use blocking HTTP clients that can't be created directly in async context.
+pub fn create_llm_provider() -> Arc<dyn LlmProvider> {
+    // Use block_in_place to safely create blocking providers from async context
+    tokio::task::block_in_place(|| {
+        // Try Anthropic first (Claude is excellent for strategic analysis)
+        if let Ok(provider) = AnthropicProvider::from_env(ANTHROPIC_MODEL) {
+            info!(provider = "anthropic", model = ANTHROPIC_MODEL, "Using Anthropic Claude for LLM insights");
+            return Arc::new(provider) as Arc<dyn LlmProvider>;
+        }
+
+        // Try OpenAI second
+        if let Ok(provider) = OpenAiProvider::from_env(OPENAI_MODEL) {
+            info!(provider = "openai", model = OPENAI_MODEL, "Using OpenAI GPT for LLM insights");
+            return Arc::new(provider) as Arc<dyn LlmProvider>;
+        }
+
+        // Fall back to mock provider
+        warn!("No LLM API keys found (ANTHROPIC_API_KEY or OPENAI_API_KEY). Using mock provider.");
+        info!("Set ANTHROPIC_API_KEY or OPENAI_API_KEY in .env for real LLM insights");
+        Arc::new(MockInsightProvider::default_insights()) as Arc<dyn LlmProvider>
+    })
+}
+
+/// Register agents and invariants for a specific domain pack.
+///
+/// # Arguments
+/// * `engine` - The convergence engine to register agents with
+/// * `pack_name` - Name of the domain pack (e.g., "growth-strategy")
+/// * `wiring` - Provider selection and per-run hooks
+pub fn register_pack_agents(engine: &mut Engine, pack_name: &str, wiring: &AgentWiring) -> Result<()> {
+    match pack_name {
+        "growth-strategy" => {
+            info!(pack = %pack_name, mock = wiring.use_mock, "Registering growth-strategy agents and invariants");
+
+            // Register deterministic agents
+            engine.register(wiring.wrap(MarketSignalAgent));
+            engine.register(wiring.wrap(CompetitorAgent));
+            engine.register(wiring.wrap(StrategyAgent));
+            engine.register(wiring.wrap(EvaluationAgent));
+
+            // Create LLM provider based on mock flag; a replay never calls out
+            let replaying = matches!(wiring.cassette, Some(CassetteMode::Replay(_)));
+            let llm_provider: Arc<dyn LlmProvider> = if wiring.use_mock || replaying {
+                info!("Using mock LLM provider for deterministic output");
+                Arc::new(MockInsightProvider::default_insights())
+            } else {
+                create_llm_provider()
+            };
+            match wiring.cassette {
+                Some(CassetteMode::Replay(ref player)) => {
+                    if let Some(recorded) = player.recorded_provider() {
+                        wiring.stats.set_provider(&recorded.name, &recorded.model);
+                    }
+                }
+                _ => wiring.stats.set_provider(llm_provider.name(), llm_provider.model()),
+            }
+
+            // Register LLM-powered agents
+            let insight_provider = wiring.provider_for(&llm_provider, "StrategicInsightAgent");
+            engine.register(wiring.wrap(
+                StrategicInsightAgent::new(insight_provider).with_ledger(wiring.ledger.clone()),
+            ));
+            let risk_provider = wiring.provider_for(&llm_provider, "RiskAssessmentAgent");
+            engine.register(wiring.wrap(
+                RiskAssessmentAgent::new(risk_provider).with_ledger(wiring.ledger.clone()),
+            ));
+
+            // Register Invariants
+            wiring.register_invariant(engine, BrandSafetyInvariant::default());
+            wiring.register_invariant(engine, RequireMultipleStrategies);
+            wiring.register_invariant(engine, RequireStrategyEvaluations);
+            wiring.register_invariant(engine, RequireEvaluationRationale);
+        }
+        _ => {
+            warn!(pack = %pack_name, "No specific agent registration for pack");
+        }
+    }
+
+    engine.set_streaming(Arc::new(wiring.streaming_callback()));
+    Ok(())
+}
+
+/// Names of the agents a pack registers, ordered by name.
+///
+/// Registers the pack on a throwaway engine with the mock provider, so no
+/// LLM credentials are needed.
+pub fn pack_agent_names(pack_name: &str) -> Result<Vec<String>> {
+    let wiring = AgentWiring::new(true);
+    register_pack_agents(&mut Engine::new(), pack_name, &wiring)?;
+    Ok(wiring.stats.snapshot().into_iter().map(|stat| stat.name).collect())
+}